@@ -0,0 +1,292 @@
+//! # Asset Registry Module
+//!
+//! ## Overview
+//!
+//! Asset Registry module keeps the metadata of the currencies known to the
+//! chain: ERC20 contracts mirrored as `CurrencyId::ERC20`, new assets
+//! allocated as `CurrencyId::ForeignAsset` and native tokens. The metadata
+//! is cached on registration so it doesn't need to be read from the EVM
+//! again, and the registered minimal balance is used as the existential
+//! deposit of the currency.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use frame_support::{pallet_prelude::*, traits::Currency, transactional};
+use frame_system::pallet_prelude::*;
+use primitives::{evm::EvmAddress, CurrencyId, ForeignAssetId};
+use sp_runtime::traits::One;
+use sp_std::{boxed::Box, vec::Vec};
+use support::{AssetRegistry, EVMBridge, InvokeContext};
+
+mod default_weight;
+mod mock;
+mod tests;
+
+pub use module::*;
+pub use support::AssetMetadata;
+
+pub trait WeightInfo {
+	fn register_erc20_asset() -> Weight;
+	fn update_erc20_asset() -> Weight;
+	fn register_foreign_asset() -> Weight;
+	fn update_foreign_asset() -> Weight;
+	fn register_native_asset() -> Weight;
+	fn update_native_asset() -> Weight;
+}
+
+pub type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+#[frame_support::pallet]
+pub mod module {
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// Currency type for the balance of the minimal balance.
+		type Currency: Currency<Self::AccountId>;
+
+		/// The EVM bridge to read ERC20 metadata with.
+		type EVMBridge: EVMBridge<Self::AccountId, BalanceOf<Self>>;
+
+		/// Required origin for registering and updating assets.
+		type RegisterOrigin: EnsureOrigin<Self::Origin>;
+
+		/// Weight information for the extrinsics in this module.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// Error for asset registry module.
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The currency id is invalid for this operation
+		InvalidCurrencyId,
+		/// The asset is already registered
+		AssetIdExisted,
+		/// The asset is not registered
+		AssetIdNotExists,
+		/// All foreign asset ids are in use
+		ForeignAssetIdOverflow,
+		/// Failed to read the ERC20 metadata from the contract
+		InvalidERC20Contract,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(fn deposit_event)]
+	#[pallet::metadata(BalanceOf<T> = "Balance")]
+	pub enum Event<T: Config> {
+		/// An asset was registered. \[currency_id, metadata\]
+		AssetRegistered(CurrencyId, AssetMetadata<BalanceOf<T>>),
+		/// An asset was updated. \[currency_id, metadata\]
+		AssetUpdated(CurrencyId, AssetMetadata<BalanceOf<T>>),
+	}
+
+	/// The next foreign asset id to allocate.
+	///
+	/// NextForeignAssetId: ForeignAssetId
+	#[pallet::storage]
+	#[pallet::getter(fn next_foreign_asset_id)]
+	pub type NextForeignAssetId<T: Config> = StorageValue<_, ForeignAssetId, ValueQuery>;
+
+	/// The metadata of registered currencies.
+	///
+	/// AssetMetadatas: map CurrencyId => Option<AssetMetadata>
+	#[pallet::storage]
+	#[pallet::getter(fn asset_metadatas)]
+	pub type AssetMetadatas<T: Config> =
+		StorageMap<_, Twox64Concat, CurrencyId, AssetMetadata<BalanceOf<T>>, OptionQuery>;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(PhantomData<T>);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Register an ERC20 contract, caching its name, symbol and decimals.
+		///
+		/// The dispatch origin of this call must be `RegisterOrigin`.
+		#[pallet::weight(T::WeightInfo::register_erc20_asset())]
+		#[transactional]
+		pub fn register_erc20_asset(
+			origin: OriginFor<T>,
+			contract: EvmAddress,
+			minimal_balance: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			T::RegisterOrigin::ensure_origin(origin)?;
+
+			let currency_id = CurrencyId::ERC20(contract);
+			ensure!(
+				!AssetMetadatas::<T>::contains_key(currency_id),
+				Error::<T>::AssetIdExisted
+			);
+
+			let metadata = Self::erc20_metadata(contract, minimal_balance)?;
+			AssetMetadatas::<T>::insert(currency_id, &metadata);
+
+			Self::deposit_event(Event::AssetRegistered(currency_id, metadata));
+			Ok(().into())
+		}
+
+		/// Update the minimal balance of a registered ERC20 contract and
+		/// refresh its cached metadata.
+		///
+		/// The dispatch origin of this call must be `RegisterOrigin`.
+		#[pallet::weight(T::WeightInfo::update_erc20_asset())]
+		#[transactional]
+		pub fn update_erc20_asset(
+			origin: OriginFor<T>,
+			contract: EvmAddress,
+			minimal_balance: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			T::RegisterOrigin::ensure_origin(origin)?;
+
+			let currency_id = CurrencyId::ERC20(contract);
+			ensure!(
+				AssetMetadatas::<T>::contains_key(currency_id),
+				Error::<T>::AssetIdNotExists
+			);
+
+			let metadata = Self::erc20_metadata(contract, minimal_balance)?;
+			AssetMetadatas::<T>::insert(currency_id, &metadata);
+
+			Self::deposit_event(Event::AssetUpdated(currency_id, metadata));
+			Ok(().into())
+		}
+
+		/// Register a new asset, allocating the next `ForeignAssetId` for it.
+		///
+		/// The dispatch origin of this call must be `RegisterOrigin`.
+		#[pallet::weight(T::WeightInfo::register_foreign_asset())]
+		#[transactional]
+		pub fn register_foreign_asset(
+			origin: OriginFor<T>,
+			metadata: Box<AssetMetadata<BalanceOf<T>>>,
+		) -> DispatchResultWithPostInfo {
+			T::RegisterOrigin::ensure_origin(origin)?;
+
+			let foreign_asset_id =
+				NextForeignAssetId::<T>::try_mutate(|id| -> Result<ForeignAssetId, DispatchError> {
+					let current_id = *id;
+					*id = id.checked_add(One::one()).ok_or(Error::<T>::ForeignAssetIdOverflow)?;
+					Ok(current_id)
+				})?;
+
+			let currency_id = CurrencyId::ForeignAsset(foreign_asset_id);
+			AssetMetadatas::<T>::insert(currency_id, &*metadata);
+
+			Self::deposit_event(Event::AssetRegistered(currency_id, *metadata));
+			Ok(().into())
+		}
+
+		/// Update the metadata of a registered foreign asset.
+		///
+		/// The dispatch origin of this call must be `RegisterOrigin`.
+		#[pallet::weight(T::WeightInfo::update_foreign_asset())]
+		pub fn update_foreign_asset(
+			origin: OriginFor<T>,
+			foreign_asset_id: ForeignAssetId,
+			metadata: Box<AssetMetadata<BalanceOf<T>>>,
+		) -> DispatchResultWithPostInfo {
+			T::RegisterOrigin::ensure_origin(origin)?;
+
+			let currency_id = CurrencyId::ForeignAsset(foreign_asset_id);
+			Self::do_update_asset(currency_id, *metadata)?;
+			Ok(().into())
+		}
+
+		/// Register the metadata of a native token.
+		///
+		/// The dispatch origin of this call must be `RegisterOrigin`.
+		#[pallet::weight(T::WeightInfo::register_native_asset())]
+		pub fn register_native_asset(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			metadata: Box<AssetMetadata<BalanceOf<T>>>,
+		) -> DispatchResultWithPostInfo {
+			T::RegisterOrigin::ensure_origin(origin)?;
+
+			ensure!(currency_id.is_token_currency_id(), Error::<T>::InvalidCurrencyId);
+			ensure!(
+				!AssetMetadatas::<T>::contains_key(currency_id),
+				Error::<T>::AssetIdExisted
+			);
+
+			AssetMetadatas::<T>::insert(currency_id, &*metadata);
+
+			Self::deposit_event(Event::AssetRegistered(currency_id, *metadata));
+			Ok(().into())
+		}
+
+		/// Update the metadata of a native token.
+		///
+		/// The dispatch origin of this call must be `RegisterOrigin`.
+		#[pallet::weight(T::WeightInfo::update_native_asset())]
+		pub fn update_native_asset(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			metadata: Box<AssetMetadata<BalanceOf<T>>>,
+		) -> DispatchResultWithPostInfo {
+			T::RegisterOrigin::ensure_origin(origin)?;
+
+			ensure!(currency_id.is_token_currency_id(), Error::<T>::InvalidCurrencyId);
+			Self::do_update_asset(currency_id, *metadata)?;
+			Ok(().into())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	fn erc20_metadata(
+		contract: EvmAddress,
+		minimal_balance: BalanceOf<T>,
+	) -> Result<AssetMetadata<BalanceOf<T>>, DispatchError> {
+		let context = InvokeContext {
+			contract,
+			sender: Default::default(),
+			origin: Default::default(),
+		};
+
+		Ok(AssetMetadata {
+			name: T::EVMBridge::name(context).map_err(|_| Error::<T>::InvalidERC20Contract)?,
+			symbol: T::EVMBridge::symbol(context).map_err(|_| Error::<T>::InvalidERC20Contract)?,
+			decimals: T::EVMBridge::decimals(context).map_err(|_| Error::<T>::InvalidERC20Contract)?,
+			minimal_balance,
+		})
+	}
+
+	fn do_update_asset(currency_id: CurrencyId, metadata: AssetMetadata<BalanceOf<T>>) -> DispatchResult {
+		AssetMetadatas::<T>::try_mutate(currency_id, |maybe_metadata| -> DispatchResult {
+			let old_metadata = maybe_metadata.as_mut().ok_or(Error::<T>::AssetIdNotExists)?;
+			*old_metadata = metadata.clone();
+			Ok(())
+		})?;
+
+		Self::deposit_event(Event::AssetUpdated(currency_id, metadata));
+		Ok(())
+	}
+
+	/// The registered name of `currency_id`.
+	pub fn name(currency_id: CurrencyId) -> Option<Vec<u8>> {
+		Self::asset_metadatas(currency_id).map(|metadata| metadata.name)
+	}
+
+	/// The registered symbol of `currency_id`.
+	pub fn symbol(currency_id: CurrencyId) -> Option<Vec<u8>> {
+		Self::asset_metadatas(currency_id).map(|metadata| metadata.symbol)
+	}
+
+	/// The registered decimals of `currency_id`.
+	pub fn decimals(currency_id: CurrencyId) -> Option<u8> {
+		Self::asset_metadatas(currency_id).map(|metadata| metadata.decimals)
+	}
+}
+
+impl<T: Config> AssetRegistry<BalanceOf<T>> for Pallet<T> {
+	fn asset_metadata(currency_id: CurrencyId) -> Option<AssetMetadata<BalanceOf<T>>> {
+		Self::asset_metadatas(currency_id)
+	}
+}
@@ -0,0 +1,190 @@
+//! Unit tests for the asset registry module.
+
+#![cfg(test)]
+
+use super::*;
+use frame_support::{assert_noop, assert_ok};
+use mock::{
+	AssetRegistry, Event, ExtBuilder, Origin, Runtime, System, ALICE, BOB, ERC20_ADDRESS, INVALID_ERC20_ADDRESS,
+};
+use primitives::TokenSymbol;
+use sp_runtime::traits::BadOrigin;
+
+fn foreign_asset_metadata(minimal_balance: u128) -> AssetMetadata<u128> {
+	AssetMetadata {
+		name: b"Foreign Token".to_vec(),
+		symbol: b"FT".to_vec(),
+		decimals: 12,
+		minimal_balance,
+	}
+}
+
+#[test]
+fn register_erc20_asset_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(AssetRegistry::register_erc20_asset(
+			Origin::signed(ALICE),
+			ERC20_ADDRESS,
+			10
+		));
+
+		let metadata = AssetMetadata {
+			name: b"Test Token".to_vec(),
+			symbol: b"TT".to_vec(),
+			decimals: 17,
+			minimal_balance: 10,
+		};
+		let currency_id = CurrencyId::ERC20(ERC20_ADDRESS);
+		assert_eq!(AssetRegistry::asset_metadatas(currency_id), Some(metadata.clone()));
+		assert_eq!(AssetRegistry::name(currency_id), Some(b"Test Token".to_vec()));
+		assert_eq!(AssetRegistry::symbol(currency_id), Some(b"TT".to_vec()));
+		assert_eq!(AssetRegistry::decimals(currency_id), Some(17));
+		assert_eq!(
+			<AssetRegistry as support::AssetRegistry<u128>>::asset_metadata(currency_id),
+			Some(metadata.clone())
+		);
+		System::assert_last_event(Event::AssetRegistry(crate::Event::AssetRegistered(
+			currency_id,
+			metadata,
+		)));
+
+		assert_noop!(
+			AssetRegistry::register_erc20_asset(Origin::signed(ALICE), ERC20_ADDRESS, 10),
+			Error::<Runtime>::AssetIdExisted
+		);
+	});
+}
+
+#[test]
+fn register_erc20_asset_should_fail() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			AssetRegistry::register_erc20_asset(Origin::signed(BOB), ERC20_ADDRESS, 10),
+			BadOrigin
+		);
+		assert_noop!(
+			AssetRegistry::register_erc20_asset(Origin::signed(ALICE), INVALID_ERC20_ADDRESS, 10),
+			Error::<Runtime>::InvalidERC20Contract
+		);
+	});
+}
+
+#[test]
+fn update_erc20_asset_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			AssetRegistry::update_erc20_asset(Origin::signed(ALICE), ERC20_ADDRESS, 20),
+			Error::<Runtime>::AssetIdNotExists
+		);
+
+		assert_ok!(AssetRegistry::register_erc20_asset(
+			Origin::signed(ALICE),
+			ERC20_ADDRESS,
+			10
+		));
+		assert_ok!(AssetRegistry::update_erc20_asset(
+			Origin::signed(ALICE),
+			ERC20_ADDRESS,
+			20
+		));
+		assert_eq!(
+			AssetRegistry::asset_metadatas(CurrencyId::ERC20(ERC20_ADDRESS)).map(|metadata| metadata.minimal_balance),
+			Some(20)
+		);
+	});
+}
+
+#[test]
+fn register_foreign_asset_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(AssetRegistry::register_foreign_asset(
+			Origin::signed(ALICE),
+			Box::new(foreign_asset_metadata(1))
+		));
+		System::assert_last_event(Event::AssetRegistry(crate::Event::AssetRegistered(
+			CurrencyId::ForeignAsset(0),
+			foreign_asset_metadata(1),
+		)));
+
+		assert_ok!(AssetRegistry::register_foreign_asset(
+			Origin::signed(ALICE),
+			Box::new(foreign_asset_metadata(2))
+		));
+		assert_eq!(AssetRegistry::next_foreign_asset_id(), 2);
+		assert_eq!(
+			AssetRegistry::asset_metadatas(CurrencyId::ForeignAsset(1)),
+			Some(foreign_asset_metadata(2))
+		);
+	});
+}
+
+#[test]
+fn register_foreign_asset_should_fail_on_overflow() {
+	ExtBuilder::default().build().execute_with(|| {
+		NextForeignAssetId::<Runtime>::put(ForeignAssetId::max_value());
+		assert_noop!(
+			AssetRegistry::register_foreign_asset(Origin::signed(ALICE), Box::new(foreign_asset_metadata(1))),
+			Error::<Runtime>::ForeignAssetIdOverflow
+		);
+	});
+}
+
+#[test]
+fn update_foreign_asset_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			AssetRegistry::update_foreign_asset(Origin::signed(ALICE), 0, Box::new(foreign_asset_metadata(2))),
+			Error::<Runtime>::AssetIdNotExists
+		);
+
+		assert_ok!(AssetRegistry::register_foreign_asset(
+			Origin::signed(ALICE),
+			Box::new(foreign_asset_metadata(1))
+		));
+		assert_ok!(AssetRegistry::update_foreign_asset(
+			Origin::signed(ALICE),
+			0,
+			Box::new(foreign_asset_metadata(2))
+		));
+		assert_eq!(
+			AssetRegistry::asset_metadatas(CurrencyId::ForeignAsset(0)),
+			Some(foreign_asset_metadata(2))
+		);
+		System::assert_last_event(Event::AssetRegistry(crate::Event::AssetUpdated(
+			CurrencyId::ForeignAsset(0),
+			foreign_asset_metadata(2),
+		)));
+	});
+}
+
+#[test]
+fn register_native_asset_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		let usdd = CurrencyId::Token(TokenSymbol::USDD);
+		assert_noop!(
+			AssetRegistry::register_native_asset(
+				Origin::signed(ALICE),
+				CurrencyId::ForeignAsset(0),
+				Box::new(foreign_asset_metadata(1))
+			),
+			Error::<Runtime>::InvalidCurrencyId
+		);
+
+		assert_ok!(AssetRegistry::register_native_asset(
+			Origin::signed(ALICE),
+			usdd,
+			Box::new(foreign_asset_metadata(1))
+		));
+		assert_noop!(
+			AssetRegistry::register_native_asset(Origin::signed(ALICE), usdd, Box::new(foreign_asset_metadata(1))),
+			Error::<Runtime>::AssetIdExisted
+		);
+
+		assert_ok!(AssetRegistry::update_native_asset(
+			Origin::signed(ALICE),
+			usdd,
+			Box::new(foreign_asset_metadata(3))
+		));
+		assert_eq!(AssetRegistry::asset_metadatas(usdd), Some(foreign_asset_metadata(3)));
+	});
+}
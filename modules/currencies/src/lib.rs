@@ -9,7 +9,7 @@ use frame_support::{
 	pallet_prelude::*,
 	traits::{
 		Currency as PalletCurrency, ExistenceRequirement, LockableCurrency as PalletLockableCurrency,
-		ReservableCurrency as PalletReservableCurrency, WithdrawReasons,
+		ReservableCurrency as PalletReservableCurrency, StorageVersion, WithdrawReasons,
 	},
 };
 use frame_system::pallet_prelude::*;
@@ -30,13 +30,16 @@ use sp_runtime::{
 	DispatchError, DispatchResult,
 };
 use sp_std::{
+	collections::btree_map::BTreeMap,
 	convert::{TryFrom, TryInto},
 	fmt::Debug,
 	marker, result,
+	vec::Vec,
 };
-use support::{EVMBridge, InvokeContext};
+use support::{AssetRegistry, CurrencyMetadata, EVMBridge, InvokeContext};
 
 mod default_weight;
+pub mod migrations;
 mod mock;
 mod tests;
 
@@ -61,6 +64,9 @@ type AmountOf<T> =
 pub mod module {
 	use super::*;
 
+	/// Version 1 tracks the reserved ERC20 balances on-chain.
+	pub(crate) const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
 		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
@@ -78,6 +84,14 @@ pub mod module {
 		/// Mapping from address to account id.
 		type AddressMapping: AddressMapping<Self::AccountId>;
 		type EVMBridge: EVMBridge<Self::AccountId, BalanceOf<Self>>;
+
+		/// The asset registry providing cached currency metadata.
+		type AssetRegistry: AssetRegistry<BalanceOf<Self>>;
+
+		/// The ERC20 contracts and accounts which reserved before the reserved
+		/// balances were tracked on-chain. Their reserve address balances are
+		/// migrated once, any other reserve address balance is not reserved.
+		type Erc20ReservesBeforeTracking: Get<Vec<(EvmAddress, Self::AccountId)>>;
 	}
 
 	#[pallet::error]
@@ -105,11 +119,46 @@ pub mod module {
 		Withdrawn(CurrencyIdOf<T>, T::AccountId, BalanceOf<T>),
 	}
 
+	/// The ERC20 balance reserved for an account, held by its reserve
+	/// address.
+	///
+	/// Erc20ReservedBalance: double_map contract, AccountId => Balance
+	#[pallet::storage]
+	#[pallet::getter(fn erc20_reserved_balance)]
+	pub type Erc20ReservedBalance<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, EvmAddress, Blake2_128Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
+
+	/// The ERC20 balance locks of an account. The largest lock is held by its
+	/// lock address.
+	///
+	/// Erc20Locks: double_map contract, AccountId => BTreeMap<LockIdentifier,
+	/// Balance>
+	#[pallet::storage]
+	#[pallet::getter(fn erc20_locks)]
+	pub type Erc20Locks<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		EvmAddress,
+		Blake2_128Concat,
+		T::AccountId,
+		BTreeMap<LockIdentifier, BalanceOf<T>>,
+		ValueQuery,
+	>;
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(PhantomData<T>);
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let mut weight: Weight = T::DbWeight::get().reads(1);
+			if StorageVersion::get::<Pallet<T>>() < 1 {
+				weight = weight.saturating_add(migrations::migrate_to_v1::<T>());
+			}
+			weight
+		}
+	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
@@ -177,7 +226,9 @@ impl<T: Config> MultiCurrency<T::AccountId> for Pallet<T> {
 
 	fn minimum_balance(currency_id: Self::CurrencyId) -> Self::Balance {
 		match currency_id {
-			CurrencyId::ERC20(_) => Default::default(),
+			CurrencyId::ERC20(_) => T::AssetRegistry::asset_metadata(currency_id)
+				.map(|metadata| metadata.minimal_balance)
+				.unwrap_or_default(),
 			CurrencyId::Token(TokenSymbol::DUST) => T::NativeCurrency::minimum_balance(),
 			_ => T::MultiCurrency::minimum_balance(currency_id),
 		}
@@ -199,15 +250,7 @@ impl<T: Config> MultiCurrency<T::AccountId> for Pallet<T> {
 	fn total_balance(currency_id: Self::CurrencyId, who: &T::AccountId) -> Self::Balance {
 		match currency_id {
 			CurrencyId::ERC20(contract) => {
-				if let Some(address) = T::AddressMapping::get_evm_address(who) {
-					let context = InvokeContext {
-						contract,
-						sender: Default::default(),
-						origin: Default::default(),
-					};
-					return T::EVMBridge::balance_of(context, address).unwrap_or_default();
-				}
-				Default::default()
+				Self::free_balance(currency_id, who).saturating_add(Self::erc20_reserved_balance(contract, who))
 			}
 			CurrencyId::Token(TokenSymbol::DUST) => T::NativeCurrency::total_balance(who),
 			_ => T::MultiCurrency::total_balance(currency_id, who),
@@ -218,12 +261,9 @@ impl<T: Config> MultiCurrency<T::AccountId> for Pallet<T> {
 		match currency_id {
 			CurrencyId::ERC20(contract) => {
 				if let Some(address) = T::AddressMapping::get_evm_address(who) {
-					let context = InvokeContext {
-						contract,
-						sender: Default::default(),
-						origin: Default::default(),
-					};
-					return T::EVMBridge::balance_of(context, address).unwrap_or_default();
+					// locked funds are held by the lock address, but still count as free
+					return Self::erc20_balance_of(contract, address)
+						.saturating_add(Self::erc20_locked_balance(contract, who));
 				}
 				Default::default()
 			}
@@ -236,15 +276,7 @@ impl<T: Config> MultiCurrency<T::AccountId> for Pallet<T> {
 		match currency_id {
 			CurrencyId::ERC20(contract) => {
 				let address = T::AddressMapping::get_evm_address(who).ok_or(Error::<T>::EvmAccountNotFound)?;
-				let balance = T::EVMBridge::balance_of(
-					InvokeContext {
-						contract,
-						sender: Default::default(),
-						origin: Default::default(),
-					},
-					address,
-				)
-				.unwrap_or_default();
+				let balance = Self::erc20_balance_of(contract, address);
 				ensure!(balance >= amount, Error::<T>::BalanceTooLow);
 				Ok(())
 			}
@@ -354,7 +386,11 @@ impl<T: Config> MultiLockableCurrency<T::AccountId> for Pallet<T> {
 		amount: Self::Balance,
 	) -> DispatchResult {
 		match currency_id {
-			CurrencyId::ERC20(_) => Err(Error::<T>::ERC20InvalidOperation.into()),
+			CurrencyId::ERC20(contract) => {
+				let mut locks = Self::erc20_locks(contract, who);
+				locks.insert(lock_id, amount);
+				Self::update_erc20_locks(contract, who, locks)
+			}
 			CurrencyId::Token(TokenSymbol::DUST) => T::NativeCurrency::set_lock(lock_id, who, amount),
 			_ => T::MultiCurrency::set_lock(lock_id, currency_id, who, amount),
 		}
@@ -367,7 +403,12 @@ impl<T: Config> MultiLockableCurrency<T::AccountId> for Pallet<T> {
 		amount: Self::Balance,
 	) -> DispatchResult {
 		match currency_id {
-			CurrencyId::ERC20(_) => Err(Error::<T>::ERC20InvalidOperation.into()),
+			CurrencyId::ERC20(contract) => {
+				let mut locks = Self::erc20_locks(contract, who);
+				let locked = locks.get(&lock_id).copied().unwrap_or_default();
+				locks.insert(lock_id, locked.max(amount));
+				Self::update_erc20_locks(contract, who, locks)
+			}
 			CurrencyId::Token(TokenSymbol::DUST) => T::NativeCurrency::extend_lock(lock_id, who, amount),
 			_ => T::MultiCurrency::extend_lock(lock_id, currency_id, who, amount),
 		}
@@ -375,7 +416,11 @@ impl<T: Config> MultiLockableCurrency<T::AccountId> for Pallet<T> {
 
 	fn remove_lock(lock_id: LockIdentifier, currency_id: Self::CurrencyId, who: &T::AccountId) -> DispatchResult {
 		match currency_id {
-			CurrencyId::ERC20(_) => Err(Error::<T>::ERC20InvalidOperation.into()),
+			CurrencyId::ERC20(contract) => {
+				let mut locks = Self::erc20_locks(contract, who);
+				locks.remove(&lock_id);
+				Self::update_erc20_locks(contract, who, locks)
+			}
 			CurrencyId::Token(TokenSymbol::DUST) => T::NativeCurrency::remove_lock(lock_id, who),
 			_ => T::MultiCurrency::remove_lock(lock_id, currency_id, who),
 		}
@@ -414,20 +459,7 @@ impl<T: Config> MultiReservableCurrency<T::AccountId> for Pallet<T> {
 
 	fn reserved_balance(currency_id: Self::CurrencyId, who: &T::AccountId) -> Self::Balance {
 		match currency_id {
-			CurrencyId::ERC20(contract) => {
-				if let Some(address) = T::AddressMapping::get_evm_address(who) {
-					return T::EVMBridge::balance_of(
-						InvokeContext {
-							contract,
-							sender: Default::default(),
-							origin: Default::default(),
-						},
-						reserve_address(address),
-					)
-					.unwrap_or_default();
-				}
-				Default::default()
-			}
+			CurrencyId::ERC20(contract) => Self::erc20_reserved_balance(contract, who),
 			CurrencyId::Token(TokenSymbol::DUST) => T::NativeCurrency::reserved_balance(who),
 			_ => T::MultiCurrency::reserved_balance(currency_id, who),
 		}
@@ -448,7 +480,10 @@ impl<T: Config> MultiReservableCurrency<T::AccountId> for Pallet<T> {
 					},
 					reserve_address(address),
 					value,
-				)
+				)?;
+				let reserved = Self::erc20_reserved_balance(contract, who).saturating_add(value);
+				Self::set_erc20_reserved_balance(contract, who, reserved);
+				Ok(())
 			}
 			CurrencyId::Token(TokenSymbol::DUST) => T::NativeCurrency::reserve(who, value),
 			_ => T::MultiCurrency::reserve(currency_id, who, value),
@@ -462,27 +497,24 @@ impl<T: Config> MultiReservableCurrency<T::AccountId> for Pallet<T> {
 					return value;
 				}
 				if let Some(address) = T::AddressMapping::get_evm_address(who) {
-					let sender = reserve_address(address);
-					let reserved_balance = T::EVMBridge::balance_of(
-						InvokeContext {
-							contract,
-							sender: Default::default(),
-							origin: Default::default(),
-						},
-						sender,
-					)
-					.unwrap_or_default();
+					let reserved_balance = Self::erc20_reserved_balance(contract, who);
 					let actual = reserved_balance.min(value);
+					if actual.is_zero() {
+						return value;
+					}
 					return match T::EVMBridge::transfer(
 						InvokeContext {
 							contract,
-							sender,
+							sender: reserve_address(address),
 							origin: address,
 						},
 						address,
 						actual,
 					) {
-						Ok(_) => value - actual,
+						Ok(_) => {
+							Self::set_erc20_reserved_balance(contract, who, reserved_balance - actual);
+							value - actual
+						}
 						Err(_) => value,
 					};
 				}
@@ -521,16 +553,11 @@ impl<T: Config> MultiReservableCurrency<T::AccountId> for Pallet<T> {
 				let slashed_reserve_address = reserve_address(slashed_address);
 				let beneficiary_reserve_address = reserve_address(beneficiary_address);
 
-				let slashed_reserved_balance = T::EVMBridge::balance_of(
-					InvokeContext {
-						contract,
-						sender: Default::default(),
-						origin: Default::default(),
-					},
-					slashed_reserve_address,
-				)
-				.unwrap_or_default();
+				let slashed_reserved_balance = Self::erc20_reserved_balance(contract, slashed);
 				let actual = slashed_reserved_balance.min(value);
+				if actual.is_zero() {
+					return Ok(value);
+				}
 				match status {
 					BalanceStatus::Free => T::EVMBridge::transfer(
 						InvokeContext {
@@ -549,9 +576,16 @@ impl<T: Config> MultiReservableCurrency<T::AccountId> for Pallet<T> {
 						},
 						beneficiary_reserve_address,
 						actual,
-					),
+					)
+					.map(|_| {
+						let reserved = Self::erc20_reserved_balance(contract, beneficiary).saturating_add(actual);
+						Self::set_erc20_reserved_balance(contract, beneficiary, reserved);
+					}),
 				}
-				.map(|_| value - actual)
+				.map(|_| {
+					Self::set_erc20_reserved_balance(contract, slashed, slashed_reserved_balance - actual);
+					value - actual
+				})
 			}
 			CurrencyId::Token(TokenSymbol::DUST) => {
 				T::NativeCurrency::repatriate_reserved(slashed, beneficiary, value, status)
@@ -854,7 +888,126 @@ impl<T: Config> TransferAll<T::AccountId> for Pallet<T> {
 	}
 }
 
+impl<T: Config> CurrencyMetadata for Pallet<T> {
+	/// The name of `currency_id`, read from the asset registry. Unregistered
+	/// ERC20 contracts are queried directly.
+	fn name(currency_id: CurrencyId) -> Option<Vec<u8>> {
+		match T::AssetRegistry::asset_metadata(currency_id) {
+			Some(metadata) => Some(metadata.name),
+			None => match currency_id {
+				CurrencyId::ERC20(contract) => T::EVMBridge::name(Self::erc20_context(contract)).ok(),
+				_ => None,
+			},
+		}
+	}
+
+	/// The symbol of `currency_id`, read from the asset registry. Unregistered
+	/// ERC20 contracts are queried directly.
+	fn symbol(currency_id: CurrencyId) -> Option<Vec<u8>> {
+		match T::AssetRegistry::asset_metadata(currency_id) {
+			Some(metadata) => Some(metadata.symbol),
+			None => match currency_id {
+				CurrencyId::ERC20(contract) => T::EVMBridge::symbol(Self::erc20_context(contract)).ok(),
+				_ => None,
+			},
+		}
+	}
+
+	/// The decimals of `currency_id`, read from the asset registry.
+	/// Unregistered ERC20 contracts are queried directly.
+	fn decimals(currency_id: CurrencyId) -> Option<u8> {
+		match T::AssetRegistry::asset_metadata(currency_id) {
+			Some(metadata) => Some(metadata.decimals),
+			None => match currency_id {
+				CurrencyId::ERC20(contract) => T::EVMBridge::decimals(Self::erc20_context(contract)).ok(),
+				_ => None,
+			},
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	fn erc20_context(contract: EvmAddress) -> InvokeContext {
+		InvokeContext {
+			contract,
+			sender: Default::default(),
+			origin: Default::default(),
+		}
+	}
+
+	fn erc20_balance_of(contract: EvmAddress, address: EvmAddress) -> BalanceOf<T> {
+		T::EVMBridge::balance_of(Self::erc20_context(contract), address).unwrap_or_default()
+	}
+
+	fn erc20_locked_balance(contract: EvmAddress, who: &T::AccountId) -> BalanceOf<T> {
+		Self::erc20_locks(contract, who)
+			.values()
+			.copied()
+			.max()
+			.unwrap_or_default()
+	}
+
+	fn set_erc20_reserved_balance(contract: EvmAddress, who: &T::AccountId, reserved: BalanceOf<T>) {
+		if reserved.is_zero() {
+			Erc20ReservedBalance::<T>::remove(contract, who);
+		} else {
+			Erc20ReservedBalance::<T>::insert(contract, who, reserved);
+		}
+	}
+
+	/// Store the new `locks` of `who` and move the difference between the
+	/// old and the new largest lock between its address and its lock address.
+	fn update_erc20_locks(
+		contract: EvmAddress,
+		who: &T::AccountId,
+		locks: BTreeMap<LockIdentifier, BalanceOf<T>>,
+	) -> DispatchResult {
+		let address = T::AddressMapping::get_evm_address(who).ok_or(Error::<T>::EvmAccountNotFound)?;
+		let old_locked = Self::erc20_locked_balance(contract, who);
+		let new_locked = locks.values().copied().max().unwrap_or_default();
+
+		if new_locked > old_locked {
+			let amount = new_locked - old_locked;
+			ensure!(
+				Self::erc20_balance_of(contract, address) >= amount,
+				Error::<T>::BalanceTooLow
+			);
+			T::EVMBridge::transfer(
+				InvokeContext {
+					contract,
+					sender: address,
+					origin: address,
+				},
+				lock_address(address),
+				amount,
+			)?;
+		} else if new_locked < old_locked {
+			T::EVMBridge::transfer(
+				InvokeContext {
+					contract,
+					sender: lock_address(address),
+					origin: address,
+				},
+				address,
+				old_locked - new_locked,
+			)?;
+		}
+
+		if locks.is_empty() {
+			Erc20Locks::<T>::remove(contract, who);
+		} else {
+			Erc20Locks::<T>::insert(contract, who, locks);
+		}
+		Ok(())
+	}
+}
+
 fn reserve_address(address: EvmAddress) -> EvmAddress {
 	let payload = (b"erc20:", address);
 	EvmAddress::from_slice(&payload.using_encoded(blake2_256)[0..20])
 }
+
+fn lock_address(address: EvmAddress) -> EvmAddress {
+	let payload = (b"erc20lock:", address);
+	EvmAddress::from_slice(&payload.using_encoded(blake2_256)[0..20])
+}
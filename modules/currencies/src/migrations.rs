@@ -0,0 +1,20 @@
+//! Storage migrations for the currencies module.
+
+use super::*;
+
+/// Track the ERC20 balances reserved before version 1, held by the reserve
+/// addresses of `Config::Erc20ReservesBeforeTracking`. Balances of other
+/// reserve addresses were sent to them directly and are not reserved.
+pub fn migrate_to_v1<T: Config>() -> Weight {
+	let mut count: Weight = 0;
+	for (contract, who) in T::Erc20ReservesBeforeTracking::get() {
+		count += 1;
+		if let Some(address) = T::AddressMapping::get_evm_address(&who) {
+			let reserved = Pallet::<T>::erc20_balance_of(contract, reserve_address(address));
+			Pallet::<T>::set_erc20_reserved_balance(contract, &who, reserved);
+		}
+	}
+	StorageVersion::new(1).put::<Pallet<T>>();
+
+	T::DbWeight::get().reads_writes(count.saturating_mul(2) + 1, count + 1)
+}
@@ -148,6 +148,7 @@ impl module_evm::Config for Runtime {
 
 	type DeveloperDeposit = DeveloperDeposit;
 	type DeploymentFee = DeploymentFee;
+	type OnDeploymentFee = ();
 	type FreeDeploymentOrigin = EnsureSignedBy<CouncilAccount, AccountId32>;
 
 	type WeightInfo = ();
@@ -157,6 +158,10 @@ impl module_evm_bridge::Config for Runtime {
 	type EVM = EVM;
 }
 
+parameter_types! {
+	pub Erc20ReservesBeforeTracking: Vec<(H160, AccountId)> = vec![(ERC20_ADDRESS, alice())];
+}
+
 impl Config for Runtime {
 	type Event = Event;
 	type MultiCurrency = Tokens;
@@ -164,6 +169,24 @@ impl Config for Runtime {
 	type WeightInfo = ();
 	type AddressMapping = MockAddressMapping;
 	type EVMBridge = EVMBridge;
+	type AssetRegistry = MockAssetRegistry;
+	type Erc20ReservesBeforeTracking = Erc20ReservesBeforeTracking;
+}
+
+pub struct MockAssetRegistry;
+impl support::AssetRegistry<Balance> for MockAssetRegistry {
+	fn asset_metadata(currency_id: CurrencyId) -> Option<support::AssetMetadata<Balance>> {
+		if currency_id == ERC20 {
+			Some(support::AssetMetadata {
+				name: b"Test Token".to_vec(),
+				symbol: b"TT".to_vec(),
+				decimals: 17,
+				minimal_balance: 10,
+			})
+		} else {
+			None
+		}
+	}
 }
 
 pub type NativeCurrency = Currency<Runtime, GetNativeCurrencyId>;
@@ -206,6 +229,7 @@ pub const BOB: AccountId = AccountId::new([2u8; 32]);
 pub const EVA: AccountId = AccountId::new([5u8; 32]);
 
 pub const ID_1: LockIdentifier = *b"1       ";
+pub const ID_2: LockIdentifier = *b"2       ";
 
 pub const ERC20_ADDRESS: H160 = H160([32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1]);
 pub const ERC20: CurrencyId = CurrencyId::ERC20(ERC20_ADDRESS);
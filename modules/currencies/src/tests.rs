@@ -6,10 +6,11 @@ use super::*;
 use frame_support::{assert_noop, assert_ok};
 use mock::{
 	alice, bob, AccountId, AdaptedBasicCurrency, Currencies, Event, ExtBuilder, NativeCurrency, Origin, PalletBalances,
-	Runtime, System, Tokens, ALICE, BOB, ERC20, EVA, EVM, ID_1, NATIVE_CURRENCY_ID, X_TOKEN_ID,
+	Runtime, System, Tokens, ALICE, BOB, ERC20, ERC20_ADDRESS, EVA, EVM, ID_1, ID_2, NATIVE_CURRENCY_ID, X_TOKEN_ID,
 };
 use sp_core::H160;
 use sp_runtime::traits::BadOrigin;
+use sp_std::str::FromStr;
 use support::EVM as EVMTrait;
 
 #[test]
@@ -296,6 +297,20 @@ fn erc20_total_issuance_should_work() {
 	});
 }
 
+#[test]
+fn erc20_metadata_should_use_asset_registry() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(Currencies::minimum_balance(ERC20), 10);
+		assert_eq!(Currencies::name(ERC20), Some(b"Test Token".to_vec()));
+		assert_eq!(Currencies::symbol(ERC20), Some(b"TT".to_vec()));
+		assert_eq!(Currencies::decimals(ERC20), Some(17));
+
+		assert_eq!(Currencies::minimum_balance(CurrencyId::ERC20(H160::default())), 0);
+		assert_eq!(Currencies::name(CurrencyId::ForeignAsset(0)), None);
+		assert_eq!(Currencies::decimals(X_TOKEN_ID), None);
+	});
+}
+
 #[test]
 fn erc20_free_balance_should_work() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -464,19 +479,127 @@ fn erc20_should_not_slash() {
 }
 
 #[test]
-fn erc20_should_not_be_lockable() {
+fn erc20_reserve_should_track_reserved_balance() {
+	ExtBuilder::default()
+		.balances(vec![(alice(), NATIVE_CURRENCY_ID, 100000)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(Currencies::reserve(ERC20, &alice(), 100));
+			assert_eq!(Currencies::erc20_reserved_balance(ERC20_ADDRESS, &alice()), 100);
+			assert_eq!(Currencies::total_balance(ERC20, &alice()), u128::max_value());
+
+			// sending tokens to the reserve address directly doesn't increase the reserved balance
+			<EVM as EVMTrait<AccountId>>::set_origin(alice());
+			let reserve = reserve_address(H160::from_str("1000000000000000000000000000000000000001").unwrap());
+			assert_ok!(Currencies::transfer(
+				Origin::signed(alice()),
+				<Runtime as Config>::AddressMapping::get_account_id(&reserve),
+				ERC20,
+				10
+			));
+			assert_eq!(Currencies::reserved_balance(ERC20, &alice()), 100);
+
+			assert_eq!(Currencies::unreserve(ERC20, &alice(), 100), 0);
+			assert_eq!(Currencies::reserved_balance(ERC20, &alice()), 0);
+			assert!(!Erc20ReservedBalance::<Runtime>::contains_key(ERC20_ADDRESS, &alice()));
+		});
+}
+
+#[test]
+fn erc20_reserves_before_tracking_should_migrate() {
+	use frame_support::traits::{OnRuntimeUpgrade, StorageVersion};
+
+	ExtBuilder::default()
+		.balances(vec![(alice(), NATIVE_CURRENCY_ID, 100000)])
+		.build()
+		.execute_with(|| {
+			// held by the reserve addresses before the reserves were tracked on-chain
+			<EVM as EVMTrait<AccountId>>::set_origin(alice());
+			let alice_reserve = reserve_address(H160::from_str("1000000000000000000000000000000000000001").unwrap());
+			let bob_reserve = reserve_address(H160::from_str("1000000000000000000000000000000000000002").unwrap());
+			assert_ok!(Currencies::transfer(
+				Origin::signed(alice()),
+				<Runtime as Config>::AddressMapping::get_account_id(&alice_reserve),
+				ERC20,
+				100
+			));
+			assert_ok!(Currencies::transfer(
+				Origin::signed(alice()),
+				<Runtime as Config>::AddressMapping::get_account_id(&bob_reserve),
+				ERC20,
+				30
+			));
+			assert_eq!(Currencies::reserved_balance(ERC20, &alice()), 0);
+
+			StorageVersion::new(0).put::<Currencies>();
+			Currencies::on_runtime_upgrade();
+			assert_eq!(StorageVersion::get::<Currencies>(), STORAGE_VERSION);
+
+			// only the listed reserves are tracked
+			assert_eq!(Currencies::reserved_balance(ERC20, &alice()), 100);
+			assert_eq!(Currencies::reserved_balance(ERC20, &bob()), 0);
+
+			assert_eq!(Currencies::unreserve(ERC20, &alice(), 200), 100);
+			assert_eq!(Currencies::reserved_balance(ERC20, &alice()), 0);
+			assert_eq!(Currencies::free_balance(ERC20, &alice()), u128::max_value() - 30);
+		});
+}
+
+#[test]
+fn erc20_lockable_should_work() {
+	ExtBuilder::default()
+		.balances(vec![(alice(), NATIVE_CURRENCY_ID, 100000)])
+		.build()
+		.execute_with(|| {
+			<EVM as EVMTrait<AccountId>>::set_origin(alice());
+			assert_ok!(Currencies::transfer(Origin::signed(alice()), bob(), ERC20, 100));
+
+			assert_ok!(Currencies::set_lock(ID_1, ERC20, &bob(), 30));
+			assert_eq!(Currencies::erc20_locks(ERC20_ADDRESS, &bob()).get(&ID_1), Some(&30));
+			assert_eq!(Currencies::free_balance(ERC20, &bob()), 100);
+			assert_eq!(Currencies::total_balance(ERC20, &bob()), 100);
+			assert_ok!(Currencies::ensure_can_withdraw(ERC20, &bob(), 70));
+			assert_noop!(
+				Currencies::ensure_can_withdraw(ERC20, &bob(), 71),
+				Error::<Runtime>::BalanceTooLow
+			);
+
+			// the largest lock is held
+			assert_ok!(Currencies::set_lock(ID_2, ERC20, &bob(), 20));
+			assert_ok!(Currencies::extend_lock(ID_1, ERC20, &bob(), 10));
+			assert_eq!(Currencies::erc20_locks(ERC20_ADDRESS, &bob()).get(&ID_1), Some(&30));
+			assert_noop!(
+				Currencies::ensure_can_withdraw(ERC20, &bob(), 71),
+				Error::<Runtime>::BalanceTooLow
+			);
+			assert_ok!(Currencies::extend_lock(ID_1, ERC20, &bob(), 50));
+			assert_noop!(
+				Currencies::ensure_can_withdraw(ERC20, &bob(), 51),
+				Error::<Runtime>::BalanceTooLow
+			);
+
+			// locked funds can't be reserved
+			assert!(!Currencies::can_reserve(ERC20, &bob(), 51));
+			assert_noop!(
+				Currencies::set_lock(ID_2, ERC20, &bob(), 101),
+				Error::<Runtime>::BalanceTooLow
+			);
+
+			assert_ok!(Currencies::remove_lock(ID_1, ERC20, &bob()));
+			assert_ok!(Currencies::ensure_can_withdraw(ERC20, &bob(), 80));
+			assert_ok!(Currencies::remove_lock(ID_2, ERC20, &bob()));
+			assert_ok!(Currencies::ensure_can_withdraw(ERC20, &bob(), 100));
+			assert_eq!(Currencies::free_balance(ERC20, &bob()), 100);
+			assert!(!Erc20Locks::<Runtime>::contains_key(ERC20_ADDRESS, &bob()));
+		});
+}
+
+#[test]
+fn erc20_lock_requires_evm_address() {
 	ExtBuilder::default().build().execute_with(|| {
 		assert_noop!(
-			Currencies::set_lock(ID_1, ERC20, &alice(), 1),
-			Error::<Runtime>::ERC20InvalidOperation
-		);
-		assert_noop!(
-			Currencies::extend_lock(ID_1, ERC20, &alice(), 1),
-			Error::<Runtime>::ERC20InvalidOperation
-		);
-		assert_noop!(
-			Currencies::remove_lock(ID_1, ERC20, &alice()),
-			Error::<Runtime>::ERC20InvalidOperation
+			Currencies::set_lock(ID_1, ERC20, &ALICE, 1),
+			Error::<Runtime>::EvmAccountNotFound
 		);
 	});
 }
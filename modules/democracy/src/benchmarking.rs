@@ -0,0 +1,151 @@
+#![cfg(feature = "runtime-benchmarks")]
+
+use crate::*;
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite};
+use frame_support::traits::{EnsureOrigin, Get, OnInitialize};
+use frame_system::RawOrigin;
+use sp_runtime::traits::Bounded;
+
+benchmarks! {
+	where_clause { where T::Proposal: From<frame_system::Call<T>> }
+
+	propose {
+		let alice = funded_account::<T>("alice", 0);
+		let value = T::MinimumDeposit::get();
+
+	}: _(RawOrigin::Signed(alice), remark_proposal::<T>(0), value)
+
+	second {
+		// the proposal already has all backers but one
+		let s in 0..T::MaxSeconds::get().saturating_sub(2);
+		let proposal = add_proposal::<T>(0);
+		for i in 0..s {
+			let _ = Pallet::<T>::second(RawOrigin::Signed(funded_account::<T>("seconder", i)).into(), proposal);
+		}
+		let bob = funded_account::<T>("bob", 0);
+
+	}: _(RawOrigin::Signed(bob), proposal)
+
+	vote {
+		// bob replaces a vote among the most votes he can have
+		let r in 1..T::MaxVotes::get();
+		let bob = funded_account::<T>("bob", 0);
+		for i in 0..r {
+			let ref_index = add_referendum::<T>(i);
+			let _ = Pallet::<T>::vote(RawOrigin::Signed(bob.clone()).into(), ref_index, true, 100u32.into());
+		}
+
+	}: _(RawOrigin::Signed(bob), r - 1, false, 200u32.into())
+
+	remove_vote {
+		let r in 1..T::MaxVotes::get();
+		let bob = funded_account::<T>("bob", 0);
+		for i in 0..r {
+			let ref_index = add_referendum::<T>(i);
+			let _ = Pallet::<T>::vote(RawOrigin::Signed(bob.clone()).into(), ref_index, true, 100u32.into());
+		}
+
+	}: _(RawOrigin::Signed(bob), 0)
+
+	unlock {
+		// every vote but one is on a cancelled referendum
+		let r in 1..T::MaxVotes::get();
+		let bob = funded_account::<T>("bob", 0);
+		for i in 0..r {
+			let ref_index = add_referendum::<T>(i);
+			let _ = Pallet::<T>::vote(RawOrigin::Signed(bob.clone()).into(), ref_index, true, 100u32.into());
+			if ref_index > 0 {
+				<ReferendumInfoOf<T>>::remove(ref_index);
+			}
+		}
+
+	}: _(RawOrigin::Signed(bob.clone()), bob)
+
+	fast_track {
+		let s in 1..T::MaxSeconds::get();
+		let proposal = add_backed_proposal::<T>(0, s);
+		let origin = T::FastTrackOrigin::successful_origin();
+
+	}: _<T::Origin>(origin, proposal, T::FastTrackVotingPeriod::get(), Zero::zero())
+
+	cancel_referendum {
+		let ref_index = add_referendum::<T>(0);
+		let origin = T::CancellationOrigin::successful_origin();
+
+	}: _<T::Origin>(origin, ref_index)
+
+	cancel_proposal {
+		let s in 1..T::MaxSeconds::get();
+		let proposal = add_backed_proposal::<T>(0, s);
+		let origin = T::CancellationOrigin::successful_origin();
+
+	}: _<T::Origin>(origin, proposal)
+
+	on_initialize {
+		// every open referendum ends and passes, while a proposal is tabled
+		let r in 0..100;
+		let bob = funded_account::<T>("bob", 0);
+		for i in 0..r {
+			let ref_index = add_referendum::<T>(i);
+			let _ = Pallet::<T>::vote(RawOrigin::Signed(bob.clone()).into(), ref_index, true, 100u32.into());
+			<VotingOf<T>>::remove(&bob);
+		}
+		add_backed_proposal::<T>(r, T::MaxSeconds::get());
+		let end = frame_system::Pallet::<T>::block_number().saturating_add(T::FastTrackVotingPeriod::get());
+		let launch = (end / T::LaunchPeriod::get() + One::one()) * T::LaunchPeriod::get();
+		frame_system::Pallet::<T>::set_block_number(launch);
+
+	}: {
+		Pallet::<T>::on_initialize(launch);
+	}
+}
+
+fn funded_account<T: Config>(name: &'static str, index: u32) -> T::AccountId {
+	let who: T::AccountId = account(name, index, 0);
+	T::Currency::make_free_balance_be(&who, BalanceOf::<T>::max_value() / 2u32.into());
+	who
+}
+
+fn remark_proposal<T: Config>(i: u32) -> Box<T::Proposal>
+where
+	T::Proposal: From<frame_system::Call<T>>,
+{
+	Box::new(frame_system::Call::<T>::remark(i.encode()).into())
+}
+
+fn add_proposal<T: Config>(i: u32) -> PropIndex
+where
+	T::Proposal: From<frame_system::Call<T>>,
+{
+	add_backed_proposal::<T>(i, 1)
+}
+
+/// Make a public proposal backed by `s` accounts, the proposer included.
+fn add_backed_proposal<T: Config>(i: u32, s: u32) -> PropIndex
+where
+	T::Proposal: From<frame_system::Call<T>>,
+{
+	let proposer = funded_account::<T>("proposer", i);
+	let _ = Pallet::<T>::propose(
+		RawOrigin::Signed(proposer).into(),
+		remark_proposal::<T>(i),
+		T::MinimumDeposit::get(),
+	);
+	let proposal = Pallet::<T>::public_prop_count() - 1;
+	for j in 1..s {
+		let _ = Pallet::<T>::second(RawOrigin::Signed(funded_account::<T>("seconder", j)).into(), proposal);
+	}
+	proposal
+}
+
+/// Start a referendum ending with the fast-track voting period.
+fn add_referendum<T: Config>(i: u32) -> ReferendumIndex
+where
+	T::Proposal: From<frame_system::Call<T>>,
+{
+	let end = frame_system::Pallet::<T>::block_number().saturating_add(T::FastTrackVotingPeriod::get());
+	Pallet::<T>::start_referendum(*remark_proposal::<T>(i), end, Zero::zero())
+}
+
+// auto-generate benchmark tests
+impl_benchmark_test_suite!(Pallet, mock::new_test_ext(), mock::Runtime);
@@ -0,0 +1,675 @@
+//! # Democracy
+//!
+//! Public proposals put to referendum, replacing the sudo key.
+//!
+//! Every launch period the most backed public proposal is tabled as a
+//! referendum. DUST holders vote with the balance they lock, weighed by the
+//! conviction of their PoC commitments, and stay locked until the proposal
+//! is enacted. A referendum passes with more ayes than nays once the locked
+//! balances reach the minimum turnout. The Tech Council can fast-track a
+//! proposal to a shorter referendum. Passed proposals are enacted with root
+//! origin through the scheduler.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use frame_support::{
+	pallet_prelude::*,
+	traits::{
+		schedule::{DispatchTime, Named as ScheduleNamed},
+		Currency, Imbalance, IsType, LockIdentifier, LockableCurrency, OnUnbalanced, ReservableCurrency,
+		WithdrawReasons,
+	},
+	weights::Weight,
+};
+use frame_system::pallet_prelude::*;
+use sp_runtime::{
+	traits::{Dispatchable, One, Saturating, Zero},
+	Perbill,
+};
+use sp_std::prelude::*;
+
+mod benchmarking;
+mod mock;
+mod tests;
+pub mod weights;
+
+pub use module::*;
+
+/// Index of a public proposal.
+pub type PropIndex = u32;
+/// Index of a referendum.
+pub type ReferendumIndex = u32;
+
+/// Lock on the balances backing the votes of an account.
+pub const DEMOCRACY_LOCK_ID: LockIdentifier = *b"dust/dem";
+pub type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+pub type NegativeImbalanceOf<T> =
+	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::NegativeImbalance;
+pub type ReferendumStatusOf<T> =
+	ReferendumStatus<<T as frame_system::Config>::BlockNumber, <T as Config>::Proposal, BalanceOf<T>>;
+
+/// Voting weight of the balance an account locks to vote, such as scaled by
+/// the conviction of its PoC commitments.
+pub trait VotingConviction<AccountId, Balance> {
+	fn voting_weight(who: &AccountId, locked: Balance) -> Balance;
+}
+
+impl<AccountId, Balance> VotingConviction<AccountId, Balance> for () {
+	fn voting_weight(_who: &AccountId, locked: Balance) -> Balance {
+		locked
+	}
+}
+
+/// A vote of an account on a referendum.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub struct AccountVote<Balance> {
+	pub aye: bool,
+	/// Balance locked until the proposal is enacted
+	pub balance: Balance,
+	/// Locked balance weighed by the conviction of the voter, updated while
+	/// the referendum is ongoing
+	pub weight: Balance,
+}
+
+/// Voting weight for and against a referendum.
+#[derive(Encode, Decode, Clone, Default, PartialEq, Eq, RuntimeDebug)]
+pub struct Tally<Balance> {
+	pub ayes: Balance,
+	pub nays: Balance,
+	/// Balance locked by the voters
+	pub turnout: Balance,
+}
+
+impl<Balance: Saturating + Copy> Tally<Balance> {
+	fn add(&mut self, vote: &AccountVote<Balance>) {
+		self.turnout = self.turnout.saturating_add(vote.balance);
+		if vote.aye {
+			self.ayes = self.ayes.saturating_add(vote.weight);
+		} else {
+			self.nays = self.nays.saturating_add(vote.weight);
+		}
+	}
+
+	fn remove(&mut self, vote: &AccountVote<Balance>) {
+		self.turnout = self.turnout.saturating_sub(vote.balance);
+		if vote.aye {
+			self.ayes = self.ayes.saturating_sub(vote.weight);
+		} else {
+			self.nays = self.nays.saturating_sub(vote.weight);
+		}
+	}
+}
+
+/// A referendum open for voting.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct ReferendumStatus<BlockNumber, Proposal, Balance> {
+	/// Block the voting ends in
+	pub end: BlockNumber,
+	pub proposal: Proposal,
+	/// Blocks between the end and the enactment of a passed proposal
+	pub delay: BlockNumber,
+	pub tally: Tally<Balance>,
+}
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub enum ReferendumInfo<BlockNumber, Proposal, Balance> {
+	Ongoing(ReferendumStatus<BlockNumber, Proposal, Balance>),
+	/// `enactment` is the block a passed proposal is enacted in
+	Finished {
+		approved: bool,
+		end: BlockNumber,
+		enactment: Option<BlockNumber>,
+	},
+}
+
+pub trait WeightInfo {
+	fn propose() -> Weight;
+	fn second(s: u32) -> Weight;
+	fn vote(r: u32) -> Weight;
+	fn remove_vote(r: u32) -> Weight;
+	fn unlock(r: u32) -> Weight;
+	fn fast_track(s: u32) -> Weight;
+	fn cancel_referendum() -> Weight;
+	fn cancel_proposal(s: u32) -> Weight;
+	fn on_initialize(r: u32) -> Weight;
+}
+
+#[frame_support::pallet]
+pub mod module {
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+		type WeightInfo: WeightInfo;
+		/// Reservable currency for proposal deposits, lockable for votes
+		type Currency: Currency<Self::AccountId>
+			+ ReservableCurrency<Self::AccountId>
+			+ LockableCurrency<Self::AccountId, Moment = Self::BlockNumber>;
+		/// Call enacted by a passed referendum
+		type Proposal: Parameter + Dispatchable<Origin = Self::Origin>;
+		/// Voting weight of the locked balance
+		type Conviction: VotingConviction<Self::AccountId, BalanceOf<Self>>;
+		/// How often (in blocks) the most backed public proposal is tabled
+		#[pallet::constant]
+		type LaunchPeriod: Get<Self::BlockNumber>;
+		/// How long (in blocks) a referendum is open for voting
+		#[pallet::constant]
+		type VotingPeriod: Get<Self::BlockNumber>;
+		/// Shortest voting period of a fast-tracked referendum
+		#[pallet::constant]
+		type FastTrackVotingPeriod: Get<Self::BlockNumber>;
+		/// Blocks between the end and the enactment of a passed referendum
+		#[pallet::constant]
+		type EnactmentPeriod: Get<Self::BlockNumber>;
+		/// Share of the total issuance the voters need to lock for a
+		/// referendum to pass
+		#[pallet::constant]
+		type MinimumTurnout: Get<Perbill>;
+		/// Minimum deposit to make a public proposal
+		#[pallet::constant]
+		type MinimumDeposit: Get<BalanceOf<Self>>;
+		/// How many public proposals can wait to be tabled.
+		#[pallet::constant]
+		type MaxProposals: Get<u32>;
+		/// How many accounts can back a public proposal, the proposer included.
+		#[pallet::constant]
+		type MaxSeconds: Get<u32>;
+		/// How many referenda an account can vote on at once.
+		#[pallet::constant]
+		type MaxVotes: Get<u32>;
+		/// Origin allowed to table a public proposal with a shorter referendum
+		type FastTrackOrigin: EnsureOrigin<Self::Origin>;
+		/// Origin allowed to cancel public proposals and referenda
+		type CancellationOrigin: EnsureOrigin<Self::Origin>;
+		/// Receiver of the deposits of cancelled proposals
+		type Slash: OnUnbalanced<NegativeImbalanceOf<Self>>;
+		/// Origin passed referenda are enacted with
+		type PalletsOrigin: From<frame_system::RawOrigin<Self::AccountId>>;
+		/// Enacts the passed referenda
+		type Scheduler: ScheduleNamed<Self::BlockNumber, Self::Proposal, Self::PalletsOrigin>;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Deposit is below `MinimumDeposit`
+		ValueLow,
+		/// Public proposal not found
+		ProposalMissing,
+		/// Already have maximum allowed number of public proposals
+		TooManyProposals,
+		/// The proposal already has maximum allowed number of backers
+		TooManySeconds,
+		/// Referendum not found or no longer open for voting
+		ReferendumInvalid,
+		/// Not enough free funds to back the vote
+		InsufficientFunds,
+		/// Already voting on maximum allowed number of referenda
+		MaxVotesReached,
+		/// The account has not voted on the referendum
+		NotVoter,
+		/// Voting period is shorter than `FastTrackVotingPeriod`
+		VotingPeriodLow,
+		/// The vote backs a passed proposal not enacted yet
+		VoteLocked,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(fn deposit_event)]
+	#[pallet::metadata(T::AccountId = "AccountId", BalanceOf<T> = "Balance", T::BlockNumber = "BlockNumber")]
+	pub enum Event<T: Config> {
+		/// Proposal,Proposer,Deposit
+		Proposed(PropIndex, T::AccountId, BalanceOf<T>),
+		/// Proposal,Backer
+		Seconded(PropIndex, T::AccountId),
+		/// Proposal,Deposits returned to the backers
+		Tabled(PropIndex, BalanceOf<T>),
+		/// Proposal,Referendum it was fast-tracked to
+		FastTracked(PropIndex, ReferendumIndex),
+		/// Proposal,Deposits slashed
+		ProposalCancelled(PropIndex, BalanceOf<T>),
+		/// Referendum,Block the voting ends in
+		Started(ReferendumIndex, T::BlockNumber),
+		/// Voter,Referendum,Vote
+		Voted(T::AccountId, ReferendumIndex, AccountVote<BalanceOf<T>>),
+		/// Referendum,Block the proposal is enacted in
+		Passed(ReferendumIndex, T::BlockNumber),
+		NotPassed(ReferendumIndex),
+		Cancelled(ReferendumIndex),
+	}
+
+	/// Number of public proposals made so far.
+	#[pallet::storage]
+	#[pallet::getter(fn public_prop_count)]
+	pub type PublicPropCount<T: Config> = StorageValue<_,
+		PropIndex, ValueQuery>;
+
+	/// Public proposals waiting to be tabled, with their proposers.
+	#[pallet::storage]
+	#[pallet::getter(fn public_props)]
+	pub type PublicProps<T: Config> = StorageValue<_,
+		Vec<(PropIndex, T::Proposal, T::AccountId)>,
+		ValueQuery>;
+
+	/// Backers of a public proposal and the deposit each of them reserved.
+	#[pallet::storage]
+	#[pallet::getter(fn deposit_of)]
+	pub type DepositOf<T: Config> = StorageMap<_,
+		Twox64Concat, PropIndex, (Vec<T::AccountId>, BalanceOf<T>),
+		OptionQuery>;
+
+	/// Number of referenda started so far.
+	#[pallet::storage]
+	#[pallet::getter(fn referendum_count)]
+	pub type ReferendumCount<T: Config> = StorageValue<_,
+		ReferendumIndex, ValueQuery>;
+
+	/// Lowest referendum which may still be open for voting.
+	#[pallet::storage]
+	#[pallet::getter(fn lowest_unbaked)]
+	pub type LowestUnbaked<T: Config> = StorageValue<_,
+		ReferendumIndex, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn referendum_info)]
+	pub type ReferendumInfoOf<T: Config> = StorageMap<_,
+		Twox64Concat, ReferendumIndex, ReferendumInfo<T::BlockNumber, T::Proposal, BalanceOf<T>>,
+		OptionQuery>;
+
+	/// Votes of an account, sorted by referendum, kept until unlocked.
+	#[pallet::storage]
+	#[pallet::getter(fn voting_of)]
+	pub type VotingOf<T: Config> = StorageMap<_,
+		Blake2_128Concat, T::AccountId, Vec<(ReferendumIndex, AccountVote<BalanceOf<T>>)>,
+		ValueQuery>;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(PhantomData<T>);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+		fn on_initialize(n: T::BlockNumber) -> Weight {
+			// every referendum which may still be open is checked
+			let weight = T::WeightInfo::on_initialize(Self::referendum_count().saturating_sub(Self::lowest_unbaked()));
+
+			if (n % T::LaunchPeriod::get()).is_zero() {
+				Self::launch_public(n);
+			}
+			Self::bake_referenda(n);
+			weight
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+
+		/// Make a public proposal, reserving `value` as its deposit.
+		#[pallet::weight(T::WeightInfo::propose())]
+		pub fn propose(
+			origin: OriginFor<T>,
+			proposal: Box<T::Proposal>,
+			#[pallet::compact] value: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			ensure!(value >= T::MinimumDeposit::get(), Error::<T>::ValueLow);
+			let mut public_props = Self::public_props();
+			ensure!(
+				(public_props.len() as u32) < T::MaxProposals::get(),
+				Error::<T>::TooManyProposals
+			);
+
+			T::Currency::reserve(&who, value)?;
+
+			let index = Self::public_prop_count();
+			<PublicPropCount<T>>::put(index.saturating_add(1));
+			public_props.push((index, *proposal, who.clone()));
+			<PublicProps<T>>::put(public_props);
+			<DepositOf<T>>::insert(index, (vec![who.clone()], value));
+
+			Self::deposit_event(Event::Proposed(index, who, value));
+			Ok(().into())
+		}
+
+		/// Back a public proposal, reserving the same deposit as the proposer.
+		#[pallet::weight(T::WeightInfo::second(T::MaxSeconds::get()))]
+		pub fn second(origin: OriginFor<T>, #[pallet::compact] proposal: PropIndex) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			<DepositOf<T>>::try_mutate(proposal, |deposit| -> DispatchResult {
+				let (backers, value) = deposit.as_mut().ok_or(Error::<T>::ProposalMissing)?;
+				ensure!(
+					(backers.len() as u32) < T::MaxSeconds::get(),
+					Error::<T>::TooManySeconds
+				);
+				T::Currency::reserve(&who, *value)?;
+				backers.push(who.clone());
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::Seconded(proposal, who));
+			Ok(().into())
+		}
+
+		/// Vote on an ongoing referendum, replacing the previous vote on it.
+		///
+		/// `balance` is locked until the proposal is enacted, or the
+		/// referendum ends without passing, and is weighed by the conviction
+		/// of the voter.
+		#[pallet::weight(T::WeightInfo::vote(T::MaxVotes::get()))]
+		pub fn vote(
+			origin: OriginFor<T>,
+			#[pallet::compact] ref_index: ReferendumIndex,
+			aye: bool,
+			#[pallet::compact] balance: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			ensure!(
+				balance <= T::Currency::free_balance(&who),
+				Error::<T>::InsufficientFunds
+			);
+			let mut status = Self::referendum_status(ref_index)?;
+
+			let vote = AccountVote {
+				aye,
+				balance,
+				weight: T::Conviction::voting_weight(&who, balance),
+			};
+			let mut votes = Self::voting_of(&who);
+			match votes.binary_search_by_key(&ref_index, |(index, _)| *index) {
+				Ok(i) => {
+					status.tally.remove(&votes[i].1);
+					votes[i].1 = vote;
+				}
+				Err(i) => {
+					ensure!((votes.len() as u32) < T::MaxVotes::get(), Error::<T>::MaxVotesReached);
+					votes.insert(i, (ref_index, vote));
+				}
+			}
+			status.tally.add(&vote);
+
+			<ReferendumInfoOf<T>>::insert(ref_index, ReferendumInfo::Ongoing(status));
+			Self::update_votes(&who, votes);
+
+			Self::deposit_event(Event::Voted(who, ref_index, vote));
+			Ok(().into())
+		}
+
+		/// Withdraw a vote, taking it out of the tally if the referendum is
+		/// still ongoing, and unlock its balance. Votes on a passed proposal
+		/// stay until it is enacted.
+		#[pallet::weight(T::WeightInfo::remove_vote(T::MaxVotes::get()))]
+		pub fn remove_vote(
+			origin: OriginFor<T>,
+			#[pallet::compact] ref_index: ReferendumIndex,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let mut votes = Self::voting_of(&who);
+			let i = votes
+				.binary_search_by_key(&ref_index, |(index, _)| *index)
+				.map_err(|_| Error::<T>::NotVoter)?;
+			if let Ok(mut status) = Self::referendum_status(ref_index) {
+				status.tally.remove(&votes[i].1);
+				<ReferendumInfoOf<T>>::insert(ref_index, ReferendumInfo::Ongoing(status));
+			} else {
+				ensure!(!Self::is_locked(ref_index), Error::<T>::VoteLocked);
+			}
+			votes.remove(i);
+			Self::update_votes(&who, votes);
+			Ok(().into())
+		}
+
+		/// Unlock the balances of `target` backing votes on referenda which
+		/// are no longer ongoing, or whose proposal has been enacted.
+		#[pallet::weight(T::WeightInfo::unlock(T::MaxVotes::get()))]
+		pub fn unlock(origin: OriginFor<T>, target: T::AccountId) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			let mut votes = Self::voting_of(&target);
+			votes.retain(|(index, _)| Self::is_locked(*index));
+			Self::update_votes(&target, votes);
+			Ok(().into())
+		}
+
+		/// Table a public proposal right away, with a voting period of at
+		/// least `FastTrackVotingPeriod`, enacted `delay` blocks after it ends.
+		#[pallet::weight(T::WeightInfo::fast_track(T::MaxSeconds::get()))]
+		pub fn fast_track(
+			origin: OriginFor<T>,
+			#[pallet::compact] proposal: PropIndex,
+			voting_period: T::BlockNumber,
+			delay: T::BlockNumber,
+		) -> DispatchResultWithPostInfo {
+			T::FastTrackOrigin::ensure_origin(origin)?;
+			ensure!(
+				voting_period >= T::FastTrackVotingPeriod::get(),
+				Error::<T>::VotingPeriodLow
+			);
+			let call = Self::take_public_prop(proposal)?;
+			Self::return_deposits(proposal);
+
+			let now = frame_system::Pallet::<T>::block_number();
+			let ref_index = Self::start_referendum(call, now.saturating_add(voting_period), delay);
+
+			Self::deposit_event(Event::FastTracked(proposal, ref_index));
+			Ok(().into())
+		}
+
+		/// Cancel an ongoing referendum.
+		#[pallet::weight(T::WeightInfo::cancel_referendum())]
+		pub fn cancel_referendum(
+			origin: OriginFor<T>,
+			#[pallet::compact] ref_index: ReferendumIndex,
+		) -> DispatchResultWithPostInfo {
+			T::CancellationOrigin::ensure_origin(origin)?;
+			Self::referendum_status(ref_index)?;
+			<ReferendumInfoOf<T>>::remove(ref_index);
+
+			Self::deposit_event(Event::Cancelled(ref_index));
+			Ok(().into())
+		}
+
+		/// Remove a public proposal, slashing the deposits of its backers.
+		#[pallet::weight(T::WeightInfo::cancel_proposal(T::MaxSeconds::get()))]
+		pub fn cancel_proposal(
+			origin: OriginFor<T>,
+			#[pallet::compact] proposal: PropIndex,
+		) -> DispatchResultWithPostInfo {
+			T::CancellationOrigin::ensure_origin(origin)?;
+			Self::take_public_prop(proposal)?;
+
+			let mut slashed: BalanceOf<T> = Zero::zero();
+			if let Some((backers, value)) = <DepositOf<T>>::take(proposal) {
+				for backer in backers.iter() {
+					let (imbalance, _) = T::Currency::slash_reserved(backer, value);
+					slashed = slashed.saturating_add(imbalance.peek());
+					T::Slash::on_unbalanced(imbalance);
+				}
+			}
+
+			Self::deposit_event(Event::ProposalCancelled(proposal, slashed));
+			Ok(().into())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Status of a referendum open for voting.
+	pub fn referendum_status(ref_index: ReferendumIndex) -> Result<ReferendumStatusOf<T>, DispatchError> {
+		match Self::referendum_info(ref_index) {
+			Some(ReferendumInfo::Ongoing(status)) => Ok(status),
+			_ => Err(Error::<T>::ReferendumInvalid.into()),
+		}
+	}
+
+	/// Whether the votes on a referendum are still locked: it is ongoing, or
+	/// it passed and its proposal is not enacted yet.
+	fn is_locked(ref_index: ReferendumIndex) -> bool {
+		match Self::referendum_info(ref_index) {
+			Some(ReferendumInfo::Ongoing(_)) => true,
+			Some(ReferendumInfo::Finished {
+				enactment: Some(when), ..
+			}) => frame_system::Pallet::<T>::block_number() < when,
+			_ => false,
+		}
+	}
+
+	/// Total deposit backing a public proposal.
+	pub fn backing_for(proposal: PropIndex) -> Option<BalanceOf<T>> {
+		Self::deposit_of(proposal).map(|(backers, value)| value.saturating_mul((backers.len() as u32).into()))
+	}
+
+	fn take_public_prop(proposal: PropIndex) -> Result<T::Proposal, DispatchError> {
+		let mut public_props = Self::public_props();
+		let i = public_props
+			.iter()
+			.position(|(index, ..)| *index == proposal)
+			.ok_or(Error::<T>::ProposalMissing)?;
+		let (_, call, _) = public_props.remove(i);
+		<PublicProps<T>>::put(public_props);
+		Ok(call)
+	}
+
+	/// Unreserve the deposits of the backers of a tabled proposal.
+	fn return_deposits(proposal: PropIndex) -> BalanceOf<T> {
+		let mut returned: BalanceOf<T> = Zero::zero();
+		if let Some((backers, value)) = <DepositOf<T>>::take(proposal) {
+			for backer in backers.iter() {
+				T::Currency::unreserve(backer, value);
+				returned = returned.saturating_add(value);
+			}
+		}
+		returned
+	}
+
+	/// Table the most backed public proposal.
+	fn launch_public(now: T::BlockNumber) {
+		let winner = Self::public_props()
+			.iter()
+			.max_by_key(|(index, ..)| Self::backing_for(*index))
+			.map(|(index, ..)| *index);
+		if let Some(proposal) = winner {
+			if let Ok(call) = Self::take_public_prop(proposal) {
+				let returned = Self::return_deposits(proposal);
+				Self::deposit_event(Event::Tabled(proposal, returned));
+				Self::start_referendum(
+					call,
+					now.saturating_add(T::VotingPeriod::get()),
+					T::EnactmentPeriod::get(),
+				);
+			}
+		}
+	}
+
+	fn start_referendum(proposal: T::Proposal, end: T::BlockNumber, delay: T::BlockNumber) -> ReferendumIndex {
+		let ref_index = Self::referendum_count();
+		<ReferendumCount<T>>::put(ref_index.saturating_add(1));
+		let status = ReferendumStatus {
+			end,
+			proposal,
+			delay,
+			tally: Default::default(),
+		};
+		<ReferendumInfoOf<T>>::insert(ref_index, ReferendumInfo::Ongoing(status));
+
+		Self::deposit_event(Event::Started(ref_index, end));
+		ref_index
+	}
+
+	/// Close the referenda whose voting has ended.
+	fn bake_referenda(now: T::BlockNumber) {
+		let count = Self::referendum_count();
+		let mut lowest = Self::lowest_unbaked();
+		for ref_index in lowest..count {
+			if let Ok(status) = Self::referendum_status(ref_index) {
+				if status.end <= now {
+					Self::bake_referendum(ref_index, status);
+				}
+			}
+		}
+
+		// skip the closed and cancelled referenda from now on
+		while lowest < count && Self::referendum_status(lowest).is_err() {
+			lowest += 1;
+		}
+		<LowestUnbaked<T>>::put(lowest);
+	}
+
+	/// Schedule the enactment of a referendum passed by a simple majority
+	/// with enough turnout.
+	fn bake_referendum(ref_index: ReferendumIndex, status: ReferendumStatusOf<T>) {
+		let min_turnout = T::MinimumTurnout::get() * T::Currency::total_issuance();
+		let approved = status.tally.turnout >= min_turnout && status.tally.ayes > status.tally.nays;
+		let mut enactment = None;
+		if approved {
+			// the scheduler has already run for the current block
+			let when = frame_system::Pallet::<T>::block_number()
+				.saturating_add(status.delay)
+				.saturating_add(One::one());
+			enactment = Some(when);
+			if T::Scheduler::schedule_named(
+				(DEMOCRACY_LOCK_ID, ref_index).encode(),
+				DispatchTime::At(when),
+				None,
+				63,
+				frame_system::RawOrigin::Root.into(),
+				status.proposal,
+			)
+			.is_err()
+			{
+				frame_support::print("LOGIC ERROR: bake_referendum/schedule_named failed");
+			}
+			Self::deposit_event(Event::Passed(ref_index, when));
+		} else {
+			Self::deposit_event(Event::NotPassed(ref_index));
+		}
+		<ReferendumInfoOf<T>>::insert(
+			ref_index,
+			ReferendumInfo::Finished {
+				approved,
+				end: status.end,
+				enactment,
+			},
+		);
+	}
+
+	/// Weigh the votes of `who` on ongoing referenda by its current
+	/// conviction, which drops as its PoC commitments stop voting or shrink.
+	pub fn reweigh_votes(who: &T::AccountId) {
+		let mut votes = Self::voting_of(who);
+		let mut changed = false;
+		for (ref_index, vote) in votes.iter_mut() {
+			if let Ok(mut status) = Self::referendum_status(*ref_index) {
+				let weight = T::Conviction::voting_weight(who, vote.balance);
+				if weight != vote.weight {
+					status.tally.remove(vote);
+					vote.weight = weight;
+					status.tally.add(vote);
+					<ReferendumInfoOf<T>>::insert(*ref_index, ReferendumInfo::Ongoing(status));
+					changed = true;
+				}
+			}
+		}
+		if changed {
+			<VotingOf<T>>::insert(who, votes);
+		}
+	}
+
+	/// Store the votes of `who` and lock the largest balance backing them.
+	fn update_votes(who: &T::AccountId, votes: Vec<(ReferendumIndex, AccountVote<BalanceOf<T>>)>) {
+		let locked = votes
+			.iter()
+			.map(|(_, vote)| vote.balance)
+			.max()
+			.unwrap_or_else(Zero::zero);
+		if locked.is_zero() {
+			T::Currency::remove_lock(DEMOCRACY_LOCK_ID, who);
+		} else {
+			T::Currency::set_lock(DEMOCRACY_LOCK_ID, who, locked, WithdrawReasons::TRANSFER);
+		}
+
+		if votes.is_empty() {
+			<VotingOf<T>>::remove(who);
+		} else {
+			<VotingOf<T>>::insert(who, votes);
+		}
+	}
+}
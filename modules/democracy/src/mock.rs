@@ -0,0 +1,159 @@
+#![cfg(test)]
+
+use crate as module_democracy;
+use frame_support::{construct_runtime, parameter_types};
+use frame_system::EnsureRoot;
+use sp_runtime::Perbill;
+use std::cell::RefCell;
+
+type Balance = u64;
+
+pub const ALICE: u64 = 0;
+pub const BOB: u64 = 1;
+pub const CHARLIE: u64 = 2;
+pub const EVE: u64 = 3;
+
+parameter_types!(
+	pub const BlockHashCount: u32 = 250;
+);
+impl frame_system::Config for Runtime {
+	type BaseCallFilter = frame_support::traits::Everything;
+	type Origin = Origin;
+	type Index = u64;
+	type BlockNumber = u64;
+	type Call = Call;
+	type Hash = sp_runtime::testing::H256;
+	type Hashing = sp_runtime::traits::BlakeTwo256;
+	type AccountId = u64;
+	type Lookup = sp_runtime::traits::IdentityLookup<Self::AccountId>;
+	type Header = sp_runtime::testing::Header;
+	type Event = Event;
+	type BlockHashCount = BlockHashCount;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<Balance>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+}
+
+parameter_types! {
+	pub const ExistentialDeposit: u64 = 1;
+	pub const MaxLocks: u32 = 50;
+	pub const MaxReserves: u32 = 50;
+}
+impl pallet_balances::Config for Runtime {
+	type MaxLocks = MaxLocks;
+	type Balance = u64;
+	type Event = Event;
+	type DustRemoval = ();
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type MaxReserves = MaxReserves;
+	type ReserveIdentifier = [u8; 8];
+}
+
+parameter_types! {
+	pub const MaximumSchedulerWeight: u64 = 2_000_000_000_000;
+	pub const MaxScheduledPerBlock: u32 = 50;
+}
+impl pallet_scheduler::Config for Runtime {
+	type Event = Event;
+	type Origin = Origin;
+	type PalletsOrigin = OriginCaller;
+	type Call = Call;
+	type MaximumWeight = MaximumSchedulerWeight;
+	type ScheduleOrigin = EnsureRoot<u64>;
+	type MaxScheduledPerBlock = MaxScheduledPerBlock;
+	type WeightInfo = ();
+}
+
+thread_local! {
+	pub static EVE_CONVICTION: RefCell<Balance> = RefCell::new(11);
+}
+
+/// Eve's locked balance weighs `EVE_CONVICTION` times as much, 11 by default.
+pub struct MockConviction;
+impl module_democracy::VotingConviction<u64, Balance> for MockConviction {
+	fn voting_weight(who: &u64, locked: Balance) -> Balance {
+		if *who == EVE {
+			locked * EVE_CONVICTION.with(|v| *v.borrow())
+		} else {
+			locked
+		}
+	}
+}
+
+parameter_types! {
+	pub const LaunchPeriod: u64 = 10;
+	pub const VotingPeriod: u64 = 10;
+	pub const FastTrackVotingPeriod: u64 = 2;
+	pub const EnactmentPeriod: u64 = 5;
+	pub MinimumTurnout: Perbill = Perbill::from_percent(5);
+	pub const MinimumDeposit: Balance = 100;
+	pub const MaxProposals: u32 = 3;
+	pub const MaxSeconds: u32 = 3;
+	pub const MaxVotes: u32 = 2;
+}
+
+impl module_democracy::Config for Runtime {
+	type Event = Event;
+	type Currency = Balances;
+	type Proposal = Call;
+	type Conviction = MockConviction;
+	type LaunchPeriod = LaunchPeriod;
+	type VotingPeriod = VotingPeriod;
+	type FastTrackVotingPeriod = FastTrackVotingPeriod;
+	type EnactmentPeriod = EnactmentPeriod;
+	type MinimumTurnout = MinimumTurnout;
+	type MinimumDeposit = MinimumDeposit;
+	type MaxProposals = MaxProposals;
+	type MaxSeconds = MaxSeconds;
+	type MaxVotes = MaxVotes;
+	type FastTrackOrigin = EnsureRoot<u64>;
+	type CancellationOrigin = EnsureRoot<u64>;
+	type Slash = ();
+	type PalletsOrigin = OriginCaller;
+	type Scheduler = Scheduler;
+	type WeightInfo = ();
+}
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Runtime>;
+type Block = frame_system::mocking::MockBlock<Runtime>;
+
+construct_runtime!(
+	pub enum Runtime where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic
+	{
+		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		Balances: pallet_balances::{Pallet, Call, Storage, Event<T>},
+		Scheduler: pallet_scheduler::{Pallet, Call, Storage, Event<T>},
+		Democracy: module_democracy::{Pallet, Call, Storage, Event<T>},
+	}
+);
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut t = frame_system::GenesisConfig::default()
+		.build_storage::<Runtime>()
+		.unwrap();
+
+	// inject test balances
+	pallet_balances::GenesisConfig::<Runtime> {
+		balances: vec![(ALICE, 10_000), (BOB, 10_000), (CHARLIE, 10_000), (EVE, 10_000)],
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| System::set_block_number(1));
+
+	ext
+}
@@ -0,0 +1,365 @@
+#![cfg(test)]
+
+use crate::mock::*;
+use crate::{Error, ReferendumInfo};
+use frame_support::{assert_noop, assert_ok, traits::OnInitialize};
+use sp_runtime::traits::BadOrigin;
+
+#[test]
+fn propose_and_second_reserve_deposits() {
+	new_test_ext().execute_with(|| {
+		// the deposit needs to cover the minimum
+		assert_noop!(
+			Democracy::propose(Origin::signed(ALICE), set_balance_proposal(1000), 99),
+			Error::<Runtime>::ValueLow
+		);
+
+		assert_ok!(Democracy::propose(
+			Origin::signed(ALICE),
+			set_balance_proposal(1000),
+			100
+		));
+		System::assert_last_event(Event::Democracy(crate::Event::Proposed(0, ALICE, 100)));
+		assert_eq!(Balances::reserved_balance(ALICE), 100);
+
+		// backers reserve the same deposit
+		assert_ok!(Democracy::second(Origin::signed(BOB), 0));
+		assert_ok!(Democracy::second(Origin::signed(CHARLIE), 0));
+		assert_eq!(Balances::reserved_balance(BOB), 100);
+		assert_eq!(Balances::reserved_balance(CHARLIE), 100);
+		assert_eq!(Democracy::backing_for(0), Some(300));
+
+		// the number of backers is bounded
+		assert_noop!(
+			Democracy::second(Origin::signed(EVE), 0),
+			Error::<Runtime>::TooManySeconds
+		);
+		assert_noop!(
+			Democracy::second(Origin::signed(EVE), 1),
+			Error::<Runtime>::ProposalMissing
+		);
+	});
+}
+
+#[test]
+fn launches_most_backed_proposal() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Democracy::propose(
+			Origin::signed(ALICE),
+			set_balance_proposal(1000),
+			200
+		));
+		assert_ok!(Democracy::propose(Origin::signed(BOB), set_balance_proposal(2000), 100));
+		assert_ok!(Democracy::second(Origin::signed(CHARLIE), 1));
+		assert_ok!(Democracy::second(Origin::signed(EVE), 1));
+
+		// bob's proposal has more backing and is tabled first
+		run_to_block(10);
+		System::assert_has_event(Event::Democracy(crate::Event::Tabled(1, 300)));
+		System::assert_last_event(Event::Democracy(crate::Event::Started(0, 20)));
+		assert_eq!(
+			Democracy::referendum_status(0).unwrap().proposal,
+			*set_balance_proposal(2000)
+		);
+		assert_eq!(Balances::reserved_balance(BOB), 0);
+		assert_eq!(Balances::reserved_balance(EVE), 0);
+		assert_eq!(Balances::reserved_balance(ALICE), 200);
+		assert_eq!(Democracy::public_props().len(), 1);
+
+		// alice's proposal is tabled next launch period
+		run_to_block(20);
+		System::assert_has_event(Event::Democracy(crate::Event::NotPassed(0)));
+		System::assert_has_event(Event::Democracy(crate::Event::Started(1, 30)));
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert!(Democracy::public_props().is_empty());
+		assert_eq!(Democracy::lowest_unbaked(), 1);
+	});
+}
+
+#[test]
+fn passed_referendum_is_enacted() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Democracy::propose(
+			Origin::signed(ALICE),
+			set_balance_proposal(1000),
+			100
+		));
+		run_to_block(10);
+
+		// votes lock the balance, eve's is weighed by her conviction
+		assert_ok!(Democracy::vote(Origin::signed(BOB), 0, true, 500));
+		assert_ok!(Democracy::vote(Origin::signed(EVE), 0, false, 100));
+		assert_eq!(Balances::locks(BOB)[0].amount, 500);
+		assert_eq!(Balances::locks(EVE)[0].amount, 100);
+		let tally = Democracy::referendum_status(0).unwrap().tally;
+		assert_eq!((tally.ayes, tally.nays), (500, 1100));
+
+		// voting again replaces the vote
+		assert_ok!(Democracy::vote(Origin::signed(BOB), 0, true, 2000));
+		let tally = Democracy::referendum_status(0).unwrap().tally;
+		assert_eq!((tally.ayes, tally.nays), (2000, 1100));
+		assert_eq!(Balances::locks(BOB)[0].amount, 2000);
+
+		// enacted after the enactment period
+		run_to_block(20);
+		System::assert_last_event(Event::Democracy(crate::Event::Passed(0, 26)));
+		assert_eq!(
+			Democracy::referendum_info(0),
+			Some(ReferendumInfo::Finished {
+				approved: true,
+				end: 20,
+				enactment: Some(26)
+			})
+		);
+		run_to_block(25);
+		assert_eq!(Balances::free_balance(42), 0);
+
+		// the votes stay locked until then
+		assert_noop!(
+			Democracy::remove_vote(Origin::signed(BOB), 0),
+			Error::<Runtime>::VoteLocked
+		);
+		assert_ok!(Democracy::unlock(Origin::signed(ALICE), BOB));
+		assert_eq!(Balances::locks(BOB)[0].amount, 2000);
+
+		run_to_block(26);
+		assert_eq!(Balances::free_balance(42), 1000);
+
+		// the votes can be unlocked once the proposal is enacted
+		assert_ok!(Democracy::unlock(Origin::signed(ALICE), BOB));
+		assert!(Balances::locks(BOB).is_empty());
+		assert!(Democracy::voting_of(BOB).is_empty());
+	});
+}
+
+#[test]
+fn reweighs_votes_as_conviction_drops() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Democracy::propose(
+			Origin::signed(ALICE),
+			set_balance_proposal(1000),
+			100
+		));
+		run_to_block(10);
+
+		assert_ok!(Democracy::vote(Origin::signed(EVE), 0, true, 1000));
+		assert_ok!(Democracy::vote(Origin::signed(BOB), 0, false, 5000));
+		let tally = Democracy::referendum_status(0).unwrap().tally;
+		assert_eq!((tally.ayes, tally.nays), (11000, 5000));
+
+		// eve's commitments stop voting before the referendum ends
+		EVE_CONVICTION.with(|v| *v.borrow_mut() = 1);
+		Democracy::reweigh_votes(&EVE);
+		let tally = Democracy::referendum_status(0).unwrap().tally;
+		assert_eq!((tally.ayes, tally.nays), (1000, 5000));
+		assert_eq!(Democracy::voting_of(EVE)[0].1.weight, 1000);
+
+		run_to_block(20);
+		System::assert_last_event(Event::Democracy(crate::Event::NotPassed(0)));
+	});
+}
+
+#[test]
+fn rejected_referendum_is_not_enacted() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Democracy::propose(
+			Origin::signed(ALICE),
+			set_balance_proposal(1000),
+			100
+		));
+		run_to_block(10);
+
+		assert_ok!(Democracy::vote(Origin::signed(BOB), 0, true, 1000));
+		assert_ok!(Democracy::vote(Origin::signed(CHARLIE), 0, false, 1000));
+
+		// ties are rejected
+		run_to_block(20);
+		System::assert_last_event(Event::Democracy(crate::Event::NotPassed(0)));
+		assert_eq!(
+			Democracy::referendum_info(0),
+			Some(ReferendumInfo::Finished {
+				approved: false,
+				end: 20,
+				enactment: None
+			})
+		);
+		run_to_block(30);
+		assert_eq!(Balances::free_balance(42), 0);
+
+		// votes on a rejected proposal are unlocked right away
+		assert_ok!(Democracy::remove_vote(Origin::signed(BOB), 0));
+		assert!(Balances::locks(BOB).is_empty());
+	});
+}
+
+#[test]
+fn referendum_needs_minimum_turnout() {
+	new_test_ext().execute_with(|| {
+		for value in 1..=2 {
+			assert_ok!(Democracy::propose(
+				Origin::signed(ALICE),
+				set_balance_proposal(value),
+				100
+			));
+		}
+		run_to_block(10);
+
+		// unopposed ayes need to lock 5% of the 40_000 issued
+		assert_ok!(Democracy::vote(Origin::signed(BOB), 0, true, 1999));
+		assert_eq!(Democracy::referendum_status(0).unwrap().tally.turnout, 1999);
+		run_to_block(20);
+		System::assert_has_event(Event::Democracy(crate::Event::NotPassed(0)));
+
+		// conviction weighs the votes but does not add to the turnout
+		assert_ok!(Democracy::vote(Origin::signed(EVE), 1, true, 1000));
+		let tally = Democracy::referendum_status(1).unwrap().tally;
+		assert_eq!((tally.ayes, tally.turnout), (11_000, 1000));
+		assert_ok!(Democracy::vote(Origin::signed(BOB), 1, true, 1000));
+		run_to_block(30);
+		System::assert_has_event(Event::Democracy(crate::Event::Passed(1, 36)));
+	});
+}
+
+#[test]
+fn fast_tracks_proposal() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Democracy::propose(
+			Origin::signed(ALICE),
+			set_balance_proposal(1000),
+			100
+		));
+
+		assert_noop!(Democracy::fast_track(Origin::signed(ALICE), 0, 2, 0), BadOrigin);
+		assert_noop!(
+			Democracy::fast_track(Origin::root(), 0, 1, 0),
+			Error::<Runtime>::VotingPeriodLow
+		);
+		assert_noop!(
+			Democracy::fast_track(Origin::root(), 1, 2, 0),
+			Error::<Runtime>::ProposalMissing
+		);
+
+		// the referendum starts right away and the deposit is returned
+		assert_ok!(Democracy::fast_track(Origin::root(), 0, 2, 0));
+		System::assert_last_event(Event::Democracy(crate::Event::FastTracked(0, 0)));
+		assert_eq!(Democracy::referendum_status(0).unwrap().end, 3);
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert!(Democracy::public_props().is_empty());
+
+		assert_ok!(Democracy::vote(Origin::signed(BOB), 0, true, 2000));
+		run_to_block(3);
+		System::assert_last_event(Event::Democracy(crate::Event::Passed(0, 4)));
+		run_to_block(4);
+		assert_eq!(Balances::free_balance(42), 1000);
+	});
+}
+
+#[test]
+fn removes_votes() {
+	new_test_ext().execute_with(|| {
+		for value in 1..=3 {
+			assert_ok!(Democracy::propose(
+				Origin::signed(ALICE),
+				set_balance_proposal(value),
+				100
+			));
+		}
+		for proposal in 0..3 {
+			assert_ok!(Democracy::fast_track(Origin::root(), proposal, 5, 0));
+		}
+
+		assert_noop!(
+			Democracy::vote(Origin::signed(BOB), 0, true, 10_001),
+			Error::<Runtime>::InsufficientFunds
+		);
+		assert_noop!(
+			Democracy::vote(Origin::signed(BOB), 3, true, 100),
+			Error::<Runtime>::ReferendumInvalid
+		);
+
+		// the number of votes per account is bounded
+		assert_ok!(Democracy::vote(Origin::signed(BOB), 0, true, 300));
+		assert_ok!(Democracy::vote(Origin::signed(BOB), 1, false, 200));
+		assert_noop!(
+			Democracy::vote(Origin::signed(BOB), 2, true, 100),
+			Error::<Runtime>::MaxVotesReached
+		);
+		assert_eq!(Balances::locks(BOB)[0].amount, 300);
+
+		// removing a vote takes it out of the tally and relocks the rest
+		assert_ok!(Democracy::remove_vote(Origin::signed(BOB), 0));
+		assert_eq!(Democracy::referendum_status(0).unwrap().tally.ayes, 0);
+		assert_eq!(Balances::locks(BOB)[0].amount, 200);
+		assert_noop!(
+			Democracy::remove_vote(Origin::signed(BOB), 0),
+			Error::<Runtime>::NotVoter
+		);
+
+		// ongoing referenda stay locked
+		assert_ok!(Democracy::unlock(Origin::signed(BOB), BOB));
+		assert_eq!(Balances::locks(BOB)[0].amount, 200);
+
+		assert_ok!(Democracy::remove_vote(Origin::signed(BOB), 1));
+		assert!(Balances::locks(BOB).is_empty());
+		assert!(Democracy::voting_of(BOB).is_empty());
+	});
+}
+
+#[test]
+fn cancels_proposals_and_referenda() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Democracy::propose(
+			Origin::signed(ALICE),
+			set_balance_proposal(1000),
+			100
+		));
+		assert_ok!(Democracy::second(Origin::signed(BOB), 0));
+
+		// the deposits of a cancelled proposal are slashed
+		assert_noop!(Democracy::cancel_proposal(Origin::signed(ALICE), 0), BadOrigin);
+		assert_ok!(Democracy::cancel_proposal(Origin::root(), 0));
+		System::assert_last_event(Event::Democracy(crate::Event::ProposalCancelled(0, 200)));
+		assert_eq!(Balances::total_balance(&ALICE), 9_900);
+		assert_eq!(Balances::total_balance(&BOB), 9_900);
+		assert!(Democracy::public_props().is_empty());
+		assert_eq!(Democracy::deposit_of(0), None);
+
+		assert_ok!(Democracy::propose(
+			Origin::signed(ALICE),
+			set_balance_proposal(1000),
+			100
+		));
+		assert_ok!(Democracy::fast_track(Origin::root(), 1, 5, 0));
+		assert_ok!(Democracy::vote(Origin::signed(BOB), 0, true, 100));
+
+		// a cancelled referendum is not enacted and its votes can be unlocked
+		assert_ok!(Democracy::cancel_referendum(Origin::root(), 0));
+		System::assert_last_event(Event::Democracy(crate::Event::Cancelled(0)));
+		assert_noop!(
+			Democracy::cancel_referendum(Origin::root(), 0),
+			Error::<Runtime>::ReferendumInvalid
+		);
+		assert_noop!(
+			Democracy::vote(Origin::signed(BOB), 0, true, 100),
+			Error::<Runtime>::ReferendumInvalid
+		);
+		assert_ok!(Democracy::unlock(Origin::signed(BOB), BOB));
+		assert!(Balances::locks(BOB).is_empty());
+
+		run_to_block(10);
+		assert_eq!(Democracy::lowest_unbaked(), 1);
+		assert_eq!(Balances::free_balance(42), 0);
+	});
+}
+
+fn set_balance_proposal(value: u64) -> Box<Call> {
+	Box::new(Call::Balances(pallet_balances::Call::set_balance(42, value, 0)))
+}
+
+fn run_to_block(n: u64) {
+	while System::block_number() < n {
+		System::set_block_number(System::block_number() + 1);
+		Scheduler::on_initialize(System::block_number());
+		Democracy::on_initialize(System::block_number());
+	}
+}
@@ -0,0 +1,127 @@
+//! Estimated weights for module_democracy.
+//!
+//! These are hand-written estimates, not benchmark output. Replace them with
+//! generated weights once the benchmarks have been run on reference hardware.
+
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::Weight};
+use sp_std::marker::PhantomData;
+
+/// Weight functions for module_democracy.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> crate::WeightInfo for WeightInfo<T> {
+	fn propose() -> Weight {
+		(52_374_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	fn second(s: u32) -> Weight {
+		(33_106_000 as Weight)
+			.saturating_add((186_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn vote(r: u32) -> Weight {
+		(41_882_000 as Weight)
+			.saturating_add((254_000 as Weight).saturating_mul(r as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	fn remove_vote(r: u32) -> Weight {
+		(24_519_000 as Weight)
+			.saturating_add((233_000 as Weight).saturating_mul(r as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	fn unlock(r: u32) -> Weight {
+		(21_707_000 as Weight)
+			.saturating_add((1_052_000 as Weight).saturating_mul(r as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(r as Weight)))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	fn fast_track(s: u32) -> Weight {
+		(36_488_000 as Weight)
+			.saturating_add((3_907_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	fn cancel_referendum() -> Weight {
+		(17_326_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn cancel_proposal(s: u32) -> Weight {
+		(41_213_000 as Weight)
+			.saturating_add((7_820_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	fn on_initialize(r: u32) -> Weight {
+		(9_614_000 as Weight)
+			.saturating_add((5_322_000 as Weight).saturating_mul(r as Weight))
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(r as Weight)))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+}
+
+use frame_support::weights::constants::RocksDbWeight as DbWeight;
+impl crate::WeightInfo for () {
+	fn propose() -> Weight {
+		(52_374_000 as Weight)
+			.saturating_add(DbWeight::get().reads(3 as Weight))
+			.saturating_add(DbWeight::get().writes(3 as Weight))
+	}
+	fn second(s: u32) -> Weight {
+		(33_106_000 as Weight)
+			.saturating_add((186_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(DbWeight::get().reads(1 as Weight))
+			.saturating_add(DbWeight::get().writes(1 as Weight))
+	}
+	fn vote(r: u32) -> Weight {
+		(41_882_000 as Weight)
+			.saturating_add((254_000 as Weight).saturating_mul(r as Weight))
+			.saturating_add(DbWeight::get().reads(3 as Weight))
+			.saturating_add(DbWeight::get().writes(3 as Weight))
+	}
+	fn remove_vote(r: u32) -> Weight {
+		(24_519_000 as Weight)
+			.saturating_add((233_000 as Weight).saturating_mul(r as Weight))
+			.saturating_add(DbWeight::get().reads(3 as Weight))
+			.saturating_add(DbWeight::get().writes(3 as Weight))
+	}
+	fn unlock(r: u32) -> Weight {
+		(21_707_000 as Weight)
+			.saturating_add((1_052_000 as Weight).saturating_mul(r as Weight))
+			.saturating_add(DbWeight::get().reads(3 as Weight))
+			.saturating_add(DbWeight::get().reads((1 as Weight).saturating_mul(r as Weight)))
+			.saturating_add(DbWeight::get().writes(2 as Weight))
+	}
+	fn fast_track(s: u32) -> Weight {
+		(36_488_000 as Weight)
+			.saturating_add((3_907_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(DbWeight::get().reads(3 as Weight))
+			.saturating_add(DbWeight::get().writes(4 as Weight))
+	}
+	fn cancel_referendum() -> Weight {
+		(17_326_000 as Weight)
+			.saturating_add(DbWeight::get().reads(1 as Weight))
+			.saturating_add(DbWeight::get().writes(1 as Weight))
+	}
+	fn cancel_proposal(s: u32) -> Weight {
+		(41_213_000 as Weight)
+			.saturating_add((7_820_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(DbWeight::get().reads(2 as Weight))
+			.saturating_add(DbWeight::get().writes(2 as Weight))
+	}
+	fn on_initialize(r: u32) -> Weight {
+		(9_614_000 as Weight)
+			.saturating_add((5_322_000 as Weight).saturating_mul(r as Weight))
+			.saturating_add(DbWeight::get().reads(5 as Weight))
+			.saturating_add(DbWeight::get().reads((1 as Weight).saturating_mul(r as Weight)))
+			.saturating_add(DbWeight::get().writes(3 as Weight))
+	}
+}
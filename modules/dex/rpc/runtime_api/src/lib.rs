@@ -0,0 +1,21 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::all)]
+
+use codec::Codec;
+use primitives::CurrencyId;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	pub trait DexApi<Balance> where
+		Balance: Codec,
+	{
+		/// The liquidity pool of the pair, in the order the currencies were passed in.
+		fn get_liquidity_pool(currency_id_a: CurrencyId, currency_id_b: CurrencyId) -> (Balance, Balance);
+
+		/// Quote the amount received for `supply_amount` along `path`.
+		fn get_swap_target_amount(path: Vec<CurrencyId>, supply_amount: Balance) -> Option<Balance>;
+
+		/// Quote the amount to supply to receive `target_amount` along `path`.
+		fn get_swap_supply_amount(path: Vec<CurrencyId>, target_amount: Balance) -> Option<Balance>;
+	}
+}
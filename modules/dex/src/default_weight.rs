@@ -0,0 +1,33 @@
+//! Estimated weights for module_dex.
+//!
+//! These are hand-written estimates, not benchmark output. Replace them with
+//! generated weights once the pallet has benchmarks.
+
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(clippy::unnecessary_cast)]
+
+use frame_support::weights::{constants::RocksDbWeight as DbWeight, Weight};
+
+impl crate::WeightInfo for () {
+	fn add_liquidity() -> Weight {
+		(120_000_000 as Weight)
+			.saturating_add(DbWeight::get().reads(8 as Weight))
+			.saturating_add(DbWeight::get().writes(7 as Weight))
+	}
+	fn remove_liquidity() -> Weight {
+		(110_000_000 as Weight)
+			.saturating_add(DbWeight::get().reads(8 as Weight))
+			.saturating_add(DbWeight::get().writes(7 as Weight))
+	}
+	fn swap_with_exact_supply() -> Weight {
+		(130_000_000 as Weight)
+			.saturating_add(DbWeight::get().reads(8 as Weight))
+			.saturating_add(DbWeight::get().writes(7 as Weight))
+	}
+	fn swap_with_exact_target() -> Weight {
+		(130_000_000 as Weight)
+			.saturating_add(DbWeight::get().reads(8 as Weight))
+			.saturating_add(DbWeight::get().writes(7 as Weight))
+	}
+}
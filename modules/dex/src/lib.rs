@@ -0,0 +1,669 @@
+//! # DEX Module
+//!
+//! ## Overview
+//!
+//! Built-in decentralized exchange module of Dust. Every enabled
+//! `TradingPair` has a constant product liquidity pool (`x * y = k`).
+//! Liquidity providers receive `CurrencyId::DEXShare` tokens as the proof of
+//! their share of the pool, and traders can swap along a path of pools with
+//! either an exact supply or an exact target amount. An exchange fee is
+//! charged on every hop and stays in the pool for the liquidity providers.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use frame_support::{pallet_prelude::*, transactional, PalletId};
+use frame_system::pallet_prelude::*;
+use orml_traits::MultiCurrency;
+use primitives::{Balance, CurrencyId, TradingPair};
+use sp_core::U256;
+use sp_runtime::{
+	traits::{AccountIdConversion, Bounded, SaturatedConversion, Zero},
+	FixedPointNumber,
+};
+use sp_std::{convert::TryInto, prelude::*, vec};
+use support::{DEXManager, Price, Ratio};
+
+mod default_weight;
+mod mock;
+mod tests;
+
+pub use module::*;
+
+pub trait WeightInfo {
+	fn add_liquidity() -> Weight;
+	fn remove_liquidity() -> Weight;
+	fn swap_with_exact_supply() -> Weight;
+	fn swap_with_exact_target() -> Weight;
+}
+
+#[frame_support::pallet]
+pub mod module {
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// Currency to transfer assets and to mint and burn the DEX share
+		/// tokens with.
+		type Currency: MultiCurrency<Self::AccountId, CurrencyId = CurrencyId, Balance = Balance>;
+
+		/// The trading pairs that can be traded and provided liquidity for.
+		#[pallet::constant]
+		type EnabledTradingPairs: Get<Vec<TradingPair>>;
+
+		/// Exchange fee charged on every hop of a swap, as
+		/// `(numerator, denominator)`.
+		#[pallet::constant]
+		type GetExchangeFee: Get<(u32, u32)>;
+
+		/// The maximum length of a trading path, including the supply and
+		/// target currencies.
+		#[pallet::constant]
+		type TradingPathLimit: Get<u32>;
+
+		/// The DEX's module id, keeps the liquidity of all pools.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// Weight information for the extrinsics in this module.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The trading pair is not enabled
+		TradingPairNotAllowed,
+		/// The currencies can't form a trading pair
+		InvalidCurrencyId,
+		/// The trading path is too short or too long
+		InvalidTradingPathLength,
+		/// The received amount is less than the accepted minimum
+		InsufficientTargetAmount,
+		/// The supplied amount is more than the accepted maximum
+		ExcessiveSupplyAmount,
+		/// The liquidity pool can't support the swap
+		InsufficientLiquidity,
+		/// The swap would require zero supply
+		ZeroSupplyAmount,
+		/// The swap would receive zero target
+		ZeroTargetAmount,
+		/// The liquidity increment or decrement is invalid
+		InvalidLiquidityIncrement,
+		/// The swap would break the constant product of the pool
+		InvalidConstantProduct,
+		/// The price impact of a hop is over the limit
+		ExceedPriceImpactLimit,
+		/// The minted share is less than the accepted minimum
+		UnacceptableShareIncrement,
+		/// The withdrawn liquidity is less than the accepted minimum
+		UnacceptableLiquidityWithdrawn,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(fn deposit_event)]
+	#[pallet::metadata(T::AccountId = "AccountId")]
+	pub enum Event<T: Config> {
+		/// Liquidity was added to a pool. \[who, currency_id_0, pool_0_increment,
+		/// currency_id_1, pool_1_increment, share_increment\]
+		AddLiquidity(T::AccountId, CurrencyId, Balance, CurrencyId, Balance, Balance),
+		/// Liquidity was removed from a pool. \[who, currency_id_0,
+		/// pool_0_decrement, currency_id_1, pool_1_decrement, share_decrement\]
+		RemoveLiquidity(T::AccountId, CurrencyId, Balance, CurrencyId, Balance, Balance),
+		/// A swap was executed. \[who, trading_path, supply_amount, target_amount\]
+		Swap(T::AccountId, Vec<CurrencyId>, Balance, Balance),
+	}
+
+	/// The liquidity of each enabled trading pair, in the order of the pair.
+	///
+	/// LiquidityPool: map TradingPair => (Balance, Balance)
+	#[pallet::storage]
+	#[pallet::getter(fn liquidity_pool)]
+	pub type LiquidityPool<T: Config> = StorageMap<_, Twox64Concat, TradingPair, (Balance, Balance), ValueQuery>;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(PhantomData<T>);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Swap exactly `supply_amount` of the first currency of `path` for
+		/// at least `min_target_amount` of the last one.
+		#[pallet::weight(<T as Config>::WeightInfo::swap_with_exact_supply())]
+		pub fn swap_with_exact_supply(
+			origin: OriginFor<T>,
+			path: Vec<CurrencyId>,
+			#[pallet::compact] supply_amount: Balance,
+			#[pallet::compact] min_target_amount: Balance,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::do_swap_with_exact_supply(&who, &path, supply_amount, min_target_amount, None)?;
+			Ok(().into())
+		}
+
+		/// Swap at most `max_supply_amount` of the first currency of `path`
+		/// for exactly `target_amount` of the last one.
+		#[pallet::weight(<T as Config>::WeightInfo::swap_with_exact_target())]
+		pub fn swap_with_exact_target(
+			origin: OriginFor<T>,
+			path: Vec<CurrencyId>,
+			#[pallet::compact] target_amount: Balance,
+			#[pallet::compact] max_supply_amount: Balance,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::do_swap_with_exact_target(&who, &path, target_amount, max_supply_amount, None)?;
+			Ok(().into())
+		}
+
+		/// Add liquidity to the pool of `currency_id_a` and `currency_id_b`.
+		///
+		/// The first provider sets the price of the pool. Later providers
+		/// supply at the current price, using at most `max_amount_a` and
+		/// `max_amount_b`, and receive DEX share tokens in proportion.
+		#[pallet::weight(<T as Config>::WeightInfo::add_liquidity())]
+		#[transactional]
+		pub fn add_liquidity(
+			origin: OriginFor<T>,
+			currency_id_a: CurrencyId,
+			currency_id_b: CurrencyId,
+			#[pallet::compact] max_amount_a: Balance,
+			#[pallet::compact] max_amount_b: Balance,
+			#[pallet::compact] min_share_increment: Balance,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::do_add_liquidity(
+				&who,
+				currency_id_a,
+				currency_id_b,
+				max_amount_a,
+				max_amount_b,
+				min_share_increment,
+			)?;
+			Ok(().into())
+		}
+
+		/// Burn `remove_share` DEX share tokens and withdraw the
+		/// corresponding part of the pool of `currency_id_a` and
+		/// `currency_id_b`.
+		#[pallet::weight(<T as Config>::WeightInfo::remove_liquidity())]
+		#[transactional]
+		pub fn remove_liquidity(
+			origin: OriginFor<T>,
+			currency_id_a: CurrencyId,
+			currency_id_b: CurrencyId,
+			#[pallet::compact] remove_share: Balance,
+			#[pallet::compact] min_withdrawn_a: Balance,
+			#[pallet::compact] min_withdrawn_b: Balance,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::do_remove_liquidity(
+				&who,
+				currency_id_a,
+				currency_id_b,
+				remove_share,
+				min_withdrawn_a,
+				min_withdrawn_b,
+			)?;
+			Ok(().into())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// The account holding the liquidity of all pools.
+	pub fn account_id() -> T::AccountId {
+		T::PalletId::get().into_account()
+	}
+
+	fn enabled_trading_pair(
+		currency_id_a: CurrencyId,
+		currency_id_b: CurrencyId,
+	) -> Result<TradingPair, DispatchError> {
+		let trading_pair =
+			TradingPair::from_currency_ids(currency_id_a, currency_id_b).ok_or(Error::<T>::InvalidCurrencyId)?;
+		ensure!(
+			T::EnabledTradingPairs::get().contains(&trading_pair),
+			Error::<T>::TradingPairNotAllowed
+		);
+		Ok(trading_pair)
+	}
+
+	fn do_add_liquidity(
+		who: &T::AccountId,
+		currency_id_a: CurrencyId,
+		currency_id_b: CurrencyId,
+		max_amount_a: Balance,
+		max_amount_b: Balance,
+		min_share_increment: Balance,
+	) -> DispatchResult {
+		let trading_pair = Self::enabled_trading_pair(currency_id_a, currency_id_b)?;
+		let dex_share_currency_id = trading_pair.dex_share_currency_id();
+
+		LiquidityPool::<T>::try_mutate(trading_pair, |(pool_0, pool_1)| -> DispatchResult {
+			let total_shares = T::Currency::total_issuance(dex_share_currency_id);
+			let (max_amount_0, max_amount_1) = if currency_id_a == trading_pair.first() {
+				(max_amount_a, max_amount_b)
+			} else {
+				(max_amount_b, max_amount_a)
+			};
+
+			let (pool_0_increment, pool_1_increment, share_increment) = if total_shares.is_zero() {
+				// the first provider sets the price, and gets as many shares as the larger
+				// of the two amounts
+				(max_amount_0, max_amount_1, max_amount_0.max(max_amount_1))
+			} else {
+				let exchange_rate_0_1 =
+					Price::checked_from_rational(*pool_1, *pool_0).ok_or(Error::<T>::InvalidLiquidityIncrement)?;
+				let input_exchange_rate_0_1 = Price::checked_from_rational(max_amount_1, max_amount_0)
+					.ok_or(Error::<T>::InvalidLiquidityIncrement)?;
+
+				if input_exchange_rate_0_1 <= exchange_rate_0_1 {
+					// `max_amount_0` is more than needed, supply at the pool price of `max_amount_1`
+					let exchange_rate_1_0 =
+						Price::checked_from_rational(*pool_0, *pool_1).ok_or(Error::<T>::InvalidLiquidityIncrement)?;
+					let amount_0 = exchange_rate_1_0
+						.checked_mul_int(max_amount_1)
+						.ok_or(Error::<T>::InvalidLiquidityIncrement)?;
+					let share_increment = Ratio::checked_from_rational(max_amount_1, *pool_1)
+						.and_then(|ratio| ratio.checked_mul_int(total_shares))
+						.ok_or(Error::<T>::InvalidLiquidityIncrement)?;
+					(amount_0, max_amount_1, share_increment)
+				} else {
+					// `max_amount_1` is more than needed, supply at the pool price of `max_amount_0`
+					let amount_1 = exchange_rate_0_1
+						.checked_mul_int(max_amount_0)
+						.ok_or(Error::<T>::InvalidLiquidityIncrement)?;
+					let share_increment = Ratio::checked_from_rational(max_amount_0, *pool_0)
+						.and_then(|ratio| ratio.checked_mul_int(total_shares))
+						.ok_or(Error::<T>::InvalidLiquidityIncrement)?;
+					(max_amount_0, amount_1, share_increment)
+				}
+			};
+
+			ensure!(
+				!share_increment.is_zero() && !pool_0_increment.is_zero() && !pool_1_increment.is_zero(),
+				Error::<T>::InvalidLiquidityIncrement
+			);
+			ensure!(
+				share_increment >= min_share_increment,
+				Error::<T>::UnacceptableShareIncrement
+			);
+
+			let module_account_id = Self::account_id();
+			T::Currency::transfer(trading_pair.first(), who, &module_account_id, pool_0_increment)?;
+			T::Currency::transfer(trading_pair.second(), who, &module_account_id, pool_1_increment)?;
+			T::Currency::deposit(dex_share_currency_id, who, share_increment)?;
+
+			*pool_0 = pool_0
+				.checked_add(pool_0_increment)
+				.ok_or(Error::<T>::InvalidLiquidityIncrement)?;
+			*pool_1 = pool_1
+				.checked_add(pool_1_increment)
+				.ok_or(Error::<T>::InvalidLiquidityIncrement)?;
+
+			Self::deposit_event(Event::AddLiquidity(
+				who.clone(),
+				trading_pair.first(),
+				pool_0_increment,
+				trading_pair.second(),
+				pool_1_increment,
+				share_increment,
+			));
+			Ok(())
+		})
+	}
+
+	fn do_remove_liquidity(
+		who: &T::AccountId,
+		currency_id_a: CurrencyId,
+		currency_id_b: CurrencyId,
+		remove_share: Balance,
+		min_withdrawn_a: Balance,
+		min_withdrawn_b: Balance,
+	) -> DispatchResult {
+		if remove_share.is_zero() {
+			return Ok(());
+		}
+		let trading_pair =
+			TradingPair::from_currency_ids(currency_id_a, currency_id_b).ok_or(Error::<T>::InvalidCurrencyId)?;
+		let dex_share_currency_id = trading_pair.dex_share_currency_id();
+
+		LiquidityPool::<T>::try_mutate(trading_pair, |(pool_0, pool_1)| -> DispatchResult {
+			let (min_withdrawn_0, min_withdrawn_1) = if currency_id_a == trading_pair.first() {
+				(min_withdrawn_a, min_withdrawn_b)
+			} else {
+				(min_withdrawn_b, min_withdrawn_a)
+			};
+
+			let total_shares = T::Currency::total_issuance(dex_share_currency_id);
+			let proportion = Ratio::checked_from_rational(remove_share, total_shares)
+				.ok_or(Error::<T>::InvalidLiquidityIncrement)?;
+			let pool_0_decrement = proportion.saturating_mul_int(*pool_0);
+			let pool_1_decrement = proportion.saturating_mul_int(*pool_1);
+
+			ensure!(
+				pool_0_decrement >= min_withdrawn_0 && pool_1_decrement >= min_withdrawn_1,
+				Error::<T>::UnacceptableLiquidityWithdrawn
+			);
+
+			let module_account_id = Self::account_id();
+			T::Currency::withdraw(dex_share_currency_id, who, remove_share)?;
+			T::Currency::transfer(trading_pair.first(), &module_account_id, who, pool_0_decrement)?;
+			T::Currency::transfer(trading_pair.second(), &module_account_id, who, pool_1_decrement)?;
+
+			*pool_0 = pool_0
+				.checked_sub(pool_0_decrement)
+				.ok_or(Error::<T>::InvalidLiquidityIncrement)?;
+			*pool_1 = pool_1
+				.checked_sub(pool_1_decrement)
+				.ok_or(Error::<T>::InvalidLiquidityIncrement)?;
+
+			Self::deposit_event(Event::RemoveLiquidity(
+				who.clone(),
+				trading_pair.first(),
+				pool_0_decrement,
+				trading_pair.second(),
+				pool_1_decrement,
+				remove_share,
+			));
+			Ok(())
+		})
+	}
+
+	fn get_liquidity(currency_id_a: CurrencyId, currency_id_b: CurrencyId) -> (Balance, Balance) {
+		if let Some(trading_pair) = TradingPair::from_currency_ids(currency_id_a, currency_id_b) {
+			let (pool_0, pool_1) = Self::liquidity_pool(trading_pair);
+			if currency_id_a == trading_pair.first() {
+				(pool_0, pool_1)
+			} else {
+				(pool_1, pool_0)
+			}
+		} else {
+			(Zero::zero(), Zero::zero())
+		}
+	}
+
+	/// Get how much target amount will be got for specific supply amount
+	/// and price impact
+	fn get_target_amount(supply_pool: Balance, target_pool: Balance, supply_amount: Balance) -> Balance {
+		if supply_amount.is_zero() || supply_pool.is_zero() || target_pool.is_zero() {
+			return Zero::zero();
+		}
+
+		let (fee_numerator, fee_denominator) = T::GetExchangeFee::get();
+		let supply_amount_with_fee: U256 =
+			U256::from(supply_amount).saturating_mul(U256::from(fee_denominator.saturating_sub(fee_numerator)));
+		let numerator: U256 = supply_amount_with_fee.saturating_mul(U256::from(target_pool));
+		let denominator: U256 = U256::from(supply_pool)
+			.saturating_mul(U256::from(fee_denominator))
+			.saturating_add(supply_amount_with_fee);
+
+		numerator
+			.checked_div(denominator)
+			.and_then(|n| TryInto::<Balance>::try_into(n).ok())
+			.unwrap_or_else(Zero::zero)
+	}
+
+	/// Get how much supply amount will be paid for specific target amount.
+	fn get_supply_amount(supply_pool: Balance, target_pool: Balance, target_amount: Balance) -> Balance {
+		if target_amount.is_zero() || supply_pool.is_zero() || target_pool.is_zero() {
+			return Zero::zero();
+		}
+
+		let (fee_numerator, fee_denominator) = T::GetExchangeFee::get();
+		let numerator: U256 = U256::from(supply_pool)
+			.saturating_mul(U256::from(target_amount))
+			.saturating_mul(U256::from(fee_denominator));
+		let denominator: U256 = U256::from(target_pool)
+			.saturating_sub(U256::from(target_amount))
+			.saturating_mul(U256::from(fee_denominator.saturating_sub(fee_numerator)));
+
+		numerator
+			.checked_div(denominator)
+			// round up, so the discarded remainder never favours the trader
+			.and_then(|r| r.checked_add(U256::one()))
+			.and_then(|n| TryInto::<Balance>::try_into(n).ok())
+			.unwrap_or_else(Zero::zero)
+	}
+
+	fn ensure_trading_path(path: &[CurrencyId]) -> DispatchResult {
+		let path_length = path.len();
+		ensure!(
+			path_length >= 2 && path_length <= T::TradingPathLimit::get().saturated_into::<usize>(),
+			Error::<T>::InvalidTradingPathLength
+		);
+		Ok(())
+	}
+
+	fn ensure_price_impact(
+		target_amount: Balance,
+		target_pool: Balance,
+		price_impact_limit: Option<Ratio>,
+	) -> DispatchResult {
+		if let Some(limit) = price_impact_limit {
+			let price_impact =
+				Ratio::checked_from_rational(target_amount, target_pool).unwrap_or_else(Ratio::max_value);
+			ensure!(price_impact <= limit, Error::<T>::ExceedPriceImpactLimit);
+		}
+		Ok(())
+	}
+
+	/// The amounts of every currency of `path` when supplying
+	/// `supply_amount`.
+	fn get_target_amounts(
+		path: &[CurrencyId],
+		supply_amount: Balance,
+		price_impact_limit: Option<Ratio>,
+	) -> Result<Vec<Balance>, DispatchError> {
+		Self::ensure_trading_path(path)?;
+
+		let path_length = path.len();
+		let mut target_amounts: Vec<Balance> = vec![Zero::zero(); path_length];
+		target_amounts[0] = supply_amount;
+
+		let mut i: usize = 0;
+		while i + 1 < path_length {
+			Self::enabled_trading_pair(path[i], path[i + 1])?;
+			let (supply_pool, target_pool) = Self::get_liquidity(path[i], path[i + 1]);
+			ensure!(
+				!supply_pool.is_zero() && !target_pool.is_zero(),
+				Error::<T>::InsufficientLiquidity
+			);
+
+			let target_amount = Self::get_target_amount(supply_pool, target_pool, target_amounts[i]);
+			ensure!(!target_amount.is_zero(), Error::<T>::ZeroTargetAmount);
+			Self::ensure_price_impact(target_amount, target_pool, price_impact_limit)?;
+
+			target_amounts[i + 1] = target_amount;
+			i += 1;
+		}
+
+		Ok(target_amounts)
+	}
+
+	/// The amounts of every currency of `path` when receiving
+	/// `target_amount`.
+	fn get_supply_amounts(
+		path: &[CurrencyId],
+		target_amount: Balance,
+		price_impact_limit: Option<Ratio>,
+	) -> Result<Vec<Balance>, DispatchError> {
+		Self::ensure_trading_path(path)?;
+
+		let path_length = path.len();
+		let mut supply_amounts: Vec<Balance> = vec![Zero::zero(); path_length];
+		supply_amounts[path_length - 1] = target_amount;
+
+		let mut i: usize = path_length - 1;
+		while i > 0 {
+			Self::enabled_trading_pair(path[i - 1], path[i])?;
+			let (supply_pool, target_pool) = Self::get_liquidity(path[i - 1], path[i]);
+			ensure!(
+				!supply_pool.is_zero() && target_pool > supply_amounts[i],
+				Error::<T>::InsufficientLiquidity
+			);
+
+			let supply_amount = Self::get_supply_amount(supply_pool, target_pool, supply_amounts[i]);
+			ensure!(!supply_amount.is_zero(), Error::<T>::ZeroSupplyAmount);
+			Self::ensure_price_impact(supply_amounts[i], target_pool, price_impact_limit)?;
+
+			supply_amounts[i - 1] = supply_amount;
+			i -= 1;
+		}
+
+		Ok(supply_amounts)
+	}
+
+	fn do_swap(
+		supply_currency_id: CurrencyId,
+		target_currency_id: CurrencyId,
+		supply_increment: Balance,
+		target_decrement: Balance,
+	) -> DispatchResult {
+		let trading_pair = Self::enabled_trading_pair(supply_currency_id, target_currency_id)?;
+
+		LiquidityPool::<T>::try_mutate(trading_pair, |(pool_0, pool_1)| -> DispatchResult {
+			let invariant_before_swap: U256 = U256::from(*pool_0).saturating_mul(U256::from(*pool_1));
+
+			if supply_currency_id == trading_pair.first() {
+				*pool_0 = pool_0
+					.checked_add(supply_increment)
+					.ok_or(Error::<T>::InvalidLiquidityIncrement)?;
+				*pool_1 = pool_1
+					.checked_sub(target_decrement)
+					.ok_or(Error::<T>::InsufficientLiquidity)?;
+			} else {
+				*pool_0 = pool_0
+					.checked_sub(target_decrement)
+					.ok_or(Error::<T>::InsufficientLiquidity)?;
+				*pool_1 = pool_1
+					.checked_add(supply_increment)
+					.ok_or(Error::<T>::InvalidLiquidityIncrement)?;
+			}
+
+			// the constant product `x * y = k` must never decrease
+			let invariant_after_swap: U256 = U256::from(*pool_0).saturating_mul(U256::from(*pool_1));
+			ensure!(
+				invariant_after_swap >= invariant_before_swap,
+				Error::<T>::InvalidConstantProduct
+			);
+			Ok(())
+		})
+	}
+
+	fn do_swap_by_path(path: &[CurrencyId], amounts: &[Balance]) -> DispatchResult {
+		let mut i: usize = 0;
+		while i + 1 < path.len() {
+			Self::do_swap(path[i], path[i + 1], amounts[i], amounts[i + 1])?;
+			i += 1;
+		}
+		Ok(())
+	}
+
+	#[transactional]
+	fn do_swap_with_exact_supply(
+		who: &T::AccountId,
+		path: &[CurrencyId],
+		supply_amount: Balance,
+		min_target_amount: Balance,
+		price_impact_limit: Option<Ratio>,
+	) -> Result<Balance, DispatchError> {
+		let amounts = Self::get_target_amounts(path, supply_amount, price_impact_limit)?;
+		let actual_target_amount = amounts[amounts.len() - 1];
+		ensure!(
+			actual_target_amount >= min_target_amount,
+			Error::<T>::InsufficientTargetAmount
+		);
+
+		let module_account_id = Self::account_id();
+		T::Currency::transfer(path[0], who, &module_account_id, supply_amount)?;
+		Self::do_swap_by_path(path, &amounts)?;
+		T::Currency::transfer(path[path.len() - 1], &module_account_id, who, actual_target_amount)?;
+
+		Self::deposit_event(Event::Swap(
+			who.clone(),
+			path.to_vec(),
+			supply_amount,
+			actual_target_amount,
+		));
+		Ok(actual_target_amount)
+	}
+
+	#[transactional]
+	fn do_swap_with_exact_target(
+		who: &T::AccountId,
+		path: &[CurrencyId],
+		target_amount: Balance,
+		max_supply_amount: Balance,
+		price_impact_limit: Option<Ratio>,
+	) -> Result<Balance, DispatchError> {
+		let amounts = Self::get_supply_amounts(path, target_amount, price_impact_limit)?;
+		let actual_supply_amount = amounts[0];
+		ensure!(
+			actual_supply_amount <= max_supply_amount,
+			Error::<T>::ExcessiveSupplyAmount
+		);
+
+		let module_account_id = Self::account_id();
+		T::Currency::transfer(path[0], who, &module_account_id, actual_supply_amount)?;
+		Self::do_swap_by_path(path, &amounts)?;
+		T::Currency::transfer(path[path.len() - 1], &module_account_id, who, target_amount)?;
+
+		Self::deposit_event(Event::Swap(
+			who.clone(),
+			path.to_vec(),
+			actual_supply_amount,
+			target_amount,
+		));
+		Ok(actual_supply_amount)
+	}
+}
+
+impl<T: Config> DEXManager<T::AccountId, CurrencyId, Balance> for Pallet<T> {
+	fn get_liquidity_pool(currency_id_a: CurrencyId, currency_id_b: CurrencyId) -> (Balance, Balance) {
+		Self::get_liquidity(currency_id_a, currency_id_b)
+	}
+
+	fn get_swap_target_amount(
+		path: &[CurrencyId],
+		supply_amount: Balance,
+		price_impact_limit: Option<Ratio>,
+	) -> Option<Balance> {
+		Self::get_target_amounts(path, supply_amount, price_impact_limit)
+			.ok()
+			.map(|amounts| amounts[amounts.len() - 1])
+	}
+
+	fn get_swap_supply_amount(
+		path: &[CurrencyId],
+		target_amount: Balance,
+		price_impact_limit: Option<Ratio>,
+	) -> Option<Balance> {
+		Self::get_supply_amounts(path, target_amount, price_impact_limit)
+			.ok()
+			.map(|amounts| amounts[0])
+	}
+
+	fn swap_with_exact_supply(
+		who: &T::AccountId,
+		path: &[CurrencyId],
+		supply_amount: Balance,
+		min_target_amount: Balance,
+		price_impact_limit: Option<Ratio>,
+	) -> Result<Balance, DispatchError> {
+		Self::do_swap_with_exact_supply(who, path, supply_amount, min_target_amount, price_impact_limit)
+	}
+
+	fn swap_with_exact_target(
+		who: &T::AccountId,
+		path: &[CurrencyId],
+		target_amount: Balance,
+		max_supply_amount: Balance,
+		price_impact_limit: Option<Ratio>,
+	) -> Result<Balance, DispatchError> {
+		Self::do_swap_with_exact_target(who, path, target_amount, max_supply_amount, price_impact_limit)
+	}
+}
@@ -0,0 +1,227 @@
+//! Unit tests for the dex module.
+
+#![cfg(test)]
+
+use super::*;
+use frame_support::{assert_noop, assert_ok};
+use mock::{DexModule, Event, ExtBuilder, Origin, Runtime, System, Tokens, ALICE, BOB, DUST, DUST_USDD_LP, USDD};
+use orml_traits::MultiCurrency;
+
+fn dust_usdd_pair() -> TradingPair {
+	TradingPair::from_currency_ids(DUST, USDD).unwrap()
+}
+
+fn initialize_pool() {
+	assert_ok!(DexModule::add_liquidity(
+		Origin::signed(ALICE),
+		DUST,
+		USDD,
+		1_000_000,
+		4_000_000,
+		0
+	));
+}
+
+#[test]
+fn add_liquidity_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		initialize_pool();
+		assert_eq!(DexModule::liquidity_pool(dust_usdd_pair()), (1_000_000, 4_000_000));
+		assert_eq!(Tokens::free_balance(DUST_USDD_LP, &ALICE), 4_000_000);
+		assert_eq!(Tokens::free_balance(DUST, &DexModule::account_id()), 1_000_000);
+		assert_eq!(Tokens::free_balance(USDD, &DexModule::account_id()), 4_000_000);
+		System::assert_last_event(Event::DexModule(crate::Event::AddLiquidity(
+			ALICE, DUST, 1_000_000, USDD, 4_000_000, 4_000_000,
+		)));
+
+		// supplies at the pool price, in either order of currencies
+		assert_noop!(
+			DexModule::add_liquidity(Origin::signed(BOB), USDD, DUST, 5_000_000, 500_000, 2_000_001),
+			Error::<Runtime>::UnacceptableShareIncrement
+		);
+		assert_ok!(DexModule::add_liquidity(
+			Origin::signed(BOB),
+			USDD,
+			DUST,
+			5_000_000,
+			500_000,
+			2_000_000
+		));
+		assert_eq!(DexModule::liquidity_pool(dust_usdd_pair()), (1_500_000, 6_000_000));
+		assert_eq!(Tokens::free_balance(DUST_USDD_LP, &BOB), 2_000_000);
+		assert_eq!(Tokens::total_issuance(DUST_USDD_LP), 6_000_000);
+		System::assert_last_event(Event::DexModule(crate::Event::AddLiquidity(
+			BOB, DUST, 500_000, USDD, 2_000_000, 2_000_000,
+		)));
+	});
+}
+
+#[test]
+fn add_liquidity_requires_valid_trading_pair() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			DexModule::add_liquidity(Origin::signed(ALICE), DUST, DUST, 1_000, 1_000, 0),
+			Error::<Runtime>::InvalidCurrencyId
+		);
+		assert_noop!(
+			DexModule::add_liquidity(Origin::signed(ALICE), DUST, DUST_USDD_LP, 1_000, 1_000, 0),
+			Error::<Runtime>::InvalidCurrencyId
+		);
+		assert_noop!(
+			DexModule::add_liquidity(Origin::signed(ALICE), DUST, USDD, 0, 1_000, 0),
+			Error::<Runtime>::InvalidLiquidityIncrement
+		);
+	});
+}
+
+#[test]
+fn remove_liquidity_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		initialize_pool();
+
+		assert_noop!(
+			DexModule::remove_liquidity(Origin::signed(ALICE), USDD, DUST, 1_000_000, 1_000_001, 250_000),
+			Error::<Runtime>::UnacceptableLiquidityWithdrawn
+		);
+		assert_ok!(DexModule::remove_liquidity(
+			Origin::signed(ALICE),
+			USDD,
+			DUST,
+			1_000_000,
+			1_000_000,
+			250_000
+		));
+		assert_eq!(DexModule::liquidity_pool(dust_usdd_pair()), (750_000, 3_000_000));
+		assert_eq!(Tokens::free_balance(DUST_USDD_LP, &ALICE), 3_000_000);
+		assert_eq!(Tokens::total_issuance(DUST_USDD_LP), 3_000_000);
+		System::assert_last_event(Event::DexModule(crate::Event::RemoveLiquidity(
+			ALICE, DUST, 250_000, USDD, 1_000_000, 1_000_000,
+		)));
+	});
+}
+
+#[test]
+fn swap_with_exact_supply_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		initialize_pool();
+		let dust_balance = Tokens::free_balance(DUST, &BOB);
+		let usdd_balance = Tokens::free_balance(USDD, &BOB);
+
+		assert_noop!(
+			DexModule::swap_with_exact_supply(Origin::signed(BOB), vec![DUST, USDD], 10_000, 39_212),
+			Error::<Runtime>::InsufficientTargetAmount
+		);
+		assert_ok!(DexModule::swap_with_exact_supply(
+			Origin::signed(BOB),
+			vec![DUST, USDD],
+			10_000,
+			39_211
+		));
+		assert_eq!(DexModule::liquidity_pool(dust_usdd_pair()), (1_010_000, 3_960_789));
+		assert_eq!(Tokens::free_balance(DUST, &BOB), dust_balance - 10_000);
+		assert_eq!(Tokens::free_balance(USDD, &BOB), usdd_balance + 39_211);
+		System::assert_last_event(Event::DexModule(crate::Event::Swap(
+			BOB,
+			vec![DUST, USDD],
+			10_000,
+			39_211,
+		)));
+	});
+}
+
+#[test]
+fn swap_with_exact_target_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		initialize_pool();
+		let dust_balance = Tokens::free_balance(DUST, &BOB);
+		let usdd_balance = Tokens::free_balance(USDD, &BOB);
+
+		assert_noop!(
+			DexModule::swap_with_exact_target(Origin::signed(BOB), vec![DUST, USDD], 40_000, 10_203),
+			Error::<Runtime>::ExcessiveSupplyAmount
+		);
+		assert_noop!(
+			DexModule::swap_with_exact_target(Origin::signed(BOB), vec![DUST, USDD], 4_000_000, 10_000_000),
+			Error::<Runtime>::InsufficientLiquidity
+		);
+		assert_ok!(DexModule::swap_with_exact_target(
+			Origin::signed(BOB),
+			vec![DUST, USDD],
+			40_000,
+			10_204
+		));
+		assert_eq!(DexModule::liquidity_pool(dust_usdd_pair()), (1_010_204, 3_960_000));
+		assert_eq!(Tokens::free_balance(DUST, &BOB), dust_balance - 10_204);
+		assert_eq!(Tokens::free_balance(USDD, &BOB), usdd_balance + 40_000);
+		System::assert_last_event(Event::DexModule(crate::Event::Swap(
+			BOB,
+			vec![DUST, USDD],
+			10_204,
+			40_000,
+		)));
+	});
+}
+
+#[test]
+fn swap_along_multi_hop_path_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		initialize_pool();
+		let dust_balance = Tokens::free_balance(DUST, &BOB);
+
+		assert_eq!(
+			DexModule::get_swap_target_amount(&[DUST, USDD, DUST], 10_000, None),
+			Some(9_611)
+		);
+		assert_ok!(DexModule::swap_with_exact_supply(
+			Origin::signed(BOB),
+			vec![DUST, USDD, DUST],
+			10_000,
+			9_611
+		));
+		assert_eq!(DexModule::liquidity_pool(dust_usdd_pair()), (1_000_389, 4_000_000));
+		assert_eq!(Tokens::free_balance(DUST, &BOB), dust_balance - 10_000 + 9_611);
+	});
+}
+
+#[test]
+fn swap_requires_valid_trading_path() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			DexModule::swap_with_exact_supply(Origin::signed(BOB), vec![DUST, USDD], 10_000, 0),
+			Error::<Runtime>::InsufficientLiquidity
+		);
+
+		initialize_pool();
+		assert_noop!(
+			DexModule::swap_with_exact_supply(Origin::signed(BOB), vec![DUST], 10_000, 0),
+			Error::<Runtime>::InvalidTradingPathLength
+		);
+		assert_noop!(
+			DexModule::swap_with_exact_supply(Origin::signed(BOB), vec![DUST, USDD, DUST, USDD], 10_000, 0),
+			Error::<Runtime>::InvalidTradingPathLength
+		);
+		assert_noop!(
+			DexModule::swap_with_exact_target(Origin::signed(BOB), vec![DUST, DUST_USDD_LP], 10_000, 10_000),
+			Error::<Runtime>::InvalidCurrencyId
+		);
+	});
+}
+
+#[test]
+fn price_impact_limit_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		initialize_pool();
+
+		let limit = Some(Ratio::saturating_from_rational(1, 100));
+		assert_eq!(
+			DexModule::get_swap_target_amount(&[DUST, USDD], 10_000, limit),
+			Some(39_211)
+		);
+		assert_eq!(DexModule::get_swap_target_amount(&[DUST, USDD], 20_000, limit), None);
+		assert_noop!(
+			<DexModule as DEXManager<_, _, _>>::swap_with_exact_supply(&BOB, &[DUST, USDD], 20_000, 0, limit),
+			Error::<Runtime>::ExceedPriceImpactLimit
+		);
+		assert_eq!(DexModule::get_liquidity_pool(USDD, DUST), (4_000_000, 1_000_000));
+	});
+}
@@ -48,4 +48,38 @@ impl crate::WeightInfo for () {
 			.saturating_add(DbWeight::get().reads(7 as Weight))
 			.saturating_add(DbWeight::get().writes(5 as Weight))
 	}
+	fn set_sponsorship() -> Weight {
+		(41_386_000 as Weight)
+			.saturating_add(DbWeight::get().reads(2 as Weight))
+			.saturating_add(DbWeight::get().writes(1 as Weight))
+	}
+	fn remove_sponsorship() -> Weight {
+		(58_920_000 as Weight)
+			.saturating_add(DbWeight::get().reads(4 as Weight))
+			.saturating_add(DbWeight::get().writes(4 as Weight))
+	}
+	fn deposit_sponsorship() -> Weight {
+		(96_311_000 as Weight)
+			.saturating_add(DbWeight::get().reads(4 as Weight))
+			.saturating_add(DbWeight::get().writes(2 as Weight))
+	}
+	fn withdraw_sponsorship() -> Weight {
+		(94_027_000 as Weight)
+			.saturating_add(DbWeight::get().reads(4 as Weight))
+			.saturating_add(DbWeight::get().writes(2 as Weight))
+	}
+	fn set_sponsored_callers(c: u32) -> Weight {
+		(43_512_000 as Weight)
+			// Standard Error: 9_000
+			.saturating_add((3_104_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(DbWeight::get().reads(3 as Weight))
+			.saturating_add(DbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
+	}
+	fn set_sponsored_selectors(c: u32) -> Weight {
+		(43_287_000 as Weight)
+			// Standard Error: 9_000
+			.saturating_add((3_087_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(DbWeight::get().reads(3 as Weight))
+			.saturating_add(DbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
+	}
 }
@@ -16,13 +16,15 @@ use frame_support::{
 		EnsureOrigin,
 		ExistenceRequirement,
 		Get,
+		IsSubType,
 		OnKilledAccount,
+		OnUnbalanced,
 		ReservableCurrency,
 		WithdrawReasons,
 	},
 	transactional,
 	weights::{Pays, PostDispatchInfo, Weight},
-	RuntimeDebug,
+	PalletId, RuntimeDebug,
 };
 use frame_system::{ensure_root, ensure_signed, pallet_prelude::*, EnsureOneOf, EnsureRoot, EnsureSigned};
 use primitive_types::{H256, U256};
@@ -30,12 +32,15 @@ use primitive_types::{H256, U256};
 use serde::{Deserialize, Serialize};
 use sha3::{Digest, Keccak256};
 use sp_runtime::{
-	traits::{Convert, DispatchInfoOf, One, PostDispatchInfoOf, SignedExtension, UniqueSaturatedInto},
+	traits::{
+		AccountIdConversion, Convert, DispatchInfoOf, One, PostDispatchInfoOf, Saturating, SignedExtension,
+		UniqueSaturatedInto, Zero,
+	},
 	transaction_validity::TransactionValidityError,
 	Either, TransactionOutcome,
 };
 use sp_std::{marker::PhantomData, vec::Vec};
-use support::{EVMStateRentTrait, ExecutionMode, InvokeContext, TransactionPayment, EVM as EVMTrait};
+use support::{EVMStateRentTrait, ExecutionMode, FeeSponsor, InvokeContext, TransactionPayment, EVM as EVMTrait};
 
 pub use crate::precompiles::{Precompile, Precompiles};
 pub use crate::runner::Runner;
@@ -65,8 +70,17 @@ pub trait WeightInfo {
 	fn disable_contract_development() -> Weight;
 	fn set_code() -> Weight;
 	fn selfdestruct() -> Weight;
+	fn set_sponsorship() -> Weight;
+	fn remove_sponsorship() -> Weight;
+	fn deposit_sponsorship() -> Weight;
+	fn withdraw_sponsorship() -> Weight;
+	fn set_sponsored_callers(c: u32) -> Weight;
+	fn set_sponsored_selectors(c: u32) -> Weight;
 }
 
+/// The id deriving the accounts holding the sponsorship funds of contracts.
+pub const SPONSORSHIP_PALLET_ID: PalletId = PalletId(*b"dust/evs");
+
 // Initially based on Istanbul hard fork configuration.
 static EVM_CONFIG: EvmConfig = EvmConfig {
 	gas_ext_code: 700,
@@ -170,6 +184,9 @@ pub mod module {
 		#[pallet::constant]
 		type DeploymentFee: Get<BalanceOf<Self>>;
 
+		/// Handler for the deployment fee.
+		type OnDeploymentFee: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
 		type FreeDeploymentOrigin: EnsureOrigin<Self::Origin>;
 
 		/// Weight information for the extrinsics in this module.
@@ -200,6 +217,15 @@ pub mod module {
 		}
 	}
 
+	/// Terms on which a contract pays the fees of calls made to it.
+	#[derive(Clone, Copy, Eq, PartialEq, RuntimeDebug, Encode, Decode)]
+	pub struct SponsorshipInfo<BlockNumber> {
+		/// Max number of sponsored calls of a single user per period.
+		pub rate_limit: u32,
+		/// Length of a rate limit period.
+		pub period: BlockNumber,
+	}
+
 	#[derive(Clone, Copy, Eq, PartialEq, RuntimeDebug, Encode, Decode)]
 	pub struct CodeInfo {
 		pub code_size: u32,
@@ -248,6 +274,49 @@ pub mod module {
 	#[pallet::getter(fn extrinsic_origin)]
 	pub type ExtrinsicOrigin<T: Config> = StorageValue<_, T::AccountId>;
 
+	/// Fee sponsorships of contracts.
+	#[pallet::storage]
+	#[pallet::getter(fn sponsorships)]
+	pub type Sponsorships<T: Config> = StorageMap<_, Twox64Concat, EvmAddress, SponsorshipInfo<T::BlockNumber>>;
+
+	/// Callers whose calls to a sponsored contract are sponsored.
+	#[pallet::storage]
+	#[pallet::getter(fn sponsored_callers)]
+	pub type SponsoredCallers<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, EvmAddress, Twox64Concat, EvmAddress, (), OptionQuery>;
+
+	/// Function selectors of a sponsored contract whose calls are sponsored.
+	#[pallet::storage]
+	#[pallet::getter(fn sponsored_selectors)]
+	pub type SponsoredSelectors<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, EvmAddress, Twox64Concat, [u8; 4], (), OptionQuery>;
+
+	/// Start of the current rate limit period and the number of sponsored
+	/// calls made within it, per contract and user.
+	#[pallet::storage]
+	#[pallet::getter(fn sponsored_usage)]
+	pub type SponsoredUsage<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		EvmAddress,
+		Blake2_128Concat,
+		T::AccountId,
+		(T::BlockNumber, u32),
+		ValueQuery,
+	>;
+
+	/// Pending scheduled calls whose fee was reserved from the sponsorship
+	/// funds of their target, by task id, with the target.
+	#[pallet::storage]
+	#[pallet::getter(fn sponsored_tasks)]
+	pub type SponsoredTasks<T: Config> = StorageMap<_, Twox64Concat, Vec<u8>, EvmAddress>;
+
+	/// Number of pending sponsored tasks per contract. The sponsorship of a
+	/// contract is kept until they have run or been cancelled.
+	#[pallet::storage]
+	#[pallet::getter(fn pending_sponsored_tasks)]
+	pub type PendingSponsoredTasks<T: Config> = StorageMap<_, Twox64Concat, EvmAddress, u32, ValueQuery>;
+
 	#[pallet::type_value]
 	pub fn EmptyEventVec<T: Config>() -> Vec<Event<T>> { Vec::new() }
 
@@ -342,6 +411,23 @@ pub mod module {
 		ContractSetCode(EvmAddress),
 		/// Selfdestructed contract code. \[contract\]
 		ContractSelfdestructed(EvmAddress),
+		/// Set the fee sponsorship of a contract. \[contract, rate_limit,
+		/// period\]
+		SponsorshipSet(EvmAddress, u32, T::BlockNumber),
+		/// Removed the fee sponsorship of a contract. \[contract\]
+		SponsorshipRemoved(EvmAddress),
+		/// Deposited sponsorship funds of a contract. \[contract, amount\]
+		SponsorshipDeposited(EvmAddress, BalanceOf<T>),
+		/// Withdrew sponsorship funds of a contract. \[contract, amount\]
+		SponsorshipWithdrawn(EvmAddress, BalanceOf<T>),
+		/// Updated the sponsored callers of a contract. \[contract, callers,
+		/// sponsored\]
+		SponsoredCallersUpdated(EvmAddress, Vec<EvmAddress>, bool),
+		/// Updated the sponsored selectors of a contract. \[contract,
+		/// selectors, sponsored\]
+		SponsoredSelectorsUpdated(EvmAddress, Vec<[u8; 4]>, bool),
+		/// A contract paid the fee of a call. \[contract, who, fee\]
+		FeeSponsored(EvmAddress, T::AccountId, BalanceOf<T>),
 	}
 
 	#[pallet::error]
@@ -370,6 +456,12 @@ pub mod module {
 		ChargeFeeFailed,
 		/// Contract address conflicts with the system contract
 		ConflictContractAddress,
+		/// Contract is not sponsored
+		SponsorshipNotFound,
+		/// Invalid sponsorship rate limit or period
+		InvalidSponsorship,
+		/// Scheduled calls sponsored by the contract are pending
+		SponsoredTasksPending,
 	}
 
 	#[pallet::pallet]
@@ -421,8 +513,9 @@ pub mod module {
 			})
 		}
 
+		/// Execute a call scheduled by `from`, whose fee was reserved from
+		/// `from`.
 		#[pallet::weight(T::GasToWeight::convert(*gas_limit))]
-		#[transactional]
 		pub fn scheduled_call(
 			origin: OriginFor<T>,
 			from: EvmAddress,
@@ -434,48 +527,32 @@ pub mod module {
 		) -> DispatchResultWithPostInfo {
 			ensure_root(origin)?;
 
-			let _from_account = T::AddressMapping::get_account_id(&from);
-			let _payed: NegativeImbalanceOf<T>;
-			#[cfg(not(feature = "with-ethereum-compatibility"))]
-			{
-				// unreserve the transaction fee for gas_limit
-				let weight = T::GasToWeight::convert(gas_limit);
-				let (_, imbalance) = T::ChargeTransactionPayment::unreserve_and_charge_fee(&_from_account, weight)
-					.map_err(|_| Error::<T>::ChargeFeeFailed)?;
-				_payed = imbalance;
-			}
-
-			let info = Runner::<T>::call(from, from, target, input, value, gas_limit, storage_limit, T::config())?;
-
-			if info.exit_reason.is_succeed() {
-				Pallet::<T>::deposit_event(Event::<T>::Executed(target));
-			} else {
-				Pallet::<T>::deposit_event(Event::<T>::ExecutedFailed(target, info.exit_reason, info.output));
-			}
-
-			let used_gas: u64 = info.used_gas.unique_saturated_into();
-
-			#[cfg(not(feature = "with-ethereum-compatibility"))]
-			{
-				use sp_runtime::traits::Zero;
-				let refund_gas = gas_limit.saturating_sub(used_gas);
-				if !refund_gas.is_zero() {
-					// ignore the result to continue. if it fails, just the user will not
-					// be refunded, there will not increase user balance.
-					let _ = T::ChargeTransactionPayment::refund_fee(
-						&_from_account,
-						T::GasToWeight::convert(refund_gas),
-						_payed,
-					);
-				}
-			}
+			let payer = T::AddressMapping::get_account_id(&from);
+			Self::do_scheduled_call(payer, from, target, input, value, gas_limit, storage_limit)
+		}
 
-			Self::process_queued_events();
+		/// Execute the sponsored task `task_id`, a call scheduled by `from`
+		/// whose fee was reserved from the sponsorship funds of its sponsor.
+		/// The task is forgotten even if the call fails, as the scheduler
+		/// drops it either way.
+		#[pallet::weight(T::GasToWeight::convert(*gas_limit))]
+		pub fn scheduled_sponsored_call(
+			origin: OriginFor<T>,
+			task_id: Vec<u8>,
+			from: EvmAddress,
+			target: EvmAddress,
+			input: Vec<u8>,
+			value: BalanceOf<T>,
+			gas_limit: u64,
+			storage_limit: u32,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
 
-			Ok(PostDispatchInfo {
-				actual_weight: Some(T::GasToWeight::convert(used_gas)),
-				pays_fee: Pays::Yes,
-			})
+			let payer = match Self::take_sponsored_task(&task_id) {
+				Some(sponsor) => Self::sponsorship_account(&sponsor),
+				None => T::AddressMapping::get_account_id(&from),
+			};
+			Self::do_scheduled_call(payer, from, target, input, value, gas_limit, storage_limit)
 		}
 
 		/// Issue an EVM create operation. This is similar to a contract
@@ -589,12 +666,13 @@ pub mod module {
 		pub fn deploy(origin: OriginFor<T>, contract: EvmAddress) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
 			let address = T::AddressMapping::get_evm_address(&who).ok_or(Error::<T>::AddressNotMapped)?;
-			T::Currency::withdraw(
+			let fee = T::Currency::withdraw(
 				&who,
 				T::DeploymentFee::get(),
 				WithdrawReasons::FEE,
 				ExistenceRequirement::KeepAlive)?;
 			Self::mark_deployed(contract, Some(address))?;
+			T::OnDeploymentFee::on_unbalanced(fee);
 			Pallet::<T>::deposit_event(Event::<T>::ContractDeployed(contract));
 			Ok(().into())
 		}
@@ -673,6 +751,162 @@ pub mod module {
 
 			Ok(().into())
 		}
+
+		/// Sponsor the fees of calls to `contract` from its sponsorship funds,
+		/// at most `rate_limit` calls per user every `period` blocks. Only calls
+		/// of whitelisted callers or to whitelisted selectors are sponsored.
+		#[pallet::weight(<T as Config>::WeightInfo::set_sponsorship())]
+		#[transactional]
+		pub fn set_sponsorship(
+			origin: OriginFor<T>,
+			contract: EvmAddress,
+			rate_limit: u32,
+			period: T::BlockNumber,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::ensure_maintainer(&who, contract)?;
+			ensure!(rate_limit > 0 && !period.is_zero(), Error::<T>::InvalidSponsorship);
+
+			Sponsorships::<T>::insert(contract, SponsorshipInfo { rate_limit, period });
+
+			Pallet::<T>::deposit_event(Event::<T>::SponsorshipSet(contract, rate_limit, period));
+
+			Ok(().into())
+		}
+
+		/// Stop sponsoring calls to `contract` and clear its whitelists. The
+		/// remaining funds can still be withdrawn. Fails while scheduled calls
+		/// sponsored by `contract` are pending.
+		#[pallet::weight(<T as Config>::WeightInfo::remove_sponsorship())]
+		#[transactional]
+		pub fn remove_sponsorship(origin: OriginFor<T>, contract: EvmAddress) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::ensure_maintainer(&who, contract)?;
+			ensure!(
+				Sponsorships::<T>::contains_key(contract),
+				Error::<T>::SponsorshipNotFound
+			);
+			ensure!(
+				PendingSponsoredTasks::<T>::get(contract).is_zero(),
+				Error::<T>::SponsoredTasksPending
+			);
+
+			Sponsorships::<T>::remove(contract);
+			SponsoredCallers::<T>::remove_prefix(contract, None);
+			SponsoredSelectors::<T>::remove_prefix(contract, None);
+			SponsoredUsage::<T>::remove_prefix(contract, None);
+
+			Pallet::<T>::deposit_event(Event::<T>::SponsorshipRemoved(contract));
+
+			Ok(().into())
+		}
+
+		/// Add `amount` to the sponsorship funds of `contract`.
+		#[pallet::weight(<T as Config>::WeightInfo::deposit_sponsorship())]
+		#[transactional]
+		pub fn deposit_sponsorship(
+			origin: OriginFor<T>,
+			contract: EvmAddress,
+			amount: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::ensure_maintainer(&who, contract)?;
+
+			T::Currency::transfer(
+				&who,
+				&Self::sponsorship_account(&contract),
+				amount,
+				ExistenceRequirement::KeepAlive,
+			)?;
+
+			Pallet::<T>::deposit_event(Event::<T>::SponsorshipDeposited(contract, amount));
+
+			Ok(().into())
+		}
+
+		/// Withdraw `amount` from the sponsorship funds of `contract`. Fees
+		/// reserved for scheduled calls stay in the funds.
+		#[pallet::weight(<T as Config>::WeightInfo::withdraw_sponsorship())]
+		#[transactional]
+		pub fn withdraw_sponsorship(
+			origin: OriginFor<T>,
+			contract: EvmAddress,
+			amount: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::ensure_maintainer(&who, contract)?;
+
+			T::Currency::transfer(
+				&Self::sponsorship_account(&contract),
+				&who,
+				amount,
+				ExistenceRequirement::AllowDeath,
+			)?;
+
+			Pallet::<T>::deposit_event(Event::<T>::SponsorshipWithdrawn(contract, amount));
+
+			Ok(().into())
+		}
+
+		/// Add `callers` to, or remove them from, the callers whose calls to
+		/// `contract` are sponsored.
+		#[pallet::weight(<T as Config>::WeightInfo::set_sponsored_callers(callers.len() as u32))]
+		#[transactional]
+		pub fn set_sponsored_callers(
+			origin: OriginFor<T>,
+			contract: EvmAddress,
+			callers: Vec<EvmAddress>,
+			sponsored: bool,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::ensure_maintainer(&who, contract)?;
+			ensure!(
+				Sponsorships::<T>::contains_key(contract),
+				Error::<T>::SponsorshipNotFound
+			);
+
+			for caller in callers.iter() {
+				if sponsored {
+					SponsoredCallers::<T>::insert(contract, caller, ());
+				} else {
+					SponsoredCallers::<T>::remove(contract, caller);
+				}
+			}
+
+			Pallet::<T>::deposit_event(Event::<T>::SponsoredCallersUpdated(contract, callers, sponsored));
+
+			Ok(().into())
+		}
+
+		/// Add `selectors` to, or remove them from, the function selectors of
+		/// `contract` whose calls are sponsored.
+		#[pallet::weight(<T as Config>::WeightInfo::set_sponsored_selectors(selectors.len() as u32))]
+		#[transactional]
+		pub fn set_sponsored_selectors(
+			origin: OriginFor<T>,
+			contract: EvmAddress,
+			selectors: Vec<[u8; 4]>,
+			sponsored: bool,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::ensure_maintainer(&who, contract)?;
+			ensure!(
+				Sponsorships::<T>::contains_key(contract),
+				Error::<T>::SponsorshipNotFound
+			);
+
+			for selector in selectors.iter() {
+				if sponsored {
+					SponsoredSelectors::<T>::insert(contract, selector, ());
+				} else {
+					SponsoredSelectors::<T>::remove(contract, selector);
+				}
+			}
+
+			Pallet::<T>::deposit_event(Event::<T>::SponsoredSelectorsUpdated(contract, selectors, sponsored));
+
+			Ok(().into())
+		}
 	}
 }
 
@@ -923,6 +1157,135 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	fn ensure_maintainer(who: &T::AccountId, contract: EvmAddress) -> DispatchResult {
+		let maintainer = T::AddressMapping::get_evm_address(who).ok_or(Error::<T>::AddressNotMapped)?;
+		ensure!(
+			Self::query_maintainer(contract)? == maintainer,
+			Error::<T>::NoPermission
+		);
+		Ok(())
+	}
+
+	/// The account holding the sponsorship funds of `contract`.
+	pub fn sponsorship_account(contract: &EvmAddress) -> T::AccountId {
+		SPONSORSHIP_PALLET_ID.into_sub_account(contract)
+	}
+
+	/// The account paying the fees of `who` calling `contract` with `input`.
+	///
+	/// Returns `None` unless `contract` is sponsored, `who` or the called
+	/// selector is whitelisted and `who` is within the rate limit.
+	pub fn call_sponsor(who: &T::AccountId, contract: &EvmAddress, input: &[u8]) -> Option<T::AccountId> {
+		let info = Self::sponsorships(contract)?;
+
+		let caller =
+			T::AddressMapping::get_evm_address(who).unwrap_or_else(|| T::AddressMapping::get_default_evm_address(who));
+		let selector_sponsored = input.get(..4).map_or(false, |bytes| {
+			let mut selector = [0u8; 4];
+			selector.copy_from_slice(bytes);
+			SponsoredSelectors::<T>::contains_key(contract, selector)
+		});
+		if !selector_sponsored && !SponsoredCallers::<T>::contains_key(contract, caller) {
+			return None;
+		}
+
+		let (period_start, calls) = Self::sponsored_usage(contract, who);
+		let now = frame_system::Pallet::<T>::block_number();
+		if now < period_start.saturating_add(info.period) && calls >= info.rate_limit {
+			return None;
+		}
+
+		Some(Self::sponsorship_account(contract))
+	}
+
+	/// Count a call of `who` to `contract` whose `fee` was sponsored towards
+	/// the rate limit of `who`.
+	pub fn note_sponsored_call(who: &T::AccountId, contract: &EvmAddress, fee: BalanceOf<T>) {
+		let info = match Self::sponsorships(contract) {
+			Some(info) => info,
+			None => return,
+		};
+
+		let now = frame_system::Pallet::<T>::block_number();
+		SponsoredUsage::<T>::mutate(contract, who, |(period_start, calls)| {
+			if *calls == 0 || now >= period_start.saturating_add(info.period) {
+				*period_start = now;
+				*calls = 0;
+			}
+			*calls = calls.saturating_add(1);
+		});
+
+		Pallet::<T>::deposit_event(Event::<T>::FeeSponsored(*contract, who.clone(), fee));
+	}
+
+	/// Record that the fee of the scheduled task `task_id` was reserved from
+	/// the sponsorship funds of `contract`.
+	pub fn note_sponsored_task(task_id: Vec<u8>, contract: EvmAddress) {
+		if !SponsoredTasks::<T>::contains_key(&task_id) {
+			PendingSponsoredTasks::<T>::mutate(contract, |count| *count = count.saturating_add(1));
+		}
+		SponsoredTasks::<T>::insert(task_id, contract);
+	}
+
+	/// Forget the task `task_id`, returning the contract sponsoring it, if any.
+	pub fn take_sponsored_task(task_id: &[u8]) -> Option<EvmAddress> {
+		let contract = SponsoredTasks::<T>::take(task_id)?;
+		PendingSponsoredTasks::<T>::mutate_exists(contract, |count| {
+			*count = count
+				.map(|count| count.saturating_sub(1))
+				.filter(|count| !count.is_zero());
+		});
+		Some(contract)
+	}
+
+	#[transactional]
+	fn do_scheduled_call(
+		payer: T::AccountId,
+		from: EvmAddress,
+		target: EvmAddress,
+		input: Vec<u8>,
+		value: BalanceOf<T>,
+		gas_limit: u64,
+		storage_limit: u32,
+	) -> DispatchResultWithPostInfo {
+		let _payed: NegativeImbalanceOf<T>;
+		#[cfg(not(feature = "with-ethereum-compatibility"))]
+		{
+			// unreserve the transaction fee for gas_limit
+			let weight = T::GasToWeight::convert(gas_limit);
+			let (_, imbalance) = T::ChargeTransactionPayment::unreserve_and_charge_fee(&payer, weight)
+				.map_err(|_| Error::<T>::ChargeFeeFailed)?;
+			_payed = imbalance;
+		}
+
+		let info = Runner::<T>::call(from, from, target, input, value, gas_limit, storage_limit, T::config())?;
+
+		if info.exit_reason.is_succeed() {
+			Pallet::<T>::deposit_event(Event::<T>::Executed(target));
+		} else {
+			Pallet::<T>::deposit_event(Event::<T>::ExecutedFailed(target, info.exit_reason, info.output));
+		}
+
+		let used_gas: u64 = info.used_gas.unique_saturated_into();
+
+		#[cfg(not(feature = "with-ethereum-compatibility"))]
+		{
+			let refund_gas = gas_limit.saturating_sub(used_gas);
+			if !refund_gas.is_zero() {
+				// ignore the result to continue. if it fails, just the user will not
+				// be refunded, there will not increase user balance.
+				let _ = T::ChargeTransactionPayment::refund_fee(&payer, T::GasToWeight::convert(refund_gas), _payed);
+			}
+		}
+
+		Self::process_queued_events();
+
+		Ok(PostDispatchInfo {
+			actual_weight: Some(T::GasToWeight::convert(used_gas)),
+			pays_fee: Pays::Yes,
+		})
+	}
+
 	fn ensure_root_or_signed(o: T::Origin) -> Result<Either<(), T::AccountId>, BadOrigin> {
 		EnsureOneOf::<T::AccountId, EnsureRoot<T::AccountId>, EnsureSigned<T::AccountId>>::try_origin(o)
 			.map_or(Err(BadOrigin), Ok)
@@ -1019,6 +1382,21 @@ impl<T: Config> EVMStateRentTrait<T::AccountId, BalanceOf<T>> for Pallet<T> {
 	}
 }
 
+impl<T: Config, C: IsSubType<Call<T>>> FeeSponsor<T::AccountId, C, BalanceOf<T>> for Pallet<T> {
+	fn sponsor_of(who: &T::AccountId, call: &C) -> Option<T::AccountId> {
+		match call.is_sub_type() {
+			Some(Call::call(target, input, ..)) => Self::call_sponsor(who, target, input),
+			_ => None,
+		}
+	}
+
+	fn on_sponsored(who: &T::AccountId, call: &C, fee: BalanceOf<T>) {
+		if let Some(Call::call(target, ..)) = call.is_sub_type() {
+			Self::note_sponsored_call(who, target, fee);
+		}
+	}
+}
+
 pub struct CallKillAccount<T>(PhantomData<T>);
 impl<T: Config> OnKilledAccount<T::AccountId> for CallKillAccount<T> {
 	fn on_killed_account(who: &T::AccountId) {
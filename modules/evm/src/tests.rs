@@ -1341,3 +1341,141 @@ fn evm_execute_mode_should_work() {
 		assert_eq!(balance(alice()), alice_balance);
 	});
 }
+
+#[test]
+fn should_sponsor_fees() {
+	new_test_ext().execute_with(|| {
+		let contract = H160::from_str("3000000000000000000000000000000000000001").unwrap();
+		assert_ok!(EVM::on_contract_initialization(&contract, &alice(), vec![0x00]));
+
+		let alice_account_id = <Test as Config>::AddressMapping::get_account_id(&alice());
+		let bob_account_id = <Test as Config>::AddressMapping::get_account_id(&bob());
+		let sponsorship_account = EVM::sponsorship_account(&contract);
+		let sponsor_of = |call: &mock::Call| <EVM as FeeSponsor<_, _, u64>>::sponsor_of(&bob_account_id, call);
+
+		// only the maintainer manages the sponsorship
+		assert_noop!(
+			EVM::set_sponsorship(Origin::signed(bob_account_id.clone()), contract, 2, 10),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			EVM::set_sponsorship(Origin::signed(alice_account_id.clone()), contract, 0, 10),
+			Error::<Test>::InvalidSponsorship
+		);
+		assert_noop!(
+			EVM::set_sponsored_callers(Origin::signed(alice_account_id.clone()), contract, vec![bob()], true),
+			Error::<Test>::SponsorshipNotFound
+		);
+
+		assert_ok!(EVM::set_sponsorship(
+			Origin::signed(alice_account_id.clone()),
+			contract,
+			2,
+			10
+		));
+		let event = Event::EVM(crate::Event::SponsorshipSet(contract, 2, 10));
+		assert!(System::events().iter().any(|record| record.event == event));
+
+		assert_ok!(EVM::deposit_sponsorship(
+			Origin::signed(alice_account_id.clone()),
+			contract,
+			1000
+		));
+		assert_eq!(Balances::free_balance(&sponsorship_account), 1000);
+		assert_eq!(balance(alice()), INITIAL_BALANCE - 1000);
+
+		// calls are sponsored for whitelisted selectors or callers only
+		let call = mock::Call::EVM(crate::Call::call(contract, vec![0xaa, 0xbb, 0xcc, 0xdd], 0, 1000000, 0));
+		let other_call = mock::Call::EVM(crate::Call::call(contract, vec![0x11, 0x22, 0x33, 0x44], 0, 1000000, 0));
+		assert_eq!(sponsor_of(&call), None);
+
+		assert_ok!(EVM::set_sponsored_selectors(
+			Origin::signed(alice_account_id.clone()),
+			contract,
+			vec![[0xaa, 0xbb, 0xcc, 0xdd]],
+			true
+		));
+		assert_eq!(sponsor_of(&call), Some(sponsorship_account.clone()));
+		assert_eq!(sponsor_of(&other_call), None);
+
+		assert_ok!(EVM::set_sponsored_callers(
+			Origin::signed(alice_account_id.clone()),
+			contract,
+			vec![bob()],
+			true
+		));
+		assert_eq!(sponsor_of(&other_call), Some(sponsorship_account.clone()));
+		assert_eq!(
+			sponsor_of(&mock::Call::System(frame_system::Call::remark(vec![]))),
+			None
+		);
+
+		// each user gets `rate_limit` sponsored calls per period
+		<EVM as FeeSponsor<_, _, u64>>::on_sponsored(&bob_account_id, &call, 10);
+		let event = Event::EVM(crate::Event::FeeSponsored(contract, bob_account_id.clone(), 10));
+		assert!(System::events().iter().any(|record| record.event == event));
+		<EVM as FeeSponsor<_, _, u64>>::on_sponsored(&bob_account_id, &other_call, 10);
+		assert_eq!(EVM::sponsored_usage(contract, &bob_account_id), (1, 2));
+		assert_eq!(sponsor_of(&call), None);
+
+		System::set_block_number(11);
+		assert_eq!(sponsor_of(&call), Some(sponsorship_account.clone()));
+		<EVM as FeeSponsor<_, _, u64>>::on_sponsored(&bob_account_id, &call, 10);
+		assert_eq!(EVM::sponsored_usage(contract, &bob_account_id), (11, 1));
+
+		assert_noop!(
+			EVM::withdraw_sponsorship(Origin::signed(bob_account_id.clone()), contract, 400),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(EVM::withdraw_sponsorship(
+			Origin::signed(alice_account_id.clone()),
+			contract,
+			400
+		));
+		assert_eq!(Balances::free_balance(&sponsorship_account), 600);
+		assert_eq!(balance(alice()), INITIAL_BALANCE - 600);
+
+		// the sponsorship is kept while sponsored tasks are pending
+		EVM::note_sponsored_task(b"task0".to_vec(), contract);
+		assert_noop!(
+			EVM::remove_sponsorship(Origin::signed(alice_account_id.clone()), contract),
+			Error::<Test>::SponsoredTasksPending
+		);
+		assert_eq!(EVM::take_sponsored_task(b"task0"), Some(contract));
+
+		// removing the sponsorship clears the whitelists and keeps the funds
+		assert_ok!(EVM::remove_sponsorship(
+			Origin::signed(alice_account_id.clone()),
+			contract
+		));
+		assert_eq!(sponsor_of(&call), None);
+		assert_eq!(EVM::sponsored_callers(contract, bob()), None);
+		assert_eq!(EVM::sponsored_usage(contract, &bob_account_id), (0, 0));
+		assert_eq!(Balances::free_balance(&sponsorship_account), 600);
+		assert_noop!(
+			EVM::remove_sponsorship(Origin::signed(alice_account_id), contract),
+			Error::<Test>::SponsorshipNotFound
+		);
+	});
+}
+
+#[test]
+fn should_track_sponsored_tasks() {
+	new_test_ext().execute_with(|| {
+		let contract = H160::from_str("3000000000000000000000000000000000000001").unwrap();
+
+		EVM::note_sponsored_task(b"task0".to_vec(), contract);
+		EVM::note_sponsored_task(b"task1".to_vec(), contract);
+		assert_eq!(EVM::sponsored_tasks(b"task0".to_vec()), Some(contract));
+		assert_eq!(EVM::pending_sponsored_tasks(contract), 2);
+
+		// a task is taken once, when it is cancelled or runs
+		assert_eq!(EVM::take_sponsored_task(b"task1"), Some(contract));
+		assert_eq!(EVM::take_sponsored_task(b"task1"), None);
+		assert_eq!(EVM::pending_sponsored_tasks(contract), 1);
+
+		assert_eq!(EVM::take_sponsored_task(b"task0"), Some(contract));
+		assert_eq!(EVM::sponsored_tasks(b"task0".to_vec()), None);
+		assert!(!PendingSponsoredTasks::<Test>::contains_key(contract));
+	});
+}
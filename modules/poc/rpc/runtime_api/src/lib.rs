@@ -0,0 +1,35 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::all)]
+
+use codec::Codec;
+use sp_runtime::Perbill;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	pub trait PocApi<AccountId, Balance, BlockNumber> where
+		AccountId: Codec,
+		Balance: Codec,
+		BlockNumber: Codec,
+	{
+		/// Candidates ranked by the voting weight approving them.
+		fn candidate_rankings() -> Vec<(AccountId, Balance)>;
+
+		/// The members the next era change would elect, with their backing stake.
+		fn projected_members() -> Vec<(AccountId, Balance)>;
+
+		/// Voting weight of all the commitments of `who`.
+		fn voting_weight(who: AccountId) -> Balance;
+
+		/// Voter rewards `who` can currently claim.
+		fn pending_rewards(who: AccountId) -> Balance;
+
+		/// Blocks from which the unbonding commitments of `who` can be withdrawn, by commitment id.
+		fn unbonding_ends(who: AccountId) -> Vec<(u32, BlockNumber)>;
+
+		/// Reward of the whole council for an era.
+		fn era_council_rewards() -> Balance;
+
+		/// Slashes of `who` as a council member, as (era, fraction, slashed deposit, slashed backing).
+		fn slashing_history(who: AccountId) -> Vec<(u32, Perbill, Balance, Balance)>;
+	}
+}
@@ -0,0 +1,174 @@
+//! RPC interface for the PoC module.
+
+use codec::Codec;
+use jsonrpc_core::{Error, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_rpc::number::NumberOrHex;
+use sp_runtime::{
+	generic::BlockId,
+	traits::{Block as BlockT, MaybeDisplay},
+	Perbill,
+};
+use std::{convert::TryInto, marker::PhantomData, sync::Arc};
+
+pub use module_poc_rpc_runtime_api::PocApi as PocRuntimeApi;
+pub use rpc_impl_PocApi::gen_server::PocApi as PocApiServer;
+
+/// A slash of a council member for misconduct.
+#[derive(Debug, Eq, PartialEq, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SlashRecord {
+	/// Era the member was slashed in
+	pub era: u32,
+	/// Share of the candidacy deposit and backing taken
+	pub fraction: Perbill,
+	/// Amount slashed from the candidacy deposit
+	pub deposit_slashed: NumberOrHex,
+	/// Amount slashed from the backing commitments
+	pub backers_slashed: NumberOrHex,
+}
+
+/// PoC election standings and rewards.
+#[rpc(server)]
+pub trait PocApi<BlockHash, AccountId, BlockNumber> {
+	/// Candidates ranked by the voting weight approving them.
+	#[rpc(name = "poc_candidateRankings")]
+	fn candidate_rankings(&self, at: Option<BlockHash>) -> Result<Vec<(AccountId, NumberOrHex)>>;
+
+	/// The members the next era change would elect, with their backing stake.
+	#[rpc(name = "poc_projectedMembers")]
+	fn projected_members(&self, at: Option<BlockHash>) -> Result<Vec<(AccountId, NumberOrHex)>>;
+
+	/// Voting weight of all the commitments of an account.
+	#[rpc(name = "poc_votingWeight")]
+	fn voting_weight(&self, who: AccountId, at: Option<BlockHash>) -> Result<NumberOrHex>;
+
+	/// Voter rewards an account can currently claim.
+	#[rpc(name = "poc_pendingRewards")]
+	fn pending_rewards(&self, who: AccountId, at: Option<BlockHash>) -> Result<NumberOrHex>;
+
+	/// Blocks from which the unbonding commitments of an account can be
+	/// withdrawn, by commitment id.
+	#[rpc(name = "poc_unbondingEnds")]
+	fn unbonding_ends(&self, who: AccountId, at: Option<BlockHash>) -> Result<Vec<(u32, BlockNumber)>>;
+
+	/// Reward of the whole council for an era.
+	#[rpc(name = "poc_eraCouncilRewards")]
+	fn era_council_rewards(&self, at: Option<BlockHash>) -> Result<NumberOrHex>;
+
+	/// Slashes of an account as a council member, oldest first.
+	#[rpc(name = "poc_slashingHistory")]
+	fn slashing_history(&self, who: AccountId, at: Option<BlockHash>) -> Result<Vec<SlashRecord>>;
+}
+
+fn runtime_err<T: std::fmt::Debug>(err: T) -> Error {
+	Error {
+		code: ErrorCode::InternalError,
+		message: "Unable to query the PoC module.".into(),
+		data: Some(format!("{:?}", err).into()),
+	}
+}
+
+fn to_number<Balance: TryInto<NumberOrHex> + MaybeDisplay + Copy>(balance: Balance) -> Result<NumberOrHex> {
+	balance.try_into().map_err(|_| Error {
+		code: ErrorCode::InvalidParams,
+		message: format!("{} doesn't fit in NumberOrHex representation", balance),
+		data: None,
+	})
+}
+
+pub struct Poc<C, B, Balance> {
+	client: Arc<C>,
+	_marker: PhantomData<(B, Balance)>,
+}
+
+impl<C, B, Balance> Poc<C, B, Balance> {
+	pub fn new(client: Arc<C>) -> Self {
+		Self {
+			client,
+			_marker: Default::default(),
+		}
+	}
+}
+
+impl<C, Block, AccountId, Balance, BlockNumber> PocApi<<Block as BlockT>::Hash, AccountId, BlockNumber>
+	for Poc<C, Block, Balance>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: PocRuntimeApi<Block, AccountId, Balance, BlockNumber>,
+	AccountId: Codec,
+	Balance: Codec + MaybeDisplay + Copy + TryInto<NumberOrHex> + Send + Sync + 'static,
+	BlockNumber: Codec,
+{
+	fn candidate_rankings(&self, at: Option<<Block as BlockT>::Hash>) -> Result<Vec<(AccountId, NumberOrHex)>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		api.candidate_rankings(&at)
+			.map_err(runtime_err)?
+			.into_iter()
+			.map(|(who, votes)| Ok((who, to_number(votes)?)))
+			.collect()
+	}
+
+	fn projected_members(&self, at: Option<<Block as BlockT>::Hash>) -> Result<Vec<(AccountId, NumberOrHex)>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		api.projected_members(&at)
+			.map_err(runtime_err)?
+			.into_iter()
+			.map(|(who, backing)| Ok((who, to_number(backing)?)))
+			.collect()
+	}
+
+	fn voting_weight(&self, who: AccountId, at: Option<<Block as BlockT>::Hash>) -> Result<NumberOrHex> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		to_number(api.voting_weight(&at, who).map_err(runtime_err)?)
+	}
+
+	fn pending_rewards(&self, who: AccountId, at: Option<<Block as BlockT>::Hash>) -> Result<NumberOrHex> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		to_number(api.pending_rewards(&at, who).map_err(runtime_err)?)
+	}
+
+	fn unbonding_ends(&self, who: AccountId, at: Option<<Block as BlockT>::Hash>) -> Result<Vec<(u32, BlockNumber)>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		api.unbonding_ends(&at, who).map_err(runtime_err)
+	}
+
+	fn era_council_rewards(&self, at: Option<<Block as BlockT>::Hash>) -> Result<NumberOrHex> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		to_number(api.era_council_rewards(&at).map_err(runtime_err)?)
+	}
+
+	fn slashing_history(&self, who: AccountId, at: Option<<Block as BlockT>::Hash>) -> Result<Vec<SlashRecord>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		api.slashing_history(&at, who)
+			.map_err(runtime_err)?
+			.into_iter()
+			.map(|(era, fraction, deposit_slashed, backers_slashed)| {
+				Ok(SlashRecord {
+					era,
+					fraction,
+					deposit_slashed: to_number(deposit_slashed)?,
+					backers_slashed: to_number(backers_slashed)?,
+				})
+			})
+			.collect()
+	}
+}
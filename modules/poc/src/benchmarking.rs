@@ -15,29 +15,33 @@ benchmarks! {
 	}
 
 	on_initialize_era {
-		// benchmark election worst-case (everyone votes for everyone else who is a valid candidate)
-		// TODO: commitments are weakly capped - 1k is a conservative ceiling. re-run if surpassed
+		// benchmark election worst-case (every candidate has votes)
 		// TODO: if MaxMembers changes (number of winners), this benchmark should re-run
-		let c in 0..1_000;
-		for i in 0..1_000 {
-			let voter: T::AccountId = account("voter", i, 0);
-			let candidate: T::AccountId = account("candidate", i, 0);
-			T::Currency::deposit_creating(&voter, BalanceOf::<T>::from(100_001 * DUST));
-			T::Currency::deposit_creating(&candidate, BalanceOf::<T>::from(1_000_001 * DUST));
-
+		let c in 0..T::MaxCandidates::get();
+		// every voter approves `MaxVotes` candidates
+		let v in 0..T::MaxCommitments::get();
+		let candidates: Vec<T::AccountId> = (0..c).map(|i| account("candidate", i, 0)).collect();
+		for candidate in candidates.iter() {
+			T::Currency::deposit_creating(candidate, BalanceOf::<T>::from(1_000_001 * DUST));
 			let _ = Pallet::<T>::start_candidacy(
 				RawOrigin::Signed(candidate.clone()).into()
 			);
+		}
+		for i in 0..v {
+			let voter: T::AccountId = account("voter", i, 0);
+			T::Currency::deposit_creating(&voter, BalanceOf::<T>::from(100_001 * DUST));
 
+			let approved: Vec<T::AccountId> = (0..T::MaxVotes::get())
+				.map(|j| account("candidate", (i + j) % c.max(1), 0))
+				.collect();
 			let amount: BalanceOf<T> = BalanceOf::<T>::from(100_000 * DUST);
 			let _ = Pallet::<T>::commit(
-				RawOrigin::Signed(voter.clone()).into(),
+				RawOrigin::Signed(voter).into(),
 				amount,
 				LockDuration::OneYear,
-				candidate
+				approved
 			);
 		}
-
 	}: {
 		// trigger the era change block
 		Pallet::<T>::on_initialize((7 * DAYS).into());
@@ -65,21 +69,69 @@ benchmarks! {
 
 	}: _(RawOrigin::Signed(alice))
 
-	commit {
+	resign {
 		let alice: T::AccountId = account("alice", 0, 0);
 		let bob: T::AccountId = account("bob", 0, 0);
 
+		// alice is a member and bob the runner-up taking her seat
+		let deposit: BalanceOf<T> = BalanceOf::<T>::from(1_000_001 * DUST);
+		for candidate in [alice.clone(), bob.clone()].iter() {
+			T::Currency::deposit_creating(candidate, deposit);
+			let _ = Pallet::<T>::start_candidacy(
+				RawOrigin::Signed(candidate.clone()).into(),
+			);
+		}
+		Members::<T>::put(vec![alice.clone()]);
+		RunnersUp::<T>::put(vec![bob]);
+
+	}: _(RawOrigin::Signed(alice))
+
+	set_parameters {
+		let origin = T::ParametersOrigin::successful_origin();
+		let mut parameters = Pallet::<T>::parameters();
+		parameters.era_duration = 14 * DAYS;
+		parameters.ten_years.voting_multiplier = 50;
+
+	}: _<T::Origin>(origin, parameters)
+
+	slash_member {
+		// every commitment backs the slashed member
+		let c in 0..1000;
+		let alice: T::AccountId = account("alice", 0, 0);
+		T::Currency::deposit_creating(&alice, BalanceOf::<T>::from(1_000_001 * DUST));
+		let _ = Pallet::<T>::start_candidacy(
+			RawOrigin::Signed(alice.clone()).into(),
+		);
+		Members::<T>::put(vec![alice.clone()]);
+		for i in 0..c {
+			let voter: T::AccountId = account("voter", i, 0);
+			T::Currency::deposit_creating(&voter, BalanceOf::<T>::from(100_001 * DUST));
+			let _ = Pallet::<T>::commit(
+				RawOrigin::Signed(voter).into(),
+				BalanceOf::<T>::from(100_000 * DUST),
+				LockDuration::OneYear,
+				vec![alice.clone()],
+			);
+		}
+		let origin = T::SlashOrigin::successful_origin();
+
+	}: _<T::Origin>(origin, alice, Perbill::from_percent(50), true, c)
+
+	commit {
+		let v in 1..T::MaxVotes::get();
+		let alice: T::AccountId = account("alice", 0, 0);
+		let candidates: Vec<T::AccountId> = (0..v).map(|i| account("candidate", i, 0)).collect();
+
 		// alice needs funds
 		let deposit: BalanceOf<T> = BalanceOf::<T>::from(100_001 * DUST);
 		T::Currency::deposit_creating(&alice, deposit);
 
 		let amount: BalanceOf<T> = BalanceOf::<T>::from(100_000 * DUST);
-	}: _(RawOrigin::Signed(alice), amount, LockDuration::OneYear, bob)
+	}: _(RawOrigin::Signed(alice), amount, LockDuration::OneYear, candidates)
 
 
 	add_funds {
 		let alice: T::AccountId = account("alice", 0, 0);
-		let bob: T::AccountId = account("bob", 0, 0);
 
 		// alice needs funds
 		let deposit: BalanceOf<T> = BalanceOf::<T>::from(200_001 * DUST);
@@ -92,14 +144,13 @@ benchmarks! {
 			RawOrigin::Signed(alice.clone()).into(),
 			amount,
 			LockDuration::OneYear,
-			bob
+			max_votes::<T>()
 		);
 
-	}: _(RawOrigin::Signed(alice), amount)
+	}: _(RawOrigin::Signed(alice), 0, amount)
 
 	unbond {
 		let alice: T::AccountId = account("alice", 0, 0);
-		let bob: T::AccountId = account("bob", 0, 0);
 
 		// alice needs funds
 		let deposit: BalanceOf<T> = BalanceOf::<T>::from(200_001 * DUST);
@@ -112,14 +163,32 @@ benchmarks! {
 			RawOrigin::Signed(alice.clone()).into(),
 			amount,
 			LockDuration::OneYear,
-			bob
+			max_votes::<T>()
 		);
 
-	}: _(RawOrigin::Signed(alice))
+	}: _(RawOrigin::Signed(alice), 0)
+
+	unbond_early {
+		let alice: T::AccountId = account("alice", 0, 0);
+
+		// alice needs funds
+		let deposit: BalanceOf<T> = BalanceOf::<T>::from(200_001 * DUST);
+		T::Currency::deposit_creating(&alice, deposit);
+
+		let amount: BalanceOf<T> = BalanceOf::<T>::from(100_000 * DUST);
+
+		// she makes a committed, voting commitment
+		let _ = Pallet::<T>::commit(
+			RawOrigin::Signed(alice.clone()).into(),
+			amount,
+			LockDuration::TenYears,
+			max_votes::<T>()
+		);
+
+	}: _(RawOrigin::Signed(alice), 0)
 
 	withdraw {
 		let alice: T::AccountId = account("alice", 0, 0);
-		let bob: T::AccountId = account("bob", 0, 0);
 
 		// alice needs funds
 		let deposit: BalanceOf<T> = BalanceOf::<T>::from(200_001 * DUST);
@@ -132,23 +201,46 @@ benchmarks! {
 			RawOrigin::Signed(alice.clone()).into(),
 			amount,
 			LockDuration::OneMonth,
-			bob
+			max_votes::<T>()
 		);
 
 		// she unbonds
 		let _ = Pallet::<T>::unbond(
 			RawOrigin::Signed(alice.clone()).into(),
+			0,
 		);
 
 		// skip 1 month
 		frame_system::Pallet::<T>::set_block_number((31 * DAYS).into());
 
-	}: _(RawOrigin::Signed(alice))
+	}: _(RawOrigin::Signed(alice), 0)
 
-	vote_candidate {
+	vote_candidates {
+		// every approval is replaced
+		let v in 1..T::MaxVotes::get();
+		let alice: T::AccountId = account("alice", 0, 0);
+		let candidates: Vec<T::AccountId> = (0..v).map(|i| account("voted", i, 0)).collect();
+
+		// alice needs funds
+		let deposit: BalanceOf<T> = BalanceOf::<T>::from(200_001 * DUST);
+		T::Currency::deposit_creating(&alice, deposit);
+
+		let amount: BalanceOf<T> = BalanceOf::<T>::from(100_000 * DUST);
+
+		// she makes initial commitment
+		let _ = Pallet::<T>::commit(
+			RawOrigin::Signed(alice.clone()).into(),
+			amount,
+			LockDuration::OneYear,
+			max_votes::<T>()
+		);
+
+	}: _(RawOrigin::Signed(alice), 0, candidates)
+
+	claim_rewards {
+		// claim every era of the reward history
+		let e in 1..T::HistoryDepth::get();
 		let alice: T::AccountId = account("alice", 0, 0);
-		let bob: T::AccountId = account("bob", 0, 0);
-		let charlie: T::AccountId = account("charlie", 0, 0);
 
 		// alice needs funds
 		let deposit: BalanceOf<T> = BalanceOf::<T>::from(200_001 * DUST);
@@ -161,11 +253,22 @@ benchmarks! {
 			RawOrigin::Signed(alice.clone()).into(),
 			amount,
 			LockDuration::OneYear,
-			bob
+			max_votes::<T>()
 		);
 
-	}: _(RawOrigin::Signed(alice), charlie)
+		// her commitment earns rewards for `e` eras
+		let era_duration: T::BlockNumber = Pallet::<T>::parameters().era_duration.into();
+		for i in 1..=(e + 1) {
+			Pallet::<T>::on_initialize(era_duration * i.into());
+		}
+
+	}: _(RawOrigin::Signed(alice), 0, (1, e), false)
+
+}
 
+/// Approve `MaxVotes` candidates, the worst case for updating the tallies.
+fn max_votes<T: Config>() -> Vec<T::AccountId> {
+	(0..T::MaxVotes::get()).map(|i| account("candidate", i, 0)).collect()
 }
 
 // auto-generate benchmark tests
@@ -9,25 +9,28 @@
 use frame_support::{
 	pallet_prelude::*,
 	traits::{
-		Currency, ReservableCurrency, IsType, WithdrawReasons, ExistenceRequirement,
-		ChangeMembers,
+		Currency, ReservableCurrency, LockableCurrency, LockIdentifier, IsType, WithdrawReasons,
+		ChangeMembers, StorageVersion, CurrencyToVote, OnUnbalanced, Imbalance, ExistenceRequirement,
 	},
 	weights::Weight,
 	ensure,
 	transactional,
 };
-use sp_runtime::{Perbill, PerThing};
+use codec::EncodeLike;
+use sp_runtime::{traits::AtLeast32BitUnsigned, Perbill, PerThing};
+use sp_npos_elections::{seq_phragmen, ElectionResult, VoteWeight};
 use frame_support::sp_runtime::traits::{
 	Zero, Saturating,
-	CheckedAdd, CheckedDiv
+	CheckedAdd, CheckedDiv, CheckedSub
 };
 use frame_system::pallet_prelude::*;
-use sp_std::prelude::*;
+use sp_std::{prelude::*, vec};
 
 #[cfg(feature = "std")]
 pub use serde::{Deserialize, Serialize};
 
 mod benchmarking;
+pub mod migrations;
 mod mock;
 mod tests;
 pub mod weights;
@@ -35,13 +38,33 @@ pub mod weights;
 pub use module::*;
 
 pub type EraIndex = u32;
+/// Identifier of a commitment among the commitments of an account.
+pub type CommitmentId = u32;
+
+/// Lock on the committed funds of an account.
+pub const POC_LOCK_ID: LockIdentifier = *b"dust/poc";
 pub type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+pub type NegativeImbalanceOf<T> =
+	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::NegativeImbalance;
 pub type CommitmentOf<T> =
 	Commitment<
 		<T as frame_system::Config>::AccountId,
 		BalanceOf<T>,
 		<T as frame_system::Config>::BlockNumber,
 	>;
+pub type PocParametersOf<T> = PocParameters<BalanceOf<T>>;
+pub type VotingAmountsOf<T> = VotingAmounts<BalanceOf<T>>;
+pub type SlashRecordOf<T> = SlashRecord<BalanceOf<T>>;
+
+/// Notified when commitments of an account stop voting or shrink, such as to
+/// re-weigh the votes they back elsewhere.
+pub trait OnVotesRemoved<AccountId> {
+	fn on_votes_removed(who: &AccountId);
+}
+
+impl<AccountId> OnVotesRemoved<AccountId> for () {
+	fn on_votes_removed(_who: &AccountId) {}
+}
 
 
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
@@ -49,7 +72,7 @@ pub type CommitmentOf<T> =
 pub enum LockState<BlockNumber> {
 	/// Locked /w voting power
 	Committed,
-	/// BlockNumber when Unbonding period started
+	/// BlockNumber when the Unbonding period ends
 	Unbonding(BlockNumber),
 }
 
@@ -67,7 +90,131 @@ pub struct Commitment<AccountId, BalanceOf, BlockNumber> {
 	pub state: LockState<BlockNumber>,
 	pub duration: LockDuration,
 	pub amount: BalanceOf,
-	pub candidate: AccountId,
+	/// Candidates approved by the commitment, sorted
+	pub candidates: Vec<AccountId>,
+	/// First era the commitment earns voter rewards for
+	pub since: EraIndex,
+	/// Era from which the commitment no longer earns voter rewards, once unbonding
+	pub until: Option<EraIndex>,
+}
+
+/// Committed amounts voting for an account, per lock duration. Their voting
+/// weight follows the current voting multipliers.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, Default, PartialEq, Eq, RuntimeDebug)]
+pub struct VotingAmounts<Balance> {
+	pub one_month: Balance,
+	pub one_year: Balance,
+	pub ten_years: Balance,
+}
+
+impl<Balance: AtLeast32BitUnsigned + Copy> VotingAmounts<Balance> {
+	fn amount_mut(&mut self, duration: &LockDuration) -> &mut Balance {
+		match duration {
+			LockDuration::OneMonth => &mut self.one_month,
+			LockDuration::OneYear  => &mut self.one_year,
+			LockDuration::TenYears => &mut self.ten_years,
+		}
+	}
+
+	fn add(&mut self, duration: &LockDuration, amount: Balance) {
+		let total = self.amount_mut(duration);
+		*total = total.saturating_add(amount);
+	}
+
+	fn sub(&mut self, duration: &LockDuration, amount: Balance) {
+		let total = self.amount_mut(duration);
+		*total = total.saturating_sub(amount);
+	}
+
+	pub fn is_zero(&self) -> bool {
+		self.one_month.is_zero() && self.one_year.is_zero() && self.ten_years.is_zero()
+	}
+
+	/// Committed amount across the lock durations.
+	pub fn total(&self) -> Balance {
+		self.one_month.saturating_add(self.one_year).saturating_add(self.ten_years)
+	}
+
+	/// Voting weight of the amounts under the multipliers of `parameters`.
+	pub fn weight(&self, parameters: &PocParameters<Balance>) -> Balance {
+		self.one_month
+			.saturating_mul(parameters.one_month.voting_multiplier.into())
+			.saturating_add(self.one_year.saturating_mul(parameters.one_year.voting_multiplier.into()))
+			.saturating_add(self.ten_years.saturating_mul(parameters.ten_years.voting_multiplier.into()))
+	}
+}
+
+/// Unbonding period and voting power of a lock duration.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct LockTerms {
+	/// Blocks between the start of unbonding and the withdrawal
+	pub unbonding_period: primitives::BlockNumber,
+	/// Voting weight per committed unit
+	pub voting_multiplier: u32,
+}
+
+/// Economics of the PoC system, adjustable by governance.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct PocParameters<Balance> {
+	/// How long (in block count) is the era
+	pub era_duration: primitives::BlockNumber,
+	/// Yearly nominator returns in % APY
+	pub nominator_apy: Perbill,
+	/// Yearly inflation rate to pay for council rewards
+	pub council_inflation: Perbill,
+	/// Minimum amount of currency needed to create a commitment
+	pub min_lock_amount: Balance,
+	/// Total amount of currency that can be locked
+	pub total_locked_cap: Balance,
+	pub one_month: LockTerms,
+	pub one_year: LockTerms,
+	pub ten_years: LockTerms,
+}
+
+impl<Balance> PocParameters<Balance> {
+	pub fn lock_terms(&self, duration: &LockDuration) -> &LockTerms {
+		match duration {
+			LockDuration::OneMonth => &self.one_month,
+			LockDuration::OneYear  => &self.one_year,
+			LockDuration::TenYears => &self.ten_years,
+		}
+	}
+
+	fn max_voting_multiplier(&self) -> u32 {
+		self.one_month.voting_multiplier
+			.max(self.one_year.voting_multiplier)
+			.max(self.ten_years.voting_multiplier)
+	}
+
+	fn max_unbonding_period(&self) -> primitives::BlockNumber {
+		self.one_month.unbonding_period
+			.max(self.one_year.unbonding_period)
+			.max(self.ten_years.unbonding_period)
+	}
+}
+
+/// A slash of a council member for misconduct.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct SlashRecord<Balance> {
+	/// Era the member was slashed in
+	pub era: EraIndex,
+	/// Share of the candidacy deposit and backing taken
+	pub fraction: Perbill,
+	/// Amount slashed from the candidacy deposit
+	pub deposit_slashed: Balance,
+	/// Amount slashed from the backing commitments
+	pub backers_slashed: Balance,
+}
+
+/// Terms the voter rewards of an era are paid with.
+#[derive(Encode, Decode, Clone, PartialEq, RuntimeDebug)]
+pub struct RewardTerms {
+	pub era_duration: primitives::BlockNumber,
+	pub nominator_apy: Perbill,
 }
 
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
@@ -92,12 +239,17 @@ impl Default for LockDuration {
 pub trait WeightInfo {
 	fn start_candidacy() -> Weight;
 	fn stop_candidacy() -> Weight;
-	fn commit() -> Weight;
+	fn resign() -> Weight;
+	fn set_parameters() -> Weight;
+	fn unbond_early() -> Weight;
+	fn slash_member(c: u32, ) -> Weight;
+	fn commit(v: u32) -> Weight;
 	fn add_funds() -> Weight;
 	fn unbond() -> Weight;
 	fn withdraw() -> Weight;
-	fn vote_candidate() -> Weight;
-	fn on_initialize_era(c: u32) -> Weight;
+	fn vote_candidates(v: u32) -> Weight;
+	fn claim_rewards(e: u32) -> Weight;
+	fn on_initialize_era(c: u32, v: u32) -> Weight;
 	fn on_initialize_empty() -> Weight;
 }
 
@@ -105,41 +257,89 @@ pub trait WeightInfo {
 #[frame_support::pallet]
 pub mod module {
 	use super::*;
-	use sp_std::iter::FromIterator;
-	use sp_std::collections::btree_map::BTreeMap;
+
+	/// Version 1 introduced the per-candidate `Votes` tallies.
+	/// Version 2 locks the committed funds instead of burning them.
+	/// Version 3 allows multiple commitments per account.
+	/// Version 4 tracks the eras commitments earn voter rewards for.
+	/// Version 5 lets commitments approve several candidates.
+	/// Version 6 counts the commitments.
+	/// Version 7 tallies the votes per lock duration and records when unbonding ends.
+	/// Version 8 tallies the votes cast by each account.
+	/// Version 9 records the candidates approved by each account.
+	pub(crate) const STORAGE_VERSION: StorageVersion = StorageVersion::new(9);
 
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
 		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
         type WeightInfo: WeightInfo;
-		/// Reservable currency for Candidacy bonds
-		type Currency: Currency<Self::AccountId> + ReservableCurrency<Self::AccountId>;
-		/// How long (in block count) is the era
+		/// Reservable currency for Candidacy bonds, lockable for commitments
+		type Currency: Currency<Self::AccountId>
+			+ ReservableCurrency<Self::AccountId>
+			+ LockableCurrency<Self::AccountId, Moment = Self::BlockNumber>;
+		/// Initial era length (in block count)
 		#[pallet::constant]
 		type EraDuration: Get<primitives::BlockNumber>;
-		/// Yearly nominator returns in % APY
+		/// Initial yearly nominator returns in % APY
 		#[pallet::constant]
 		type NominatorAPY: Get<Perbill>;
-		/// Yearly inflation rate to pay for council rewards
+		/// Initial yearly inflation rate to pay for council rewards
 		#[pallet::constant]
 		type CouncilInflation: Get<Perbill>;
 		/// How much funds need to be reserved for active candidacy
 		#[pallet::constant]
 		type CandidacyDeposit: Get<BalanceOf<Self>>;
-		/// Minimum amount of currency needed to create a commitment
+		/// Initial minimum amount of currency needed to create a commitment
 		#[pallet::constant]
 		type MinLockAmount: Get<BalanceOf<Self>>;
-		/// Total amount of currency that can be locked
+		/// Initial total amount of currency that can be locked
 		#[pallet::constant]
 		type TotalLockedCap: Get<BalanceOf<Self>>;
 		/// How many tech council candidates can apply at once.
 		#[pallet::constant]
 		type MaxCandidates: Get<u32>;
+		/// How many commitments can exist at once, bounding the accounts voting in
+		/// the election.
+		#[pallet::constant]
+		type MaxCommitments: Get<u32>;
 		/// How many tech council members are we voting in.
 		#[pallet::constant]
 		type MaxMembers: Get<u32>;
+		/// How many runners-up are kept to fill vacated seats.
+		#[pallet::constant]
+		type MaxRunnersUp: Get<u32>;
+		/// Share of the candidacy deposit lost when resigning from the council
+		#[pallet::constant]
+		type ResignationPenalty: Get<Perbill>;
+		/// Receiver of the resignation penalties
+		type ResignationPenaltyHandler: OnUnbalanced<NegativeImbalanceOf<Self>>;
+		/// Share of the committed amount lost when unbonding early with the
+		/// longest unbonding period still ahead. Shorter remaining periods pay
+		/// proportionally less.
+		#[pallet::constant]
+		type EarlyUnbondPenalty: Get<Perbill>;
+		/// Receiver of the early unbonding penalties
+		type EarlyUnbondPenaltyHandler: OnUnbalanced<NegativeImbalanceOf<Self>>;
+		/// Origin allowed to slash council members
+		type SlashOrigin: EnsureOrigin<Self::Origin>;
+		/// Receiver of the council member slashes
+		type SlashHandler: OnUnbalanced<NegativeImbalanceOf<Self>>;
+		/// Account the council rewards are paid from
+		type CouncilRewardSource: Get<Self::AccountId>;
+		/// How many past eras voter rewards can be claimed for.
+		#[pallet::constant]
+		type HistoryDepth: Get<EraIndex>;
+		/// How many candidates a commitment can approve.
+		#[pallet::constant]
+		type MaxVotes: Get<u32>;
+		/// Convert voting weights to election votes and back.
+		type CurrencyToVote: CurrencyToVote<BalanceOf<Self>>;
 		/// The receiver of the signal for when the membership has changed.
 		type MembershipChanged: ChangeMembers<Self::AccountId>;
+		/// Origin allowed to change the PoC parameters
+		type ParametersOrigin: EnsureOrigin<Self::Origin>;
+		/// Notified when commitments of an account stop voting or shrink
+		type OnVotesRemoved: OnVotesRemoved<Self::AccountId>;
 	}
 
 	#[pallet::error]
@@ -148,10 +348,14 @@ pub mod module {
 		AlreadyCandidate,
 		/// Candidate not found
 		NotCandidate,
-		/// Candidate is a member and cannot withdraw candidacy
+		/// Candidate is a member and cannot withdraw candidacy, only resign
 		CannotLeave,
+		/// Account is not a council member
+		NotMember,
 		/// Already have maximum allowed number of candidates
 		MaxCandidatesReached,
+		/// Already have maximum allowed number of commitments
+		MaxCommitmentsReached,
 		/// Account already has an active commitment
 		AlreadyCommitted,
 		/// Cannot operate on a non existing commitment
@@ -160,10 +364,27 @@ pub mod module {
 		NotCommitted,
 		/// Funds are still locked and cannot be withdrawn
 		CannotWithdrawLocked,
+		/// The unbonding period is over, the commitment can be withdrawn
+		AlreadyUnbonded,
 		/// Bonded amount is too small
 		InsufficientAmount,
 		/// The PoC system already has maximum amount committed
 		OverSubscribed,
+		/// Not enough free and unlocked funds to commit
+		InsufficientBalance,
+		/// The eras are not completed or no longer in the reward history
+		InvalidEraRange,
+		/// There are no unclaimed rewards in the eras
+		NoRewards,
+		/// A commitment needs to approve at least one candidate
+		NoVotes,
+		/// Approved more than `MaxVotes` candidates
+		TooManyVotes,
+		/// Era duration, minimum lock amount and voting multipliers cannot be zero,
+		/// and the total locked cap must exceed the minimum lock amount
+		InvalidParameters,
+		/// There are more commitments than the given commitment count
+		InvalidWitness,
 	}
 
 	#[pallet::event]
@@ -175,19 +396,35 @@ pub mod module {
 		/// Stop candidacy
 		CandidateRemoved(T::AccountId),
 		/// Created a new committment
-		Committed(T::AccountId, BalanceOf<T>),
+		Committed(T::AccountId, CommitmentId, BalanceOf<T>),
 		/// Add more funds to existing commitment
-		FundsAdded(T::AccountId, BalanceOf<T>),
+		FundsAdded(T::AccountId, CommitmentId, BalanceOf<T>),
 		/// The user has started the unbonding process
-		UnbondingStarted(T::AccountId, BalanceOf<T>),
+		UnbondingStarted(T::AccountId, CommitmentId, BalanceOf<T>),
 		/// Bond has been withdrawn
-		BondWithdrawn(T::AccountId, BalanceOf<T>),
-		/// Voter,Candidate,VotingPower
-		Voted(T::AccountId, T::AccountId, BalanceOf<T>),
+		BondWithdrawn(T::AccountId, CommitmentId, BalanceOf<T>),
+		/// Voter,Commitment,Released amount,Penalty
+		UnbondedEarly(T::AccountId, CommitmentId, BalanceOf<T>, BalanceOf<T>),
+		/// Voter,Commitment,Candidates,VotingPower
+		Voted(T::AccountId, CommitmentId, Vec<T::AccountId>, BalanceOf<T>),
 		/// Voter,Reward
 		VoterRewarded(EraIndex, T::AccountId, BalanceOf<T>),
-		/// Era, Winner,Weight
+		/// Voter,Commitment,Reward added to the commitment
+		RewardsCompounded(T::AccountId, CommitmentId, BalanceOf<T>),
+		/// Era,Rewards left unclaimed when the era left the reward history
+		UnclaimedRewardsExpired(EraIndex, BalanceOf<T>),
+		/// Era,Winner,Backing stake
 		Elected(EraIndex, T::AccountId, BalanceOf<T>),
+		/// Era,Member,Reward the reward source could not pay
+		CouncilRewardUnpaid(EraIndex, T::AccountId, BalanceOf<T>),
+		/// Member,Deposit lost
+		MemberResigned(T::AccountId, BalanceOf<T>),
+		/// Runner-up taking a vacated seat
+		RunnerUpPromoted(T::AccountId),
+		/// Member,Slashed deposit,Slashed backing
+		MemberSlashed(T::AccountId, BalanceOf<T>, BalanceOf<T>),
+		/// New parameters apply from the era
+		ParametersScheduled(EraIndex),
 	}
 
 	#[pallet::type_value]
@@ -198,6 +435,49 @@ pub mod module {
 		}
 	}
 
+	#[pallet::type_value]
+	pub fn DefaultParameters<T: Config>() -> PocParametersOf<T> {
+		PocParameters {
+			era_duration: T::EraDuration::get(),
+			nominator_apy: T::NominatorAPY::get(),
+			council_inflation: T::CouncilInflation::get(),
+			min_lock_amount: T::MinLockAmount::get(),
+			total_locked_cap: T::TotalLockedCap::get(),
+			one_month: LockTerms { unbonding_period: 30 * primitives::time::DAYS, voting_multiplier: 1 },
+			one_year: LockTerms { unbonding_period: 365 * primitives::time::DAYS, voting_multiplier: 10 },
+			ten_years: LockTerms { unbonding_period: 3650 * primitives::time::DAYS, voting_multiplier: 100 },
+		}
+	}
+
+	/// Slashes of council members for misconduct, oldest first.
+	#[pallet::storage]
+	#[pallet::getter(fn slashing_history)]
+	pub type SlashingHistory<T: Config> = StorageMap<_,
+		Blake2_128Concat, T::AccountId, Vec<SlashRecordOf<T>>,
+		ValueQuery>;
+
+	/// Parameters of the current era.
+	#[pallet::storage]
+	#[pallet::getter(fn parameters)]
+	pub type Parameters<T: Config> = StorageValue<_,
+		PocParametersOf<T>,
+		ValueQuery,
+		DefaultParameters<T>>;
+
+	/// Parameters taking effect from the next era.
+	#[pallet::storage]
+	#[pallet::getter(fn pending_parameters)]
+	pub type PendingParameters<T: Config> = StorageValue<_,
+		PocParametersOf<T>,
+		OptionQuery>;
+
+	/// Terms of the completed eras with voter rewards, kept for the reward history.
+	#[pallet::storage]
+	#[pallet::getter(fn era_reward_terms)]
+	pub type EraRewardTerms<T: Config> = StorageMap<_,
+		Twox64Concat, EraIndex, RewardTerms,
+		OptionQuery>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn current_era)]
 	pub(super) type CurrentEra<T: Config> = StorageValue<_,
@@ -205,17 +485,74 @@ pub mod module {
 		ValueQuery,
 		FirstEra<T>>;
 
+	/// Voter rewards claimed per era and commitment.
 	#[pallet::storage]
 	#[pallet::getter(fn voter_rewards)]
 	pub(crate) type VoterRewards<T: Config> = StorageDoubleMap<_,
 		Blake2_128Concat, EraIndex,
-		Blake2_128Concat, T::AccountId, BalanceOf<T>,
+		Blake2_128Concat, (T::AccountId, CommitmentId), BalanceOf<T>,
 		ValueQuery>;
 
 	#[pallet::storage]
 	#[pallet::getter(fn commitments)]
-	pub(crate) type Commitments<T: Config> = StorageMap<_,
-		Blake2_128Concat, T::AccountId, CommitmentOf<T>,
+	pub(crate) type Commitments<T: Config> = StorageDoubleMap<_,
+		Blake2_128Concat, T::AccountId,
+		Twox64Concat, CommitmentId, CommitmentOf<T>,
+		ValueQuery>;
+
+	/// Voter rewards of a completed era which have not been claimed yet.
+	#[pallet::storage]
+	#[pallet::getter(fn unclaimed_rewards)]
+	pub type UnclaimedRewards<T: Config> = StorageMap<_,
+		Twox64Concat, EraIndex, BalanceOf<T>,
+		ValueQuery>;
+
+	/// Committed funds earning voter rewards in the current era.
+	#[pallet::storage]
+	#[pallet::getter(fn rewardable_amount)]
+	pub type RewardableAmount<T: Config> = StorageValue<_,
+		BalanceOf<T>, ValueQuery>;
+
+	/// Committed funds which start earning voter rewards in the next era.
+	#[pallet::storage]
+	#[pallet::getter(fn pending_rewardable_amount)]
+	pub type PendingRewardableAmount<T: Config> = StorageValue<_,
+		BalanceOf<T>, ValueQuery>;
+
+	/// The id of the next commitment of an account.
+	#[pallet::storage]
+	#[pallet::getter(fn next_commitment_id)]
+	pub type NextCommitmentId<T: Config> = StorageMap<_,
+		Blake2_128Concat, T::AccountId, CommitmentId,
+		ValueQuery>;
+
+	/// Funds locked by an account across all its commitments.
+	#[pallet::storage]
+	#[pallet::getter(fn committed_balance)]
+	pub type CommittedBalance<T: Config> = StorageMap<_,
+		Blake2_128Concat, T::AccountId, BalanceOf<T>,
+		ValueQuery>;
+
+	/// Running tally of the committed amounts approving each account, kept
+	/// for non-candidates as well so their votes count once they apply.
+	#[pallet::storage]
+	pub type Votes<T: Config> = StorageMap<_,
+		Blake2_128Concat, T::AccountId, VotingAmountsOf<T>,
+		ValueQuery>;
+
+	/// Running tally of the committed amounts voting of each account, so that
+	/// its voting weight is known without iterating its commitments.
+	#[pallet::storage]
+	pub type AccountVotes<T: Config> = StorageMap<_,
+		Blake2_128Concat, T::AccountId, VotingAmountsOf<T>,
+		ValueQuery>;
+
+	/// Candidates approved by the voting commitments of each account, sorted,
+	/// with the number of its commitments approving them. Along with
+	/// `AccountVotes` it makes each account a single voter in the election.
+	#[pallet::storage]
+	pub type VoterApprovals<T: Config> = StorageMap<_,
+		Blake2_128Concat, T::AccountId, Vec<(T::AccountId, u32)>,
 		ValueQuery>;
 
 	#[pallet::storage]
@@ -224,6 +561,13 @@ pub mod module {
 		Vec<T::AccountId>,
 		ValueQuery>;
 
+	/// Candidates elected after the members, in order of election.
+	#[pallet::storage]
+	#[pallet::getter(fn runners_up)]
+	pub type RunnersUp<T: Config> = StorageValue<_,
+		Vec<T::AccountId>,
+		ValueQuery>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn candidates)]
 	pub type Candidates<T: Config> = StorageMap<_,
@@ -240,6 +584,12 @@ pub mod module {
 		0
 	}
 
+	/// Number of commitments, at most `MaxCommitments`.
+	#[pallet::storage]
+	#[pallet::getter(fn n_commitments)]
+	pub type CommitmentsCount<T: Config> = StorageValue<_,
+		u32, ValueQuery>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn locked_amount)]
 	pub type LockedAmount<T: Config> = StorageValue<_,
@@ -252,13 +602,15 @@ pub mod module {
 
 
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(PhantomData<T>);
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
 		fn on_initialize(n: T::BlockNumber) -> Weight {
 			let current_era = <CurrentEra<T>>::get();
-			let era_duration: T::BlockNumber = T::BlockNumber::from(T::EraDuration::get());
+			let parameters = <Parameters<T>>::get();
+			let era_duration: T::BlockNumber = T::BlockNumber::from(parameters.era_duration);
 
 			// baseline weight for execution without era change
 			let mut weight: Weight = T::WeightInfo::on_initialize_empty();
@@ -269,35 +621,45 @@ pub mod module {
 				let new_era = Era{index: new_era_index, start: n};
 				<CurrentEra<T>>::set(new_era);
 
-				// clear old voter rewards (to save space)
-				<VoterRewards<T>>::remove_prefix(&current_era.index, None);
-
-				// set winners on new era
-				let mut counter: BTreeMap<T::AccountId, BalanceOf<T>> = BTreeMap::new();
-				let mut commitment_count: u32 = 0;
-				for (_, c) in <Commitments<T>>::iter() {
-					// check if the candidate is running
-					if !<Candidates<T>>::contains_key(&c.candidate) { continue; }
-					// accumulate the votes by appropriate voting power
-					if counter.contains_key(&c.candidate) {
-						let acc_w = *counter.get(&c.candidate).unwrap();
-						counter.insert(c.candidate.clone(), Self::voting_weight(&c).saturating_add(acc_w));
-					} else {
-						counter.insert(c.candidate.clone(), Self::voting_weight(&c));
+				// close the voter rewards of the completed era
+				let terms = RewardTerms {
+					era_duration: parameters.era_duration,
+					nominator_apy: parameters.nominator_apy,
+				};
+				let era_rewards = Self::era_voter_rewards_of(<RewardableAmount<T>>::get(), &terms);
+				if !era_rewards.is_zero() {
+					<UnclaimedRewards<T>>::insert(current_era.index, era_rewards);
+					<EraRewardTerms<T>>::insert(current_era.index, terms);
+				}
+				let council_rewards = Self::council_rewards_of(&parameters);
+				let pending = <PendingRewardableAmount<T>>::take();
+				<RewardableAmount<T>>::mutate(|amount| *amount = amount.saturating_add(pending));
+
+				// expire the era leaving the reward history (to save space)
+				if let Some(expired_era) = new_era_index.checked_sub(T::HistoryDepth::get().saturating_add(1)) {
+					<VoterRewards<T>>::remove_prefix(&expired_era, None);
+					<EraRewardTerms<T>>::remove(expired_era);
+					let unclaimed = <UnclaimedRewards<T>>::take(expired_era);
+					if !unclaimed.is_zero() {
+						Self::deposit_event(Event::UnclaimedRewardsExpired(expired_era, unclaimed));
 					}
-					// used for weight calc
-					commitment_count += 1;
 				}
-				let mut sorted = Vec::from_iter(counter);
-				sorted.sort_by(|&(_, a), &(_, b)| b.cmp(&a));
 
+				// scheduled parameters apply from the new era, the tallies follow
+				// the new voting multipliers without a recount
+				if let Some(new_parameters) = <PendingParameters<T>>::take() {
+					<Parameters<T>>::put(new_parameters);
+				}
+
+				// set winners on new era
+				let (elected, runners_up, candidate_count, voter_count) = Self::elect();
 				let mut winners: Vec<T::AccountId> = Vec::new();
-				for (candidate, weight) in sorted.iter().take(T::MaxMembers::get() as usize) {
+				for (candidate, backing) in elected {
 					winners.push(candidate.clone());
 					Self::deposit_event(Event::Elected(
 							new_era_index,
-							candidate.clone(),
-							*weight
+							candidate,
+							backing
 					));
 				}
 				// pallet-collective expects sorted list
@@ -308,24 +670,65 @@ pub mod module {
 					let old_members = Members::<T>::get();
 					T::MembershipChanged::set_members_sorted(&winners[..], &old_members);
 					Members::<T>::put(winners.clone());
+					<RunnersUp<T>>::put(runners_up);
 
 					// distribute winners rewards
 					let zero = BalanceOf::<T>::from(0 as u32);
-					let rewards = Self::era_council_rewards();
-					let reward = rewards.checked_div(&BalanceOf::<T>::from(winners.len() as u32)).unwrap_or(zero);
+					let reward = council_rewards.checked_div(&BalanceOf::<T>::from(winners.len() as u32)).unwrap_or(zero);
 					if reward > zero {
+						let source = T::CouncilRewardSource::get();
 						for winner in winners.iter() {
-							// ignore failed cases
-							T::Currency::deposit_into_existing(winner, reward).ok();
+							let paid = T::Currency::transfer(&source, winner, reward, ExistenceRequirement::KeepAlive);
+							if paid.is_err() {
+								Self::deposit_event(Event::CouncilRewardUnpaid(new_era_index, winner.clone(), reward));
+							}
 						}
 					}
 				}
 
 				// accumulate the worst-case weights
-				weight = T::WeightInfo::on_initialize_era(commitment_count);
+				weight = T::WeightInfo::on_initialize_era(candidate_count, voter_count);
+			}
+			weight
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			let on_chain_version = StorageVersion::get::<Pallet<T>>();
+			let mut weight: Weight = T::DbWeight::get().reads(1);
+			if on_chain_version < 1 {
+				weight = weight.saturating_add(migrations::migrate_to_v1::<T>());
+			}
+			if on_chain_version < 2 {
+				weight = weight.saturating_add(migrations::migrate_to_v2::<T>());
+			}
+			if on_chain_version < 3 {
+				weight = weight.saturating_add(migrations::migrate_to_v3::<T>());
+			}
+			if on_chain_version < 4 {
+				weight = weight.saturating_add(migrations::migrate_to_v4::<T>());
+			}
+			if on_chain_version < 5 {
+				weight = weight.saturating_add(migrations::migrate_to_v5::<T>());
+			}
+			if on_chain_version < 6 {
+				weight = weight.saturating_add(migrations::migrate_to_v6::<T>());
+			}
+			if on_chain_version < 7 {
+				weight = weight.saturating_add(migrations::migrate_to_v7::<T>());
+			}
+			if on_chain_version < 8 {
+				weight = weight.saturating_add(migrations::migrate_to_v8::<T>());
+			}
+			if on_chain_version < 9 {
+				weight = weight.saturating_add(migrations::migrate_to_v9::<T>());
 			}
 			weight
 		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			Self::do_try_state()
+		}
 	}
 
 	#[pallet::call]
@@ -357,139 +760,214 @@ pub mod module {
 			let deposit = <Candidates<T>>::get(&origin);
 			T::Currency::unreserve(&origin, deposit);
 
+			// the vote tally is kept, it counts again if the account re-applies
 			<Candidates<T>>::remove(&origin);
 			<CandidatesCount<T>>::set(<CandidatesCount<T>>::get().saturating_sub(1));
+			<RunnersUp<T>>::mutate(|runners_up| runners_up.retain(|runner_up| *runner_up != origin));
 
 			Self::deposit_event(Event::CandidateRemoved(origin));
 			Ok(().into())
 		}
 
-		#[pallet::weight(T::WeightInfo::commit())]
+		/// Resign from the council and withdraw candidacy, losing
+		/// `ResignationPenalty` of the candidacy deposit. The top runner-up takes
+		/// the vacated seat.
+		#[pallet::weight(T::WeightInfo::resign())]
+		#[transactional]
+		pub fn resign(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			let origin = ensure_signed(origin)?;
+
+			let (imbalance, penalty, promoted) = Self::remove_member(&origin, T::ResignationPenalty::get())?;
+			T::ResignationPenaltyHandler::on_unbalanced(imbalance);
+
+			Self::deposit_event(Event::MemberResigned(origin, penalty));
+			if let Some(runner_up) = promoted {
+				Self::deposit_event(Event::RunnerUpPromoted(runner_up));
+			}
+			Ok(().into())
+		}
+
+		/// Slash `fraction` of the candidacy deposit of a council member for
+		/// misconduct and remove them from the council. With `slash_backers`, every
+		/// commitment approving the member also loses `fraction` of its share
+		/// split between the approved candidates. `commitment_count` is an upper
+		/// bound of the number of commitments, needed only to slash the backers.
+		#[pallet::weight(T::WeightInfo::slash_member(if *slash_backers { *commitment_count } else { 0 }))]
+		#[transactional]
+		pub fn slash_member(
+			origin: OriginFor<T>,
+			who: T::AccountId,
+			fraction: Perbill,
+			slash_backers: bool,
+			#[pallet::compact] commitment_count: u32,
+		) -> DispatchResultWithPostInfo {
+			T::SlashOrigin::ensure_origin(origin)?;
+
+			let (mut imbalance, deposit_slashed, promoted) = Self::remove_member(&who, fraction)?;
+
+			let mut backers_slashed: BalanceOf<T> = Zero::zero();
+			let mut counted: u32 = 0;
+			if slash_backers {
+				let commitments: Vec<(T::AccountId, CommitmentId, CommitmentOf<T>)> = <Commitments<T>>::iter().collect();
+				counted = commitments.len() as u32;
+				ensure!(counted <= commitment_count, Error::<T>::InvalidWitness);
+				for (backer, id, commitment) in commitments {
+					if commitment.candidates.binary_search(&who).is_err() {
+						continue;
+					}
+					let share = fraction * commitment.amount / BalanceOf::<T>::from(commitment.candidates.len() as u32);
+					let (slashed, amount) = Self::slash_commitment(&backer, id, commitment, share)?;
+					imbalance.subsume(slashed);
+					backers_slashed = backers_slashed.saturating_add(amount);
+				}
+			}
+			T::SlashHandler::on_unbalanced(imbalance);
+
+			<SlashingHistory<T>>::append(&who, SlashRecord {
+				era: <CurrentEra<T>>::get().index,
+				fraction,
+				deposit_slashed,
+				backers_slashed,
+			});
+
+			Self::deposit_event(Event::MemberSlashed(who, deposit_slashed, backers_slashed));
+			if let Some(runner_up) = promoted {
+				Self::deposit_event(Event::RunnerUpPromoted(runner_up));
+			}
+			Ok(Some(T::WeightInfo::slash_member(counted)).into())
+		}
+
+		/// Commit funds for `duration`, approving up to `MaxVotes` candidates.
+		#[pallet::weight(T::WeightInfo::commit(candidates.len().min(T::MaxVotes::get() as usize) as u32))]
 		#[transactional]
 		pub fn commit(
 			origin: OriginFor<T>,
 			#[pallet::compact] amount: BalanceOf<T>,
 			duration: LockDuration,
-			candidate: T::AccountId,
+			candidates: Vec<T::AccountId>,
 		) -> DispatchResultWithPostInfo {
 			let origin = ensure_signed(origin)?;
-
-			ensure!(!<Commitments<T>>::contains_key(&origin), Error::<T>::AlreadyCommitted);
+			let candidates = Self::ensure_votes(candidates)?;
+			let n_commitments = <CommitmentsCount<T>>::get();
+			ensure!(n_commitments < T::MaxCommitments::get(), Error::<T>::MaxCommitmentsReached);
 
 			// impose a minimum bond size (to make election computation faster)
-			ensure!(amount >= T::MinLockAmount::get(), Error::<T>::InsufficientAmount);
+			let parameters = <Parameters<T>>::get();
+			ensure!(amount >= parameters.min_lock_amount, Error::<T>::InsufficientAmount);
 
 			// check if at total locking capacity
 			let locked_total = <LockedAmount<T>>::get().saturating_add(amount);
-			ensure!(locked_total < T::TotalLockedCap::get(), Error::<T>::OverSubscribed);
+			ensure!(locked_total < parameters.total_locked_cap, Error::<T>::OverSubscribed);
 
-			T::Currency::withdraw(
-				&origin, amount,
-				WithdrawReasons::RESERVE,
-				ExistenceRequirement::KeepAlive)?;
+			Self::lock_more(&origin, amount)?;
 
 			// increase total locked amt
 			<LockedAmount<T>>::set(locked_total);
 
 			// create a new commitment
-			<Commitments<T>>::insert(&origin, Commitment {
+			let id = <NextCommitmentId<T>>::get(&origin);
+			<NextCommitmentId<T>>::insert(&origin, id.checked_add(1).ok_or("commitment id overflow")?);
+			let mut commitment = Commitment {
 				duration,
 				amount,
-				candidate,
+				candidates,
 				..Default::default()
-			});
-			Self::deposit_event(Event::Committed(origin, amount));
+			};
+			Self::start_earning(&mut commitment);
+			Self::add_votes(&origin, &commitment);
+			<Commitments<T>>::insert(&origin, id, commitment);
+			<CommitmentsCount<T>>::set(n_commitments.saturating_add(1));
+			Self::deposit_event(Event::Committed(origin, id, amount));
 			Ok(().into())
 		}
 
 
-		#[pallet::weight(T::WeightInfo::add_funds())]
+		/// Add funds to a commitment, re-committing it if unbonding. Outstanding
+		/// voter rewards are paid out and the commitment earns rewards on the new
+		/// amount from the next era.
+		#[pallet::weight(T::WeightInfo::add_funds().saturating_add(T::WeightInfo::claim_rewards(T::HistoryDepth::get())))]
 		#[transactional]
 		pub fn add_funds(
 			origin: OriginFor<T>,
+			id: CommitmentId,
 			#[pallet::compact] amount: BalanceOf<T>) -> DispatchResultWithPostInfo {
 			let origin = ensure_signed(origin)?;
 
-			ensure!(<Commitments<T>>::contains_key(&origin), Error::<T>::CommitmentNotFound);
-			let mut commitment = <Commitments<T>>::get(&origin);
+			ensure!(<Commitments<T>>::contains_key(&origin, id), Error::<T>::CommitmentNotFound);
+			let mut commitment = <Commitments<T>>::get(&origin, id);
 
 			ensure!(amount >= Zero::zero(), Error::<T>::InsufficientAmount);
 
 			// check if at total locking capacity
 			let locked_total = <LockedAmount<T>>::get().saturating_add(amount);
-			ensure!(locked_total < T::TotalLockedCap::get(), Error::<T>::OverSubscribed);
+			ensure!(locked_total < <Parameters<T>>::get().total_locked_cap, Error::<T>::OverSubscribed);
 
-			T::Currency::withdraw(
-				&origin, amount,
-				WithdrawReasons::RESERVE,
-				ExistenceRequirement::KeepAlive)?;
+			Self::settle_rewards(&origin, id, &commitment)?;
+			Self::stop_earning(&mut commitment);
+			Self::remove_votes(&origin, &commitment);
 			commitment.amount = commitment.amount.checked_add(&amount).ok_or("currency overflow")?;
+			Self::lock_more(&origin, amount)?;
 
 			// increase total locked amt
 			<LockedAmount<T>>::set(locked_total);
 
 			// always re-commit
 			commitment.state = LockState::Committed;
+			Self::start_earning(&mut commitment);
+			Self::add_votes(&origin, &commitment);
 
 			// save the commitment
-			<Commitments<T>>::insert(&origin, commitment);
+			<Commitments<T>>::insert(&origin, id, commitment);
 
-			Self::deposit_event(Event::FundsAdded(origin, amount));
+			Self::deposit_event(Event::FundsAdded(origin, id, amount));
 			Ok(().into())
 		}
 
 
 		#[pallet::weight(T::WeightInfo::unbond())]
-		pub fn unbond(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+		pub fn unbond(origin: OriginFor<T>, id: CommitmentId) -> DispatchResultWithPostInfo {
 			let origin = ensure_signed(origin)?;
 
-			ensure!(<Commitments<T>>::contains_key(&origin), Error::<T>::CommitmentNotFound);
-			let mut commitment = <Commitments<T>>::get(&origin);
+			ensure!(<Commitments<T>>::contains_key(&origin, id), Error::<T>::CommitmentNotFound);
+			let mut commitment = <Commitments<T>>::get(&origin, id);
 			ensure!(commitment.state == LockState::Committed, Error::<T>::NotCommitted);
 
-			// record the unbonding block number
+			// unbonding funds have no voting power and earn no rewards
+			Self::remove_votes(&origin, &commitment);
+			Self::stop_earning(&mut commitment);
+
+			// record the end of the unbonding period, later parameter changes do not move it
 			let current_block: T::BlockNumber = frame_system::Pallet::<T>::block_number();
-			commitment.state = LockState::Unbonding(current_block);
+			let unbonding_period: T::BlockNumber =
+				<Parameters<T>>::get().lock_terms(&commitment.duration).unbonding_period.into();
+			commitment.state = LockState::Unbonding(current_block + unbonding_period);
 
-			<Commitments<T>>::insert(&origin, commitment.clone());
-			Self::deposit_event(Event::UnbondingStarted(origin, commitment.amount));
+			<Commitments<T>>::insert(&origin, id, commitment.clone());
+			T::OnVotesRemoved::on_votes_removed(&origin);
+			Self::deposit_event(Event::UnbondingStarted(origin, id, commitment.amount));
 			Ok(().into())
 		}
 
 
-		#[pallet::weight(T::WeightInfo::withdraw())]
+		/// Withdraw an unbonded commitment, paying out its outstanding voter rewards.
+		#[pallet::weight(T::WeightInfo::withdraw().saturating_add(T::WeightInfo::claim_rewards(T::HistoryDepth::get())))]
 		#[transactional]
-		pub fn withdraw(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+		pub fn withdraw(origin: OriginFor<T>, id: CommitmentId) -> DispatchResultWithPostInfo {
 			let origin = ensure_signed(origin)?;
 
-			ensure!(<Commitments<T>>::contains_key(&origin), Error::<T>::CommitmentNotFound);
-			let commitment = <Commitments<T>>::get(&origin);
+			ensure!(<Commitments<T>>::contains_key(&origin, id), Error::<T>::CommitmentNotFound);
+			let commitment = <Commitments<T>>::get(&origin, id);
 			ensure!(commitment.state != LockState::Committed, Error::<T>::AlreadyCommitted);
 
 			// check if Unbonding period is over
 			// WARN: if block times are altered, this calculation will become invalid
-			if let LockState::Unbonding(start_block) = commitment.state {
-
-				let lock_period = match commitment.duration {
-					LockDuration::OneMonth => 30,
-					LockDuration::OneYear  => 365,
-					LockDuration::TenYears => 3650,
-				} * primitives::time::DAYS;
-				let lock_period: T::BlockNumber = lock_period.into();
+			if let Some(end_block) = Self::unbonding_end(&commitment) {
 				let current_block: T::BlockNumber = frame_system::Pallet::<T>::block_number();
 
-				if start_block + lock_period <= current_block {
-					// credit the user his funds
-					T::Currency::deposit_into_existing(&origin, commitment.amount)?;
-
-					// delete the commitment
-					<Commitments<T>>::remove(&origin);
-
-					// decrease the total locked amt after currency is released
-					let locked_total = <LockedAmount<T>>::get().saturating_sub(commitment.amount);
-					<LockedAmount<T>>::set(locked_total);
-
-					Self::deposit_event(Event::BondWithdrawn(origin, commitment.amount));
+				if end_block <= current_block {
+					Self::settle_rewards(&origin, id, &commitment)?;
+					Self::release(&origin, id, commitment.amount);
+					Self::deposit_event(Event::BondWithdrawn(origin, id, commitment.amount));
 					return Ok(().into());
 				}
 			}
@@ -497,33 +975,121 @@ pub mod module {
 		}
 
 
-		#[pallet::weight(T::WeightInfo::vote_candidate())]
+		/// Release a commitment before its unbonding period is over, losing a
+		/// penalty that shrinks with the time served and the unbonding time
+		/// left. The commitment stops voting and forfeits its unclaimed voter
+		/// rewards.
+		#[pallet::weight(T::WeightInfo::unbond_early())]
 		#[transactional]
-		pub fn vote_candidate(
+		pub fn unbond_early(origin: OriginFor<T>, id: CommitmentId) -> DispatchResultWithPostInfo {
+			let origin = ensure_signed(origin)?;
+
+			ensure!(<Commitments<T>>::contains_key(&origin, id), Error::<T>::CommitmentNotFound);
+			let mut commitment = <Commitments<T>>::get(&origin, id);
+			let penalty = Self::early_unbond_penalty(&commitment).ok_or(Error::<T>::AlreadyUnbonded)?;
+
+			Self::remove_votes(&origin, &commitment);
+			Self::stop_earning(&mut commitment);
+			Self::release(&origin, id, commitment.amount);
+
+			// the penalty is taken from the released funds
+			let (imbalance, _) = T::Currency::slash(&origin, penalty);
+			T::EarlyUnbondPenaltyHandler::on_unbalanced(imbalance);
+			T::OnVotesRemoved::on_votes_removed(&origin);
+
+			Self::deposit_event(Event::UnbondedEarly(origin, id, commitment.amount.saturating_sub(penalty), penalty));
+			Ok(().into())
+		}
+
+
+		/// Replace the candidates approved by a commitment, up to `MaxVotes`.
+		#[pallet::weight(T::WeightInfo::vote_candidates(candidates.len().min(T::MaxVotes::get() as usize) as u32))]
+		#[transactional]
+		pub fn vote_candidates(
 			origin: OriginFor<T>,
-			candidate: T::AccountId,
+			id: CommitmentId,
+			candidates: Vec<T::AccountId>,
 		) -> DispatchResultWithPostInfo {
 			let origin = ensure_signed(origin)?;
+			let candidates = Self::ensure_votes(candidates)?;
 
-			ensure!(<Commitments<T>>::contains_key(&origin), Error::<T>::CommitmentNotFound);
-			let mut commitment = <Commitments<T>>::get(&origin);
+			ensure!(<Commitments<T>>::contains_key(&origin, id), Error::<T>::CommitmentNotFound);
+			let mut commitment = <Commitments<T>>::get(&origin, id);
 			ensure!(commitment.state == LockState::Committed, Error::<T>::NotCommitted);
 
-			if commitment.candidate != candidate {
-				commitment.candidate = candidate.clone();
-				<Commitments<T>>::insert(&origin, &commitment);
-				Self::deposit_event(Event::Voted(origin.clone(), candidate, Self::voting_weight(&commitment)));
+			if commitment.candidates != candidates {
+				Self::remove_votes(&origin, &commitment);
+				commitment.candidates = candidates.clone();
+				Self::add_votes(&origin, &commitment);
+				<Commitments<T>>::insert(&origin, id, &commitment);
+				Self::deposit_event(Event::Voted(origin, id, candidates, Self::voting_weight(&commitment)));
 			}
+			Ok(().into())
+		}
 
-			let era_reward = Self::era_voter_reward(&commitment);
-			if era_reward > Zero::zero() {
-				// check if nominator has been rewarded already for this era
-				let current_era = <CurrentEra<T>>::get();
-				if !<VoterRewards<T>>::contains_key(&current_era.index, &origin) {
-					<VoterRewards<T>>::insert(&current_era.index, &origin, &era_reward);
-					T::Currency::deposit_into_existing(&origin, era_reward)?;
-					Self::deposit_event(Event::VoterRewarded(current_era.index, origin, era_reward));
-				}
+		/// Schedule new PoC parameters, taking effect from the next era.
+		#[pallet::weight(T::WeightInfo::set_parameters())]
+		pub fn set_parameters(origin: OriginFor<T>, parameters: PocParametersOf<T>) -> DispatchResultWithPostInfo {
+			T::ParametersOrigin::ensure_origin(origin)?;
+			ensure!(
+				parameters.era_duration > 0
+					&& !parameters.min_lock_amount.is_zero()
+					&& parameters.one_month.voting_multiplier > 0
+					&& parameters.one_year.voting_multiplier > 0
+					&& parameters.ten_years.voting_multiplier > 0
+					&& parameters.total_locked_cap > parameters.min_lock_amount,
+				Error::<T>::InvalidParameters
+			);
+
+			<PendingParameters<T>>::put(parameters);
+			Self::deposit_event(Event::ParametersScheduled(<CurrentEra<T>>::get().index.saturating_add(1)));
+			Ok(().into())
+		}
+
+		/// Claim the voter rewards of a commitment for the completed eras in
+		/// `era_range` (inclusive), at most `HistoryDepth` eras in the past. With
+		/// `compound`, the rewards are added to the commitment instead, which pays
+		/// out its other outstanding rewards and earns on the new amount from the
+		/// next era.
+		#[pallet::weight(T::WeightInfo::claim_rewards(
+			era_range.1.saturating_sub(era_range.0).saturating_add(1).min(T::HistoryDepth::get())
+		))]
+		#[transactional]
+		pub fn claim_rewards(
+			origin: OriginFor<T>,
+			id: CommitmentId,
+			era_range: (EraIndex, EraIndex),
+			compound: bool,
+		) -> DispatchResultWithPostInfo {
+			let origin = ensure_signed(origin)?;
+
+			ensure!(<Commitments<T>>::contains_key(&origin, id), Error::<T>::CommitmentNotFound);
+			let mut commitment = <Commitments<T>>::get(&origin, id);
+
+			let (first_era, end_era) = Self::claimable_eras();
+			let (from, to) = era_range;
+			ensure!(first_era <= from && from <= to && to < end_era, Error::<T>::InvalidEraRange);
+
+			let reward = Self::do_claim_rewards(&origin, id, &commitment, from, to)?;
+			ensure!(!reward.is_zero(), Error::<T>::NoRewards);
+
+			if compound {
+				ensure!(commitment.state == LockState::Committed, Error::<T>::NotCommitted);
+
+				let locked_total = <LockedAmount<T>>::get().saturating_add(reward);
+				ensure!(locked_total < <Parameters<T>>::get().total_locked_cap, Error::<T>::OverSubscribed);
+
+				Self::settle_rewards(&origin, id, &commitment)?;
+				Self::stop_earning(&mut commitment);
+				Self::remove_votes(&origin, &commitment);
+				commitment.amount = commitment.amount.checked_add(&reward).ok_or("currency overflow")?;
+				Self::lock_more(&origin, reward)?;
+				<LockedAmount<T>>::set(locked_total);
+				Self::start_earning(&mut commitment);
+				Self::add_votes(&origin, &commitment);
+				<Commitments<T>>::insert(&origin, id, commitment);
+
+				Self::deposit_event(Event::RewardsCompounded(origin, id, reward));
 			}
 			Ok(().into())
 		}
@@ -531,48 +1097,562 @@ pub mod module {
 }
 
 impl<T: Config> Pallet<T> {
+	/// Lock `amount` more of the free balance of `who` on top of the funds
+	/// already committed. The lock overlaps the other locks of `who`, so funds
+	/// held by vesting or voting can be committed too.
+	fn lock_more(who: &T::AccountId, amount: BalanceOf<T>) -> DispatchResult {
+		let committed = <CommittedBalance<T>>::get(who).checked_add(&amount).ok_or("currency overflow")?;
+		ensure!(
+			T::Currency::free_balance(who) >= committed,
+			Error::<T>::InsufficientBalance
+		);
+		T::Currency::set_lock(POC_LOCK_ID, who, committed, WithdrawReasons::all());
+		<CommittedBalance<T>>::insert(who, committed);
+		Ok(())
+	}
+
+	/// Release `amount` of the committed funds of `who`.
+	fn unlock(who: &T::AccountId, amount: BalanceOf<T>) {
+		let committed = <CommittedBalance<T>>::get(who).saturating_sub(amount);
+		if committed.is_zero() {
+			T::Currency::remove_lock(POC_LOCK_ID, who);
+			<CommittedBalance<T>>::remove(who);
+		} else {
+			T::Currency::set_lock(POC_LOCK_ID, who, committed, WithdrawReasons::all());
+			<CommittedBalance<T>>::insert(who, committed);
+		}
+	}
+
+	/// Remove `who` from the council and its candidacy, slashing `penalty` of
+	/// the candidacy deposit. The top runner-up takes the vacated seat.
+	fn remove_member(
+		who: &T::AccountId,
+		penalty: Perbill,
+	) -> Result<(NegativeImbalanceOf<T>, BalanceOf<T>, Option<T::AccountId>), DispatchError> {
+		let mut members = <Members<T>>::get();
+		let index = members.binary_search(who).map_err(|_| Error::<T>::NotMember)?;
+		members.remove(index);
+
+		let deposit = <Candidates<T>>::take(who);
+		let penalty = penalty * deposit;
+		let (imbalance, unslashed) = T::Currency::slash_reserved(who, penalty);
+		let penalty = penalty.saturating_sub(unslashed);
+		T::Currency::unreserve(who, deposit.saturating_sub(penalty));
+		<CandidatesCount<T>>::set(<CandidatesCount<T>>::get().saturating_sub(1));
+
+		let mut promoted: Option<T::AccountId> = None;
+		let mut runners_up = <RunnersUp<T>>::get();
+		if !runners_up.is_empty() {
+			let runner_up = runners_up.remove(0);
+			let index = members.binary_search(&runner_up).unwrap_or_else(|index| index);
+			members.insert(index, runner_up.clone());
+			promoted = Some(runner_up);
+			<RunnersUp<T>>::put(runners_up);
+		}
+
+		let incoming: Vec<T::AccountId> = promoted.iter().cloned().collect();
+		T::MembershipChanged::change_members_sorted(&incoming, &[who.clone()], &members);
+		<Members<T>>::put(members);
+
+		Ok((imbalance, penalty, promoted))
+	}
+
+	/// Slash `amount` of the commitment `id` of `who`, paying out its
+	/// outstanding voter rewards first. Returns the slashed imbalance and amount.
+	fn slash_commitment(
+		who: &T::AccountId,
+		id: CommitmentId,
+		mut commitment: CommitmentOf<T>,
+		amount: BalanceOf<T>,
+	) -> Result<(NegativeImbalanceOf<T>, BalanceOf<T>), DispatchError> {
+		let amount = amount.min(commitment.amount);
+		Self::settle_rewards(who, id, &commitment)?;
+
+		let earning = commitment.state == LockState::Committed;
+		Self::remove_votes(who, &commitment);
+		Self::stop_earning(&mut commitment);
+		commitment.amount = commitment.amount.saturating_sub(amount);
+
+		if commitment.amount.is_zero() {
+			Self::release(who, id, amount);
+		} else {
+			// the reduced commitment keeps voting and earns again from the next era
+			if earning {
+				Self::start_earning(&mut commitment);
+			}
+			Self::add_votes(who, &commitment);
+			<Commitments<T>>::insert(who, id, commitment);
+
+			Self::unlock(who, amount);
+			<LockedAmount<T>>::mutate(|locked_total| *locked_total = locked_total.saturating_sub(amount));
+		}
+
+		let (imbalance, unslashed) = T::Currency::slash(who, amount);
+		T::OnVotesRemoved::on_votes_removed(who);
+		Ok((imbalance, amount.saturating_sub(unslashed)))
+	}
+
+	/// Unlock the funds of the commitment `id` of `who` and delete it.
+	fn release(who: &T::AccountId, id: CommitmentId, amount: BalanceOf<T>) {
+		Self::unlock(who, amount);
+		<Commitments<T>>::remove(who, id);
+		<CommitmentsCount<T>>::set(<CommitmentsCount<T>>::get().saturating_sub(1));
+
+		// decrease the total locked amt after currency is released
+		<LockedAmount<T>>::mutate(|locked_total| *locked_total = locked_total.saturating_sub(amount));
+	}
+
+	/// The eras voter rewards can currently be claimed for, as `[first, end)`.
+	pub fn claimable_eras() -> (EraIndex, EraIndex) {
+		let current_era = <CurrentEra<T>>::get().index;
+		(current_era.saturating_sub(T::HistoryDepth::get()), current_era)
+	}
+
+	/// Pay out the unclaimed voter rewards of the commitment `id` of `who` for
+	/// the eras `from..=to`, returning the total.
+	fn do_claim_rewards(
+		who: &T::AccountId,
+		id: CommitmentId,
+		commitment: &CommitmentOf<T>,
+		from: EraIndex,
+		to: EraIndex,
+	) -> Result<BalanceOf<T>, DispatchError> {
+		let mut total: BalanceOf<T> = Zero::zero();
+		let key = (who.clone(), id);
+		for era in Self::unclaimed_eras(who, id, commitment, from, to) {
+			let reward = Self::era_voter_reward(commitment, era);
+			<VoterRewards<T>>::insert(era, &key, reward);
+			<UnclaimedRewards<T>>::mutate_exists(era, |unclaimed| {
+				let remaining = unclaimed.unwrap_or_else(Zero::zero).saturating_sub(reward);
+				*unclaimed = if remaining.is_zero() { None } else { Some(remaining) };
+			});
+			total = total.saturating_add(reward);
+			Self::deposit_event(Event::VoterRewarded(era, who.clone(), reward));
+		}
+
+		if !total.is_zero() {
+			T::Currency::deposit_into_existing(who, total)?;
+		}
+		Ok(total)
+	}
+
+	/// The eras in `from..=to` the commitment `id` of `who` earned voter
+	/// rewards in without claiming them yet.
+	fn unclaimed_eras(
+		who: &T::AccountId,
+		id: CommitmentId,
+		commitment: &CommitmentOf<T>,
+		from: EraIndex,
+		to: EraIndex,
+	) -> Vec<EraIndex> {
+		if commitment.duration == LockDuration::OneMonth {
+			return Vec::new();
+		}
+		let key = (who.clone(), id);
+		let end = to.saturating_add(1).min(commitment.until.unwrap_or(EraIndex::max_value()));
+		(from.max(commitment.since)..end)
+			.filter(|era| !<VoterRewards<T>>::contains_key(era, &key))
+			.collect()
+	}
+
+	/// Pay out all the claimable voter rewards of a commitment, before its
+	/// reward period is restarted or it is removed.
+	fn settle_rewards(who: &T::AccountId, id: CommitmentId, commitment: &CommitmentOf<T>) -> DispatchResult {
+		let (first_era, end_era) = Self::claimable_eras();
+		if end_era > first_era {
+			Self::do_claim_rewards(who, id, commitment, first_era, end_era - 1)?;
+		}
+		Ok(())
+	}
+
+	/// Start earning voter rewards from the next era.
+	fn start_earning(commitment: &mut CommitmentOf<T>) {
+		commitment.since = <CurrentEra<T>>::get().index.saturating_add(1);
+		commitment.until = None;
+		if commitment.duration != LockDuration::OneMonth {
+			<PendingRewardableAmount<T>>::mutate(|amount| *amount = amount.saturating_add(commitment.amount));
+		}
+	}
+
+	/// Stop earning voter rewards from the current era on.
+	fn stop_earning(commitment: &mut CommitmentOf<T>) {
+		if commitment.until.is_some() {
+			return;
+		}
+		let current_era = <CurrentEra<T>>::get().index;
+		commitment.until = Some(current_era);
+		if commitment.duration != LockDuration::OneMonth {
+			if commitment.since > current_era {
+				<PendingRewardableAmount<T>>::mutate(|amount| *amount = amount.saturating_sub(commitment.amount));
+			} else {
+				<RewardableAmount<T>>::mutate(|amount| *amount = amount.saturating_sub(commitment.amount));
+			}
+		}
+	}
+
+	/// Sort and deduplicate approved candidates, of which there must be
+	/// between one and `MaxVotes`.
+	fn ensure_votes(mut candidates: Vec<T::AccountId>) -> Result<Vec<T::AccountId>, DispatchError> {
+		candidates.sort();
+		candidates.dedup();
+		ensure!(!candidates.is_empty(), Error::<T>::NoVotes);
+		ensure!(candidates.len() <= T::MaxVotes::get() as usize, Error::<T>::TooManyVotes);
+		Ok(candidates)
+	}
+
+	/// Add the committed amount of a commitment of `who` to its tally and to
+	/// the tallies of the candidates it approves, if it is voting.
+	pub(crate) fn add_votes(who: &T::AccountId, commitment: &CommitmentOf<T>) {
+		if commitment.state != LockState::Committed || commitment.amount.is_zero() {
+			return;
+		}
+		<AccountVotes<T>>::mutate(who, |votes| votes.add(&commitment.duration, commitment.amount));
+		<VoterApprovals<T>>::mutate(who, |approvals| Self::add_approvals(approvals, &commitment.candidates));
+		for candidate in commitment.candidates.iter() {
+			<Votes<T>>::mutate(candidate, |votes| votes.add(&commitment.duration, commitment.amount));
+		}
+	}
+
+	/// Remove the committed amount of a commitment of `who` from its tally and
+	/// from the tallies of the candidates it approves, if it is voting,
+	/// dropping empty tallies.
+	fn remove_votes(who: &T::AccountId, commitment: &CommitmentOf<T>) {
+		if commitment.state != LockState::Committed || commitment.amount.is_zero() {
+			return;
+		}
+		<AccountVotes<T>>::mutate_exists(who, |votes| Self::sub_votes(votes, commitment));
+		<VoterApprovals<T>>::mutate_exists(who, |approvals| {
+			let mut remaining = approvals.take().unwrap_or_default();
+			Self::sub_approvals(&mut remaining, &commitment.candidates);
+			*approvals = if remaining.is_empty() { None } else { Some(remaining) };
+		});
+		for candidate in commitment.candidates.iter() {
+			<Votes<T>>::mutate_exists(candidate, |votes| Self::sub_votes(votes, commitment));
+		}
+	}
+
+	/// Count one more commitment approving each of `candidates`.
+	pub(crate) fn add_approvals(approvals: &mut Vec<(T::AccountId, u32)>, candidates: &[T::AccountId]) {
+		for candidate in candidates.iter() {
+			match approvals.binary_search_by(|(approved, _)| approved.cmp(candidate)) {
+				Ok(index) => approvals[index].1 = approvals[index].1.saturating_add(1),
+				Err(index) => approvals.insert(index, (candidate.clone(), 1)),
+			}
+		}
+	}
+
+	/// Count one less commitment approving each of `candidates`, dropping the
+	/// candidates no commitment approves anymore.
+	fn sub_approvals(approvals: &mut Vec<(T::AccountId, u32)>, candidates: &[T::AccountId]) {
+		for candidate in candidates.iter() {
+			if let Ok(index) = approvals.binary_search_by(|(approved, _)| approved.cmp(candidate)) {
+				if approvals[index].1 > 1 {
+					approvals[index].1 -= 1;
+				} else {
+					approvals.remove(index);
+				}
+			}
+		}
+	}
+
+	/// Remove the committed amount of a commitment from a tally, dropping it if
+	/// empty.
+	fn sub_votes(votes: &mut Option<VotingAmountsOf<T>>, commitment: &CommitmentOf<T>) {
+		let mut remaining = votes.take().unwrap_or_default();
+		remaining.sub(&commitment.duration, commitment.amount);
+		*votes = if remaining.is_zero() { None } else { Some(remaining) };
+	}
+
+	/// Elect up to `MaxMembers` of the candidates with votes, followed by up to
+	/// `MaxRunnersUp` runners-up, by sequential Phragmén over the committed
+	/// voting weights. Each account votes once, with the weight of all its
+	/// voting commitments for all the candidates they approve, read from the
+	/// running tallies. Returns the members in order of election with their
+	/// backing stake and the runners-up, along with the number of candidates
+	/// and voters considered.
+	fn elect() -> (Vec<(T::AccountId, BalanceOf<T>)>, Vec<T::AccountId>, u32, u32) {
+		let mut candidate_count: u32 = 0;
+		let mut candidates: Vec<T::AccountId> = Vec::new();
+		for candidate in <Candidates<T>>::iter_keys() {
+			candidate_count += 1;
+			if !<Votes<T>>::get(&candidate).is_zero() {
+				candidates.push(candidate);
+			}
+		}
+		// ties are broken by account id
+		candidates.sort();
+
+		// scale the votes against the largest possible voting weight
+		let parameters = <Parameters<T>>::get();
+		let max_multiplier = parameters.max_voting_multiplier();
+		let issuance = T::Currency::total_issuance().saturating_mul(BalanceOf::<T>::from(max_multiplier));
+		let mut voter_count: u32 = 0;
+		let mut voters: Vec<(T::AccountId, VoteWeight, Vec<T::AccountId>)> = Vec::new();
+		for (who, approvals) in <VoterApprovals<T>>::iter() {
+			voter_count += 1;
+			let weight = <AccountVotes<T>>::get(&who).weight(&parameters);
+			if !weight.is_zero() {
+				let candidates = approvals.into_iter().map(|(candidate, _)| candidate).collect();
+				voters.push((who, T::CurrencyToVote::to_vote(weight, issuance), candidates));
+			}
+		}
+
+		// the members are kept if the election fails
+		let mut elected: Vec<(T::AccountId, BalanceOf<T>)> = match seq_phragmen::<T::AccountId, Perbill>(
+			T::MaxMembers::get().saturating_add(T::MaxRunnersUp::get()) as usize,
+			candidates,
+			voters,
+			None,
+		) {
+			Ok(ElectionResult { winners, .. }) => winners
+				.into_iter()
+				.filter(|(_, backing)| !backing.is_zero())
+				.map(|(who, backing)| (who, T::CurrencyToVote::to_currency(backing, issuance)))
+				.collect(),
+			Err(_) => Vec::new(),
+		};
+		let runners_up = elected
+			.split_off(elected.len().min(T::MaxMembers::get() as usize))
+			.into_iter()
+			.map(|(who, _)| who)
+			.collect();
+		(elected, runners_up, candidate_count, voter_count)
+	}
+
+	/// Check that the vote tallies, committed balances and rewardable amounts
+	/// match a full recount of the commitments, and that the runners-up can
+	/// take a seat.
+	#[cfg(any(feature = "try-runtime", test))]
+	pub fn do_try_state() -> Result<(), &'static str> {
+		use sp_std::collections::btree_map::BTreeMap;
+
+		let current_era = <CurrentEra<T>>::get().index;
+		let mut recount: BTreeMap<T::AccountId, VotingAmountsOf<T>> = BTreeMap::new();
+		let mut account_recount: BTreeMap<T::AccountId, VotingAmountsOf<T>> = BTreeMap::new();
+		let mut approvals_recount: BTreeMap<T::AccountId, Vec<(T::AccountId, u32)>> = BTreeMap::new();
+		let mut committed: BTreeMap<T::AccountId, BalanceOf<T>> = BTreeMap::new();
+		let mut rewardable: BalanceOf<T> = Zero::zero();
+		let mut pending_rewardable: BalanceOf<T> = Zero::zero();
+		let mut commitment_count: u32 = 0;
+		for (who, id, commitment) in <Commitments<T>>::iter() {
+			ensure!(id < <NextCommitmentId<T>>::get(&who), "commitment id was never issued");
+			commitment_count += 1;
+			if commitment.state == LockState::Committed && !commitment.amount.is_zero() {
+				account_recount.entry(who.clone()).or_default().add(&commitment.duration, commitment.amount);
+				Self::add_approvals(approvals_recount.entry(who.clone()).or_default(), &commitment.candidates);
+				for candidate in commitment.candidates.iter() {
+					recount.entry(candidate.clone()).or_default().add(&commitment.duration, commitment.amount);
+				}
+			}
+			if commitment.duration != LockDuration::OneMonth && commitment.until.is_none() {
+				if commitment.since > current_era {
+					pending_rewardable = pending_rewardable.saturating_add(commitment.amount);
+				} else {
+					rewardable = rewardable.saturating_add(commitment.amount);
+				}
+			}
+			let amount = committed.entry(who).or_insert_with(Zero::zero);
+			*amount = amount.saturating_add(commitment.amount);
+		}
+		ensure!(commitment_count == <CommitmentsCount<T>>::get(), "commitment count does not match the commitments");
+		ensure!(rewardable == <RewardableAmount<T>>::get(), "rewardable amount does not match the commitments");
+		ensure!(
+			pending_rewardable == <PendingRewardableAmount<T>>::get(),
+			"pending rewardable amount does not match the commitments"
+		);
+
+		let mut tally_count: usize = 0;
+		for (candidate, votes) in <Votes<T>>::iter() {
+			ensure!(recount.get(&candidate) == Some(&votes), "vote tally does not match the commitments");
+			tally_count += 1;
+		}
+		ensure!(tally_count == recount.len(), "vote tally is missing a voted account");
+
+		let mut voter_count: usize = 0;
+		for (who, votes) in <AccountVotes<T>>::iter() {
+			ensure!(account_recount.get(&who) == Some(&votes), "account votes do not match the commitments");
+			voter_count += 1;
+		}
+		ensure!(voter_count == account_recount.len(), "account votes are missing a voter");
+
+		let mut approver_count: usize = 0;
+		for (who, approvals) in <VoterApprovals<T>>::iter() {
+			ensure!(approvals_recount.get(&who) == Some(&approvals), "voter approvals do not match the commitments");
+			approver_count += 1;
+		}
+		ensure!(approver_count == approvals_recount.len(), "voter approvals are missing a voter");
+
+		let mut committer_count: usize = 0;
+		for (who, amount) in <CommittedBalance<T>>::iter() {
+			ensure!(committed.get(&who) == Some(&amount), "committed balance does not match the commitments");
+			committer_count += 1;
+		}
+		ensure!(committer_count == committed.len(), "committed balance is missing a committer");
+
+		let members = <Members<T>>::get();
+		for runner_up in <RunnersUp<T>>::get() {
+			ensure!(<Candidates<T>>::contains_key(&runner_up), "runner-up is not a candidate");
+			ensure!(members.binary_search(&runner_up).is_err(), "runner-up is a member");
+		}
+
+		Ok(())
+	}
+
+	/// Block from which an unbonding commitment can be withdrawn.
+	pub fn unbonding_end(commitment: &CommitmentOf<T>) -> Option<T::BlockNumber> {
+		if let LockState::Unbonding(end_block) = commitment.state {
+			return Some(end_block);
+		}
+		None
+	}
+
+	/// Penalty for releasing a commitment now, `EarlyUnbondPenalty` of the
+	/// amount scaled by the unbonding time left against the longest unbonding
+	/// period. A committed commitment has its unbonding period left, less the
+	/// eras it has served in full. `None` once the commitment can be withdrawn.
+	pub fn early_unbond_penalty(commitment: &CommitmentOf<T>) -> Option<BalanceOf<T>> {
+		let parameters = <Parameters<T>>::get();
+		let remaining: T::BlockNumber = match commitment.state {
+			LockState::Committed => {
+				let served_eras = <CurrentEra<T>>::get().index.saturating_sub(commitment.since);
+				let served = T::BlockNumber::from(parameters.era_duration).saturating_mul(served_eras.into());
+				let unbonding_period: T::BlockNumber =
+					parameters.lock_terms(&commitment.duration).unbonding_period.into();
+				unbonding_period.saturating_sub(served)
+			},
+			LockState::Unbonding(end_block) => {
+				let current_block: T::BlockNumber = frame_system::Pallet::<T>::block_number();
+				let remaining = end_block.saturating_sub(current_block);
+				if remaining.is_zero() {
+					return None;
+				}
+				remaining
+			},
+		};
+		let longest: T::BlockNumber = parameters.max_unbonding_period().into();
+		let share = Perbill::from_rational(remaining.min(longest), longest);
+		Some(share * (T::EarlyUnbondPenalty::get() * commitment.amount))
+	}
+
+	/// Candidates ranked by the voting weight approving them, ties broken by
+	/// account id.
+	pub fn candidate_rankings() -> Vec<(T::AccountId, BalanceOf<T>)> {
+		let parameters = <Parameters<T>>::get();
+		let mut rankings: Vec<(T::AccountId, BalanceOf<T>)> = <Candidates<T>>::iter_keys()
+			.map(|candidate| {
+				let votes = <Votes<T>>::get(&candidate).weight(&parameters);
+				(candidate, votes)
+			})
+			.collect();
+		rankings.sort_by(|(a, a_votes), (b, b_votes)| b_votes.cmp(a_votes).then_with(|| a.cmp(b)));
+		rankings
+	}
+
+	/// Voting weight approving `who` under the current voting multipliers.
+	pub fn votes<K: EncodeLike<T::AccountId>>(who: K) -> BalanceOf<T> {
+		<Votes<T>>::get(who).weight(&<Parameters<T>>::get())
+	}
+
+	/// The members the next era change would elect, with their backing stake.
+	pub fn projected_members() -> Vec<(T::AccountId, BalanceOf<T>)> {
+		Self::elect().0
+	}
+
+	/// Voting weight of all the commitments of `who`.
+	pub fn account_voting_weight(who: &T::AccountId) -> BalanceOf<T> {
+		<AccountVotes<T>>::get(who).weight(&<Parameters<T>>::get())
+	}
+
+	/// Voting weight of `amount` of the currency of `who`: the part of it
+	/// covered by its commitments weighs as much as they do per unit, the
+	/// rest weighs one per unit.
+	pub fn convicted_weight(who: &T::AccountId, amount: BalanceOf<T>) -> BalanceOf<T> {
+		let votes = <AccountVotes<T>>::get(who);
+		let committed = votes.total();
+		if committed.is_zero() {
+			return amount;
+		}
+		let covered = amount.min(committed);
+		let weight = Perbill::from_rational(covered, committed) * votes.weight(&<Parameters<T>>::get());
+		amount.saturating_sub(covered).saturating_add(weight)
+	}
+
+	/// Voter rewards `who` can currently claim across its commitments.
+	pub fn pending_rewards(who: &T::AccountId) -> BalanceOf<T> {
+		let (first_era, end_era) = Self::claimable_eras();
+		if end_era <= first_era {
+			return Zero::zero();
+		}
+		<Commitments<T>>::iter_prefix(who).fold(Zero::zero(), |total: BalanceOf<T>, (id, commitment)| {
+			Self::unclaimed_eras(who, id, &commitment, first_era, end_era - 1)
+				.into_iter()
+				.fold(total, |total, era| total.saturating_add(Self::era_voter_reward(&commitment, era)))
+		})
+	}
+
+	/// Blocks from which the unbonding commitments of `who` can be withdrawn.
+	pub fn unbonding_ends(who: &T::AccountId) -> Vec<(CommitmentId, T::BlockNumber)> {
+		<Commitments<T>>::iter_prefix(who)
+			.filter_map(|(id, commitment)| Self::unbonding_end(&commitment).map(|end_block| (id, end_block)))
+			.collect()
+	}
+
 	/// Voting shares based on currently committed amount.
 	/// Monthly locks have 1x voting power, yearly 10x and 10 yearly 100x.
 	pub fn voting_weight(commitment: &Commitment<T::AccountId, BalanceOf<T>, T::BlockNumber>) -> BalanceOf<T> {
 		if commitment.state != LockState::Committed {
 			return BalanceOf::<T>::from(0 as u32);
 		}
-		let multiplier = match commitment.duration {
-			LockDuration::OneMonth => 1,
-			LockDuration::OneYear  => 10,
-			LockDuration::TenYears => 100,
-		};
-		commitment.amount * BalanceOf::<T>::from(multiplier as u32)
+		let multiplier = <Parameters<T>>::get().lock_terms(&commitment.duration).voting_multiplier;
+		commitment.amount * BalanceOf::<T>::from(multiplier)
 	}
 
-	/// Era reward amount based on currently committed amount.
-	/// Montly locks yield 0% APY. Longer locks yield fixed 10% APY.
-	pub fn era_voter_reward(commitment: &Commitment<T::AccountId, BalanceOf<T>, T::BlockNumber>) -> BalanceOf<T> {
-		if commitment.state != LockState::Committed {
-			return Zero::zero();
-		}
-
+	/// Era reward amount based on committed amount, for an era the commitment
+	/// earns rewards in. Montly locks yield 0% APY. Longer locks yield the nominator APY
+	/// in force during that era.
+	pub fn era_voter_reward(
+		commitment: &Commitment<T::AccountId, BalanceOf<T>, T::BlockNumber>,
+		era: EraIndex,
+	) -> BalanceOf<T> {
 		match commitment.duration {
 			LockDuration::OneMonth => {
 				Zero::zero()
 			},
 			_ => {
-				T::NominatorAPY::get() * (Self::proportion_of_era_to_year() * commitment.amount)
+				Self::era_voter_rewards_of(commitment.amount, &Self::reward_terms(era))
 			}
 		}
 	}
 
+	/// Terms of a completed era, or of the current parameters for eras without
+	/// recorded terms.
+	fn reward_terms(era: EraIndex) -> RewardTerms {
+		<EraRewardTerms<T>>::get(era).unwrap_or_else(|| {
+			let parameters = <Parameters<T>>::get();
+			RewardTerms { era_duration: parameters.era_duration, nominator_apy: parameters.nominator_apy }
+		})
+	}
+
+	/// Era reward of `amount` committed for a year or longer.
+	pub fn era_voter_rewards_of(amount: BalanceOf<T>, terms: &RewardTerms) -> BalanceOf<T> {
+		terms.nominator_apy * (Self::proportion_of_era_to_year(terms.era_duration) * amount)
+	}
+
 	/// Era reward for the whole council. Needs to be divided by n of council members.
 	pub fn era_council_rewards() -> BalanceOf<T> {
+		Self::council_rewards_of(&<Parameters<T>>::get())
+	}
+
+	fn council_rewards_of(parameters: &PocParametersOf<T>) -> BalanceOf<T> {
 		let total_supply = T::Currency::total_issuance();
-		let council_apy = T::CouncilInflation::get() * total_supply;
-		Self::proportion_of_era_to_year() * council_apy
+		let council_apy = parameters.council_inflation * total_supply;
+		Self::proportion_of_era_to_year(parameters.era_duration) * council_apy
 	}
 
 	/// example: 7/365
-	pub fn proportion_of_era_to_year() -> Perbill {
+	pub fn proportion_of_era_to_year(era_duration: primitives::BlockNumber) -> Perbill {
 		PerThing::from_rational(
-			T::EraDuration::get(),
+			era_duration,
 			365 * primitives::time::DAYS
 		)
 	}
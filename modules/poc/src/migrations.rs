@@ -0,0 +1,277 @@
+//! Storage migrations for the PoC module.
+
+use super::*;
+use frame_support::{storage::migration, traits::PalletInfoAccess, StorageHasher};
+
+const COMMITMENTS: &[u8] = b"Commitments";
+
+/// `Commitment` before version 4, without the reward eras.
+#[derive(Encode, Decode, Clone, Default)]
+pub struct CommitmentV3<AccountId, BalanceOf, BlockNumber> {
+	pub state: LockState<BlockNumber>,
+	pub duration: LockDuration,
+	pub amount: BalanceOf,
+	pub candidate: AccountId,
+}
+
+pub type CommitmentV3Of<T> = CommitmentV3<
+	<T as frame_system::Config>::AccountId,
+	BalanceOf<T>,
+	<T as frame_system::Config>::BlockNumber,
+>;
+
+impl<AccountId, BalanceOf, BlockNumber> CommitmentV3<AccountId, BalanceOf, BlockNumber> {
+	fn upgrade(self, since: EraIndex, until: Option<EraIndex>) -> CommitmentV4<AccountId, BalanceOf, BlockNumber> {
+		CommitmentV4 {
+			state: self.state,
+			duration: self.duration,
+			amount: self.amount,
+			candidate: self.candidate,
+			since,
+			until,
+		}
+	}
+}
+
+/// `Commitment` before version 5, approving a single candidate.
+#[derive(Encode, Decode, Clone, Default)]
+pub struct CommitmentV4<AccountId, BalanceOf, BlockNumber> {
+	pub state: LockState<BlockNumber>,
+	pub duration: LockDuration,
+	pub amount: BalanceOf,
+	pub candidate: AccountId,
+	pub since: EraIndex,
+	pub until: Option<EraIndex>,
+}
+
+pub type CommitmentV4Of<T> = CommitmentV4<
+	<T as frame_system::Config>::AccountId,
+	BalanceOf<T>,
+	<T as frame_system::Config>::BlockNumber,
+>;
+
+impl<AccountId, BalanceOf, BlockNumber> CommitmentV4<AccountId, BalanceOf, BlockNumber> {
+	fn upgrade(self) -> Commitment<AccountId, BalanceOf, BlockNumber> {
+		Commitment {
+			state: self.state,
+			duration: self.duration,
+			amount: self.amount,
+			candidates: vec![self.candidate],
+			since: self.since,
+			until: self.until,
+		}
+	}
+}
+
+/// `Commitments` before version 3 held a single commitment per account.
+pub mod v2 {
+	use super::*;
+
+	/// Iterate the commitments stored in the single commitment layout.
+	pub fn commitments<T: Config>() -> impl Iterator<Item = (T::AccountId, CommitmentV3Of<T>)> {
+		migration::storage_key_iter::<T::AccountId, CommitmentV3Of<T>, Blake2_128Concat>(
+			Pallet::<T>::name().as_bytes(),
+			COMMITMENTS,
+		)
+	}
+
+	/// Remove and return the commitments stored in the single commitment layout.
+	pub fn drain_commitments<T: Config>() -> Vec<(T::AccountId, CommitmentV3Of<T>)> {
+		migration::storage_key_iter::<T::AccountId, CommitmentV3Of<T>, Blake2_128Concat>(
+			Pallet::<T>::name().as_bytes(),
+			COMMITMENTS,
+		)
+		.drain()
+		.collect()
+	}
+
+	/// Store a commitment in the single commitment layout.
+	#[cfg(test)]
+	pub fn insert_commitment<T: Config>(who: &T::AccountId, commitment: CommitmentV3Of<T>) {
+		migration::put_storage_value(
+			Pallet::<T>::name().as_bytes(),
+			COMMITMENTS,
+			&who.using_encoded(Blake2_128Concat::hash),
+			commitment,
+		);
+	}
+}
+
+/// Build the per-candidate `Votes` tallies from the existing commitments.
+pub fn migrate_to_v1<T: Config>() -> Weight {
+	let mut commitment_count: Weight = 0;
+	for (who, commitment) in v2::commitments::<T>() {
+		let commitment = commitment.upgrade(0, None).upgrade();
+		Pallet::<T>::add_votes(&who, &commitment);
+		commitment_count += 1;
+	}
+	StorageVersion::new(1).put::<Pallet<T>>();
+
+	T::DbWeight::get().reads_writes(commitment_count + 1, commitment_count + 1)
+}
+
+/// Commitments used to burn the committed funds and re-mint them on withdrawal.
+/// Re-credit the committers and lock the funds in place instead. Funds which
+/// cannot be re-credited, below the existential deposit of a reaped account,
+/// are not locked.
+pub fn migrate_to_v2<T: Config>() -> Weight {
+	let mut commitment_count: Weight = 0;
+	let mut lock_count: Weight = 0;
+	for (who, commitment) in v2::commitments::<T>() {
+		commitment_count += 1;
+		let credited = T::Currency::deposit_creating(&who, commitment.amount).peek();
+		if credited.is_zero() {
+			log::warn!(
+				target: "poc",
+				"cannot re-credit {:?} committed by {:?}, not locking it",
+				commitment.amount,
+				who
+			);
+			continue;
+		}
+		T::Currency::set_lock(POC_LOCK_ID, &who, credited, WithdrawReasons::all());
+		lock_count += 1;
+	}
+	StorageVersion::new(2).put::<Pallet<T>>();
+
+	// balance and total issuance for each commitment, and a lock for each credited one
+	T::DbWeight::get().reads_writes(
+		2 * commitment_count + lock_count + 1,
+		2 * commitment_count + lock_count + 1,
+	)
+}
+
+/// Move the single commitment of each account to commitment id 0.
+pub fn migrate_to_v3<T: Config>() -> Weight {
+	let mut commitment_count: Weight = 0;
+	for (who, commitment) in v2::drain_commitments::<T>() {
+		<CommittedBalance<T>>::insert(&who, commitment.amount);
+		<NextCommitmentId<T>>::insert(&who, 1);
+		let key = [
+			who.using_encoded(Blake2_128Concat::hash),
+			(0 as CommitmentId).using_encoded(Twox64Concat::hash),
+		]
+		.concat();
+		migration::put_storage_value(Pallet::<T>::name().as_bytes(), COMMITMENTS, &key, commitment);
+		commitment_count += 1;
+	}
+	StorageVersion::new(3).put::<Pallet<T>>();
+
+	T::DbWeight::get().reads_writes(commitment_count + 1, 4 * commitment_count + 1)
+}
+
+/// Rewrite the commitments stored in the double map layout from `O` to `V`,
+/// returning their count. Unlike `translate`, neither needs to be the current
+/// `Commitment`.
+fn translate_commitments<T: Config, O: Decode, V: Encode>(mut f: impl FnMut(O) -> V) -> Weight {
+	let pallet = Pallet::<T>::name();
+	let commitments: Vec<(Vec<u8>, O)> = migration::storage_iter::<O>(pallet.as_bytes(), COMMITMENTS).collect();
+	let commitment_count = commitments.len() as Weight;
+	for (key, commitment) in commitments {
+		migration::put_storage_value(pallet.as_bytes(), COMMITMENTS, &key, f(commitment));
+	}
+	commitment_count
+}
+
+/// Record the reward eras of the commitments. Rewards used to be paid when
+/// voting, so committed funds start earning claimable rewards from the next era.
+pub fn migrate_to_v4<T: Config>() -> Weight {
+	let since = <CurrentEra<T>>::get().index.saturating_add(1);
+	let mut pending_rewardable: BalanceOf<T> = Zero::zero();
+	let commitment_count = translate_commitments::<T, CommitmentV3Of<T>, _>(|commitment| {
+		let until = match commitment.state {
+			LockState::Committed => {
+				if commitment.duration != LockDuration::OneMonth {
+					pending_rewardable = pending_rewardable.saturating_add(commitment.amount);
+				}
+				None
+			}
+			LockState::Unbonding(_) => Some(since),
+		};
+		commitment.upgrade(since, until)
+	});
+	<PendingRewardableAmount<T>>::put(pending_rewardable);
+	StorageVersion::new(4).put::<Pallet<T>>();
+
+	T::DbWeight::get().reads_writes(commitment_count + 2, commitment_count + 2)
+}
+
+/// Commitments approve a list of candidates instead of a single one.
+pub fn migrate_to_v5<T: Config>() -> Weight {
+	let mut commitment_count: Weight = 0;
+	<Commitments<T>>::translate::<CommitmentV4Of<T>, _>(|_, _, commitment| {
+		commitment_count += 1;
+		Some(commitment.upgrade())
+	});
+	StorageVersion::new(5).put::<Pallet<T>>();
+
+	T::DbWeight::get().reads_writes(commitment_count, commitment_count + 1)
+}
+
+/// Count the commitments, which are bounded by `MaxCommitments` from now on.
+/// Existing commitments are kept even above the bound.
+pub fn migrate_to_v6<T: Config>() -> Weight {
+	let commitment_count = <Commitments<T>>::iter_values().count() as u32;
+	<CommitmentsCount<T>>::put(commitment_count);
+	StorageVersion::new(6).put::<Pallet<T>>();
+
+	T::DbWeight::get().reads_writes(commitment_count as Weight, 2)
+}
+
+/// Tally the votes per lock duration, so that new voting multipliers apply
+/// without a recount, and record when unbonding commitments can be withdrawn
+/// instead of when they started unbonding.
+pub fn migrate_to_v7<T: Config>() -> Weight {
+	let parameters = <Parameters<T>>::get();
+	<Votes<T>>::remove_all(None);
+	let mut commitment_count: Weight = 0;
+	let mut tally_writes: Weight = 0;
+	<Commitments<T>>::translate::<CommitmentOf<T>, _>(|who, _, mut commitment| {
+		commitment_count += 1;
+		if let LockState::Unbonding(start_block) = commitment.state {
+			let unbonding_period: T::BlockNumber = parameters.lock_terms(&commitment.duration).unbonding_period.into();
+			commitment.state = LockState::Unbonding(start_block + unbonding_period);
+		} else {
+			tally_writes += commitment.candidates.len() as Weight;
+		}
+		Pallet::<T>::add_votes(&who, &commitment);
+		Some(commitment)
+	});
+	StorageVersion::new(7).put::<Pallet<T>>();
+
+	T::DbWeight::get().reads_writes(commitment_count + tally_writes + 1, commitment_count + tally_writes + 1)
+}
+
+/// Tally the votes cast by each account, so that its voting weight is known
+/// without iterating its commitments.
+pub fn migrate_to_v8<T: Config>() -> Weight {
+	<AccountVotes<T>>::remove_all(None);
+	let mut commitment_count: Weight = 0;
+	for (who, _, commitment) in <Commitments<T>>::iter() {
+		commitment_count += 1;
+		if commitment.state == LockState::Committed && !commitment.amount.is_zero() {
+			<AccountVotes<T>>::mutate(&who, |votes| votes.add(&commitment.duration, commitment.amount));
+		}
+	}
+	StorageVersion::new(8).put::<Pallet<T>>();
+
+	T::DbWeight::get().reads_writes(commitment_count, commitment_count + 1)
+}
+
+/// Record the candidates approved by the voting commitments of each account,
+/// so that the election reads a single voter per account.
+pub fn migrate_to_v9<T: Config>() -> Weight {
+	<VoterApprovals<T>>::remove_all(None);
+	let mut commitment_count: Weight = 0;
+	for (who, _, commitment) in <Commitments<T>>::iter() {
+		commitment_count += 1;
+		if commitment.state == LockState::Committed && !commitment.amount.is_zero() {
+			<VoterApprovals<T>>::mutate(&who, |approvals| {
+				Pallet::<T>::add_approvals(approvals, &commitment.candidates)
+			});
+		}
+	}
+	StorageVersion::new(9).put::<Pallet<T>>();
+
+	T::DbWeight::get().reads_writes(commitment_count, commitment_count + 1)
+}
@@ -80,6 +80,13 @@ parameter_types! {
 	pub const CandidacyDeposit: Balance = 250_000;
 	pub const MinLockAmount: Balance = 100;
 	pub const TotalLockedCap: Balance = 10_000_000;
+	pub const HistoryDepth: u32 = 3;
+	pub const MaxVotes: u32 = 3;
+	pub const MaxCommitments: u32 = 10;
+	pub const MaxRunnersUp: u32 = 2;
+	pub const ResignationPenalty: Perbill = Perbill::from_percent(20);
+	pub const EarlyUnbondPenalty: Perbill = Perbill::from_percent(50);
+	pub const CouncilRewardSource: u64 = 100;
 }
 
 impl module_poc::Config for Runtime {
@@ -92,8 +99,22 @@ impl module_poc::Config for Runtime {
 	type MinLockAmount = MinLockAmount;
 	type TotalLockedCap = TotalLockedCap;
 	type MaxCandidates = TechCouncilMaxCandidates;
+	type MaxCommitments = MaxCommitments;
 	type MaxMembers = TechCouncilMaxMembers;
+	type MaxRunnersUp = MaxRunnersUp;
+	type ResignationPenalty = ResignationPenalty;
+	type ResignationPenaltyHandler = ();
+	type EarlyUnbondPenalty = EarlyUnbondPenalty;
+	type EarlyUnbondPenaltyHandler = ();
+	type SlashOrigin = frame_system::EnsureRoot<u64>;
+	type SlashHandler = ();
+	type CouncilRewardSource = CouncilRewardSource;
+	type HistoryDepth = HistoryDepth;
+	type MaxVotes = MaxVotes;
+	type CurrencyToVote = frame_support::traits::SaturatingCurrencyToVote;
 	type MembershipChanged = TechCouncil;
+	type ParametersOrigin = frame_system::EnsureRoot<u64>;
+	type OnVotesRemoved = ();
 	type WeightInfo = ();
 }
 
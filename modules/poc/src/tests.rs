@@ -2,6 +2,7 @@
 
 use crate::mock::*;
 use frame_support::{assert_ok, assert_err};
+use sp_runtime::Perbill;
 
 #[test]
 fn test_setup() {
@@ -36,9 +37,9 @@ fn commits() {
 				Origin::signed(nobody),
 				(100_000 as u64).into(),
 				crate::LockDuration::OneYear,
-				bob,
+				vec![bob],
 			),
-			pallet_balances::Error::<Runtime>::InsufficientBalance
+			crate::Error::<Runtime>::InsufficientBalance
 		);
 
 		// alice commits 100k and votes for bob
@@ -47,32 +48,101 @@ fn commits() {
 				Origin::signed(alice),
 				(100_000 as u64).into(),
 				crate::LockDuration::OneYear,
-				bob,
+				vec![bob],
 			)
 		);
-		assert!(Poc::commitments(alice).state == crate::LockState::Committed);
+		assert!(Poc::commitments(alice, 0).state == crate::LockState::Committed);
 
-		// cannot commit again
+		// we can add more funds
+		assert_ok!(
+			Poc::add_funds(
+				Origin::signed(alice),
+				0,
+				(1_000 as u64).into(),
+			)
+		);
+		let balance = Poc::commitments(alice, 0).amount;
+		assert!(balance as u64 == 101_000 as u64);
+		assert_eq!(Poc::locked_amount(), 101_000 as u64);
+
+		// but not to a commitment that does not exist
 		assert_err!(
+			Poc::add_funds(
+				Origin::signed(alice),
+				1,
+				(1_000 as u64).into(),
+			),
+			crate::Error::<Runtime>::CommitmentNotFound
+		);
+
+		// and hold another commitment with a different duration
+		assert_ok!(
 			Poc::commit(
 				Origin::signed(alice),
 				(100_000 as u64).into(),
-				crate::LockDuration::OneYear,
-				bob,
+				crate::LockDuration::TenYears,
+				vec![bob],
+			)
+		);
+		assert_eq!(Poc::commitments(alice, 1).amount, 100_000 as u64);
+		assert_eq!(Poc::commitments(alice, 1).duration, crate::LockDuration::TenYears);
+		assert_eq!(Poc::next_commitment_id(alice), 2);
+		assert_eq!(Poc::committed_balance(alice), 201_000 as u64);
+		assert_eq!(Poc::locked_amount(), 201_000 as u64);
+		assert_eq!(Poc::votes(bob), 11_010_000 as u64);
+		assert_eq!(crate::VoterApprovals::<Runtime>::get(alice), vec![(bob, 2)]);
+
+		// the committed funds stay with alice, locked
+		assert_eq!(Balances::free_balance(&alice), 1_000_000 as u64);
+		assert_eq!(Balances::usable_balance(&alice), 799_000 as u64);
+		assert_eq!(Balances::total_issuance(), 4_000_000 as u64);
+
+		// locked funds cannot be committed twice
+		assert_err!(
+			Poc::add_funds(
+				Origin::signed(alice),
+				0,
+				(800_000 as u64).into(),
 			),
-			crate::Error::<Runtime>::AlreadyCommitted
+			crate::Error::<Runtime>::InsufficientBalance
 		);
+		assert_ok!(Poc::do_try_state());
+	});
+}
+
+#[test]
+fn commits_funds_held_by_other_locks() {
+	use frame_support::traits::{LockableCurrency, WithdrawReasons};
 
-		// we can however add more funds
+	new_test_ext().execute_with(|| {
+		let alice = 0 as u64;
+		let bob = 1 as u64;
+
+		// all of alice's funds are vesting
+		Balances::set_lock(*b"vesting ", &alice, 1_000_000, WithdrawReasons::all());
+
+		// the commitment lock overlaps the vesting lock
 		assert_ok!(
-			Poc::add_funds(
+			Poc::commit(
 				Origin::signed(alice),
-				(1_000 as u64).into(),
+				(1_000_000 as u64).into(),
+				crate::LockDuration::OneYear,
+				vec![bob],
 			)
 		);
-		let balance = Poc::commitments(alice).amount;
-		assert!(balance as u64 == 101_000 as u64);
-		assert_eq!(Poc::locked_amount(), 101_000 as u64);
+		assert_eq!(Poc::committed_balance(alice), 1_000_000 as u64);
+		assert_eq!(Balances::usable_balance(&alice), 0 as u64);
+
+		// but cannot lock more than the free balance
+		assert_err!(
+			Poc::add_funds(
+				Origin::signed(alice),
+				0,
+				(1 as u64).into(),
+			),
+			crate::Error::<Runtime>::InsufficientBalance
+		);
+		assert_ok!(Poc::do_try_state());
 	});
 }
 
@@ -87,42 +157,42 @@ fn withdrawals() {
 				Origin::signed(alice),
 				(100_000 as u64).into(),
 				crate::LockDuration::OneMonth,
-				bob,
+				vec![bob],
 			)
 		);
 
 		// she cannot withdraw an active commitment
 		assert_err!(
-			Poc::withdraw(Origin::signed(alice)),
+			Poc::withdraw(Origin::signed(alice), 0),
 			crate::Error::<Runtime>::AlreadyCommitted
 		);
 
 		// she starts the unbonding
-		assert_ok!(Poc::unbond(Origin::signed(alice)));
+		assert_ok!(Poc::unbond(Origin::signed(alice), 0));
 
 		// her voting power is now 0
 		assert_eq!(
-			Poc::voting_weight(&Poc::commitments(&alice)),
+			Poc::voting_weight(&Poc::commitments(&alice, 0)),
 			0
 		);
 
 		// still to early to withdraw
 		assert_err!(
-			Poc::withdraw(Origin::signed(alice)),
+			Poc::withdraw(Origin::signed(alice), 0),
 			crate::Error::<Runtime>::CannotWithdrawLocked
 		);
 
 		// after the unboding period we can withdraw
 		skip_blocks(31 * DAYS);
-		assert_ok!(Poc::withdraw(Origin::signed(alice)));
+		assert_ok!(Poc::withdraw(Origin::signed(alice), 0));
 
-		// the funds are returned to alice
-		let balance = Balances::free_balance(&alice);
+		// the funds are unlocked for alice
+		let balance = Balances::usable_balance(&alice);
 		assert_eq!(balance, 1_000_000 as u64);
 
 		// storage checks
 		assert_eq!(Poc::locked_amount(), 0 as u64);
-		assert_eq!(Poc::commitments(&alice).amount, 0 as u64);
+		assert_eq!(Poc::commitments(&alice, 0).amount, 0 as u64);
 
 		// alice can make a new commitment
 		assert_ok!(
@@ -130,15 +200,152 @@ fn withdrawals() {
 				Origin::signed(alice),
 				(100_000 as u64).into(),
 				crate::LockDuration::OneMonth,
-				bob,
+				vec![bob],
 			)
 		);
-		assert_eq!(Poc::commitments(&alice).amount, 100_000 as u64);
-		let balance = Balances::free_balance(&alice);
+		assert_eq!(Poc::commitments(&alice, 1).amount, 100_000 as u64);
+		let balance = Balances::usable_balance(&alice);
 		assert_eq!(balance, 900_000 as u64);
 	});
 }
 
+#[test]
+fn early_unbonding() {
+	new_test_ext().execute_with(|| {
+		let alice = 0 as u64;
+		let bob = 1 as u64;
+
+		for duration in [crate::LockDuration::TenYears, crate::LockDuration::OneYear].iter() {
+			assert_ok!(
+				Poc::commit(
+					Origin::signed(alice),
+					(100_000 as u64).into(),
+					duration.clone(),
+					vec![bob],
+				)
+			);
+		}
+		assert_eq!(Poc::votes(bob), 11_000_000);
+
+		assert_err!(
+			Poc::unbond_early(Origin::signed(alice), 2),
+			crate::Error::<Runtime>::CommitmentNotFound
+		);
+
+		// with the whole ten years ahead the full penalty applies, and is burnt
+		let issuance = Balances::total_issuance();
+		assert_ok!(Poc::unbond_early(Origin::signed(alice), 0));
+		System::assert_last_event(Event::Poc(crate::Event::UnbondedEarly(alice, 0, 50_000, 50_000)));
+		assert_eq!(Balances::free_balance(&alice), 950_000);
+		assert_eq!(Balances::total_issuance(), issuance - 50_000);
+		assert_eq!(Poc::committed_balance(alice), 100_000);
+		assert_eq!(Poc::locked_amount(), 100_000);
+		assert_eq!(Poc::votes(bob), 1_000_000);
+		assert_ok!(Poc::do_try_state());
+
+		// the yearly commitment earns rewards for an era
+		run_blocks(7 * HOURS);
+		run_blocks(7 * HOURS);
+		assert!(Poc::pending_rewards(&alice) > 0);
+
+		// half way through unbonding the penalty is scaled by the half year
+		// left against the ten year period, and the rewards are forfeited
+		assert_ok!(Poc::unbond(Origin::signed(alice), 1));
+		skip_blocks(365 * DAYS / 2);
+		assert_eq!(Poc::early_unbond_penalty(&Poc::commitments(alice, 1)), Some(2_500));
+		assert_ok!(Poc::unbond_early(Origin::signed(alice), 1));
+		System::assert_last_event(Event::Poc(crate::Event::UnbondedEarly(alice, 1, 97_500, 2_500)));
+		assert_eq!(Balances::free_balance(&alice), 947_500);
+		assert_eq!(Poc::pending_rewards(&alice), 0);
+		assert_eq!(Poc::committed_balance(alice), 0);
+		assert_eq!(Poc::locked_amount(), 0);
+		assert_eq!(Poc::votes(bob), 0);
+
+		// once unbonded the commitment is withdrawn instead
+		assert_ok!(
+			Poc::commit(
+				Origin::signed(alice),
+				(100_000 as u64).into(),
+				crate::LockDuration::OneMonth,
+				vec![bob],
+			)
+		);
+		assert_ok!(Poc::unbond(Origin::signed(alice), 2));
+		skip_blocks(30 * DAYS);
+		assert_err!(
+			Poc::unbond_early(Origin::signed(alice), 2),
+			crate::Error::<Runtime>::AlreadyUnbonded
+		);
+		assert_ok!(Poc::withdraw(Origin::signed(alice), 2));
+		assert_ok!(Poc::do_try_state());
+	});
+}
+
+#[test]
+fn early_unbonding_penalty_decays_with_time_served() {
+	new_test_ext().execute_with(|| {
+		let alice = 0 as u64;
+		let bob = 1 as u64;
+
+		assert_ok!(
+			Poc::commit(
+				Origin::signed(alice),
+				(100_000 as u64).into(),
+				crate::LockDuration::OneYear,
+				vec![bob],
+			)
+		);
+
+		// with the whole year ahead
+		let full_penalty = Poc::early_unbond_penalty(&Poc::commitments(alice, 0)).unwrap();
+		assert_eq!(full_penalty, 5_000);
+
+		// the eras served count against the year
+		run_blocks(3 * 7 * HOURS);
+		let penalty = Poc::early_unbond_penalty(&Poc::commitments(alice, 0)).unwrap();
+		assert!(penalty < full_penalty);
+		assert!(penalty > 0);
+
+		assert_ok!(Poc::unbond_early(Origin::signed(alice), 0));
+		System::assert_last_event(Event::Poc(crate::Event::UnbondedEarly(alice, 0, 100_000 - penalty, penalty)));
+		assert_ok!(Poc::do_try_state());
+	});
+}
+
+#[test]
+fn unbonding_ends_at_unbond_time_terms() {
+	new_test_ext().execute_with(|| {
+		let alice = 0 as u64;
+		let bob = 1 as u64;
+
+		assert_ok!(
+			Poc::commit(
+				Origin::signed(alice),
+				(100_000 as u64).into(),
+				crate::LockDuration::OneMonth,
+				vec![bob],
+			)
+		);
+		let block = System::block_number();
+		assert_ok!(Poc::unbond(Origin::signed(alice), 0));
+
+		// a longer unbonding period only applies to later unbondings
+		let mut parameters = Poc::parameters();
+		parameters.one_month.unbonding_period = 60 * DAYS;
+		assert_ok!(Poc::set_parameters(Origin::root(), parameters));
+		run_blocks(7 * HOURS);
+		assert_eq!(Poc::parameters().one_month.unbonding_period, 60 * DAYS);
+		assert_eq!(Poc::unbonding_ends(&alice), vec![(0, block + 30 * DAYS as u64)]);
+
+		skip_blocks(30 * DAYS - 7 * HOURS - 1);
+		assert!(Poc::early_unbond_penalty(&Poc::commitments(alice, 0)).is_some());
+		skip_blocks(1);
+		assert_eq!(Poc::early_unbond_penalty(&Poc::commitments(alice, 0)), None);
+		assert_ok!(Poc::withdraw(Origin::signed(alice), 0));
+		assert_ok!(Poc::do_try_state());
+	});
+}
+
 #[test]
 fn voting_rewards() {
 	new_test_ext().execute_with(|| {
@@ -152,7 +359,7 @@ fn voting_rewards() {
 				Origin::signed(alice),
 				(100_000 as u64).into(),
 				crate::LockDuration::OneMonth,
-				bob,
+				vec![bob],
 			)
 		);
 
@@ -162,7 +369,7 @@ fn voting_rewards() {
 				Origin::signed(bob),
 				(100_000 as u64).into(),
 				crate::LockDuration::OneYear,
-				bob,
+				vec![bob],
 			)
 		);
 
@@ -172,48 +379,139 @@ fn voting_rewards() {
 				Origin::signed(charlie),
 				(100_000 as u64).into(),
 				crate::LockDuration::TenYears,
-				bob,
+				vec![bob],
 			)
 		);
 
-		// all 3 vote
-		assert_ok!(Poc::vote_candidate(Origin::signed(alice), bob));
-		assert_ok!(Poc::vote_candidate(Origin::signed(bob), bob));
-		assert_ok!(Poc::vote_candidate(Origin::signed(charlie), bob));
+		// rewards cannot be claimed for an era which has not completed
+		assert_err!(
+			Poc::claim_rewards(Origin::signed(bob), 0, (0, 0), false),
+			crate::Error::<Runtime>::InvalidEraRange
+		);
 
-		// alice should not have received a reward
-		let balance = Balances::free_balance(&alice);
-		assert_eq!(balance, 900_000 as u64);
+		// commitments earn rewards from the first full era
+		run_blocks(7 * HOURS);
+		assert_err!(
+			Poc::claim_rewards(Origin::signed(bob), 0, (0, 0), false),
+			crate::Error::<Runtime>::NoRewards
+		);
+
+		run_blocks(7 * HOURS);
+		assert_eq!(Poc::unclaimed_rewards(1), 16);
+
+		// alice should not receive a reward
+		assert_err!(
+			Poc::claim_rewards(Origin::signed(alice), 0, (1, 1), false),
+			crate::Error::<Runtime>::NoRewards
+		);
 
 		// bob should receive 10% APY
 		// In [1]: (7/(24*365)) * 10000
 		// Out[1]: 7.990867579908676
-		let balance = Balances::free_balance(&bob);
+		assert_ok!(Poc::claim_rewards(Origin::signed(bob), 0, (1, 1), false));
+		System::assert_last_event(Event::Poc(crate::Event::VoterRewarded(1, bob, 8)));
+		let balance = Balances::usable_balance(&bob);
 		assert_eq!(balance, 900_008 as u64);
+		assert_eq!(Poc::unclaimed_rewards(1), 8);
 
-		// charlie should also receive 10% APY
-		let balance = Balances::free_balance(&charlie);
-		assert_eq!(balance, 900_008 as u64);
+		// claiming twice does not double rewards
+		assert_err!(
+			Poc::claim_rewards(Origin::signed(bob), 0, (0, 1), false),
+			crate::Error::<Runtime>::NoRewards
+		);
 
 		// charlie starts unbonding
-		assert_ok!(Poc::unbond(Origin::signed(charlie)));
+		assert_ok!(Poc::unbond(Origin::signed(charlie), 0));
 
 		// so he can no longer vote
 		assert_err!(
-			Poc::vote_candidate(Origin::signed(charlie), bob),
+			Poc::vote_candidates(Origin::signed(charlie), 0, vec![bob]),
 			crate::Error::<Runtime>::NotCommitted
 		);
 
-		// voting twice in the same era does not double rewards
-		assert_ok!(Poc::vote_candidate(Origin::signed(bob), bob));
-		let balance = Balances::free_balance(&bob);
+		// but can still claim for the eras he was committed in
+		run_blocks(7 * HOURS);
+		assert_ok!(Poc::claim_rewards(Origin::signed(charlie), 0, (1, 2), false));
+		let balance = Balances::usable_balance(&charlie);
 		assert_eq!(balance, 900_008 as u64);
+		assert_eq!(Poc::unclaimed_rewards(1), 0);
 
-		// voting in the next era yields more rewards
-		run_blocks(7 * HOURS + 1);
-		assert_ok!(Poc::vote_candidate(Origin::signed(bob), bob));
-		let balance = Balances::free_balance(&bob);
+		// bob keeps earning in the next eras
+		assert_ok!(Poc::claim_rewards(Origin::signed(bob), 0, (2, 2), false));
+		let balance = Balances::usable_balance(&bob);
 		assert_eq!(balance, 900_016 as u64);
+		assert_eq!(Poc::unclaimed_rewards(2), 0);
+		assert_ok!(Poc::do_try_state());
+	});
+}
+
+#[test]
+fn unclaimed_rewards_expire() {
+	new_test_ext().execute_with(|| {
+		let bob = 1 as u64;
+
+		assert_ok!(
+			Poc::commit(
+				Origin::signed(bob),
+				(100_000 as u64).into(),
+				crate::LockDuration::OneYear,
+				vec![bob],
+			)
+		);
+
+		// era 1 completes with unclaimed rewards
+		run_blocks(2 * 7 * HOURS);
+		assert_eq!(Poc::unclaimed_rewards(1), 8);
+		assert_eq!(Poc::claimable_eras(), (0, 2));
+
+		// and leaves the history after `HistoryDepth` more eras
+		run_blocks(3 * 7 * HOURS);
+		assert_eq!(Poc::claimable_eras(), (2, 5));
+		System::assert_has_event(Event::Poc(crate::Event::UnclaimedRewardsExpired(1, 8)));
+		assert_eq!(Poc::unclaimed_rewards(1), 0);
+
+		assert_err!(
+			Poc::claim_rewards(Origin::signed(bob), 0, (1, 4), false),
+			crate::Error::<Runtime>::InvalidEraRange
+		);
+		assert_ok!(Poc::claim_rewards(Origin::signed(bob), 0, (2, 4), false));
+		assert_eq!(Balances::usable_balance(&bob), 900_024 as u64);
+	});
+}
+
+#[test]
+fn compound_rewards() {
+	new_test_ext().execute_with(|| {
+		let bob = 1 as u64;
+
+		assert_ok!(
+			Poc::commit(
+				Origin::signed(bob),
+				(100_000 as u64).into(),
+				crate::LockDuration::OneYear,
+				vec![bob],
+			)
+		);
+		run_blocks(3 * 7 * HOURS);
+
+		// compounding era 1 also pays out era 2, as the reward period restarts
+		assert_ok!(Poc::claim_rewards(Origin::signed(bob), 0, (1, 1), true));
+		System::assert_last_event(Event::Poc(crate::Event::RewardsCompounded(bob, 0, 8)));
+		assert_eq!(Poc::commitments(bob, 0).amount, 100_008 as u64);
+		assert_eq!(Poc::commitments(bob, 0).since, 4);
+		assert_eq!(Poc::locked_amount(), 100_008 as u64);
+		assert_eq!(Poc::votes(bob), 1_000_080 as u64);
+		assert_eq!(Balances::free_balance(&bob), 1_000_016 as u64);
+		assert_eq!(Balances::usable_balance(&bob), 900_008 as u64);
+		assert_eq!(Poc::unclaimed_rewards(2), 0);
+		assert_ok!(Poc::do_try_state());
+
+		// adding funds pays out the outstanding rewards as well
+		run_blocks(2 * 7 * HOURS);
+		assert_ok!(Poc::add_funds(Origin::signed(bob), 0, (1_000 as u64).into()));
+		System::assert_has_event(Event::Poc(crate::Event::VoterRewarded(4, bob, 8)));
+		assert_eq!(Balances::usable_balance(&bob), 899_016 as u64);
+		assert_ok!(Poc::do_try_state());
 	});
 }
 
@@ -246,6 +544,8 @@ fn candidacy() {
 
 #[test]
 fn elections() {
+	use frame_support::traits::Currency;
+
 	new_test_ext().execute_with(|| {
 		let alice = 0 as u64;
 		let bob = 1 as u64;
@@ -265,12 +565,12 @@ fn elections() {
 				Origin::signed(alice),
 				(100_000 as u64).into(),
 				crate::LockDuration::OneMonth,
-				alice,
+				vec![alice],
 			)
 		);
 		// she gets 1x voting power
 		assert_eq!(
-			Poc::voting_weight(&Poc::commitments(&alice)),
+			Poc::voting_weight(&Poc::commitments(&alice, 0)),
 			100_000,
 		);
 
@@ -280,12 +580,12 @@ fn elections() {
 				Origin::signed(bob),
 				(100_000 as u64).into(),
 				crate::LockDuration::OneYear,
-				bob,
+				vec![bob],
 			)
 		);
 		// he gets 10x voting power
 		assert_eq!(
-			Poc::voting_weight(&Poc::commitments(&bob)),
+			Poc::voting_weight(&Poc::commitments(&bob, 0)),
 			10 * 100_000,
 		);
 
@@ -295,12 +595,12 @@ fn elections() {
 				Origin::signed(charlie),
 				(100_000 as u64).into(),
 				crate::LockDuration::TenYears,
-				charlie,
+				vec![charlie],
 			)
 		);
 		// he gets 100x voting power
 		assert_eq!(
-			Poc::voting_weight(&Poc::commitments(&charlie)),
+			Poc::voting_weight(&Poc::commitments(&charlie, 0)),
 			100 * 100_000,
 		);
 
@@ -310,17 +610,21 @@ fn elections() {
 				Origin::signed(eve),
 				(200_000 as u64).into(),
 				crate::LockDuration::TenYears,
-				nobody,
+				vec![nobody],
 			)
 		);
 		assert_eq!(
-			Poc::voting_weight(&Poc::commitments(&eve)),
+			Poc::voting_weight(&Poc::commitments(&eve, 0)),
 			100 * 200_000,
 		);
 
-		// check current supply for rewards = 4m - 500k committed
+		// council rewards are paid from the reward source
+		let source = CouncilRewardSource::get();
+		let _ = Balances::deposit_creating(&source, 1_000_000);
+
+		// committed funds still count towards the supply for rewards
 		let total_supply = Balances::total_issuance();
-		assert_eq!(total_supply, 3_500_000 as u64);
+		assert_eq!(total_supply, 5_000_000 as u64);
 
 		// run the elections
 		run_blocks(7 * HOURS);
@@ -336,20 +640,21 @@ fn elections() {
 		);
 
 		// check rewards
-		// In [1]: (7/(24*365)) * (3_500_000 * 0.01)
-		// Out[1]: 27.968036529680365
-		// In [2]: _ / 3
-		// Out[2]: 9.322678843226788
+		// In [1]: (7/(24*365)) * (5_000_000 * 0.01)
+		// Out[1]: 39.954337899543376
+		// In [2]: round(_) / 3
+		// Out[2]: 13.333333333333334
 		// per winner reward ^^^
-		assert_eq!(Balances::free_balance(&alice), 650_009 as u64);
-		assert_eq!(Balances::free_balance(&bob), 650_009 as u64);
-		assert_eq!(Balances::free_balance(&charlie), 650_009 as u64);
-		assert_eq!(Balances::free_balance(&nobody), 0 as u64);
+		assert_eq!(Balances::usable_balance(&alice), 650_013 as u64);
+		assert_eq!(Balances::usable_balance(&bob), 650_013 as u64);
+		assert_eq!(Balances::usable_balance(&charlie), 650_013 as u64);
+		assert_eq!(Balances::usable_balance(&nobody), 0 as u64);
+		assert_eq!(Balances::free_balance(&source), 1_000_000 - 3 * 13);
+		assert_eq!(Balances::total_issuance(), total_supply);
 
-		// eve votes herself (and gets voting reward)
-		assert_eq!(Balances::free_balance(&eve), 550_000 as u64);
-		assert_ok!(Poc::vote_candidate(Origin::signed(eve), eve));
-		assert_eq!(Balances::free_balance(&eve), 550_016 as u64);
+		// eve votes herself
+		assert_eq!(Balances::usable_balance(&eve), 550_000 as u64);
+		assert_ok!(Poc::vote_candidates(Origin::signed(eve), 0, vec![eve]));
 
 		// run the elections
 		run_blocks(7 * HOURS);
@@ -365,17 +670,724 @@ fn elections() {
 		);
 
 		// rewards
-		assert_eq!(Balances::free_balance(&alice), 650_009 as u64);
-		assert_eq!(Balances::free_balance(&bob), 650_018 as u64);
-		assert_eq!(Balances::free_balance(&charlie), 650_018 as u64);
-		assert_eq!(Balances::free_balance(&eve), 550_025 as u64);
+		assert_eq!(Balances::usable_balance(&alice), 650_013 as u64);
+		assert_eq!(Balances::usable_balance(&bob), 650_026 as u64);
+		assert_eq!(Balances::usable_balance(&charlie), 650_026 as u64);
+		assert_eq!(Balances::usable_balance(&eve), 550_013 as u64);
+
+		assert_ok!(Poc::do_try_state());
 
 		// TODO: cannot vote empty / too small quorum
 		// assert_ok!(Poc::unbond(Origin::signed(alice)));
 		// assert_ok!(Poc::unbond(Origin::signed(bob)));
 	});
 }
+#[test]
+fn resignation() {
+	new_test_ext().execute_with(|| {
+		let alice = 0 as u64;
+		let bob = 1 as u64;
+		let charlie = 2 as u64;
+		let eve = 3 as u64;
 
+		for candidate in [alice, bob, charlie, eve].iter() {
+			assert_ok!(Poc::start_candidacy(Origin::signed(*candidate)));
+		}
+		for (voter, amount) in [(alice, 400), (bob, 300), (charlie, 200), (eve, 100)].iter() {
+			assert_ok!(
+				Poc::commit(
+					Origin::signed(*voter),
+					(*amount as u64).into(),
+					crate::LockDuration::OneMonth,
+					vec![*voter],
+				)
+			);
+		}
+
+		// only members can resign
+		assert_err!(
+			Poc::resign(Origin::signed(alice)),
+			crate::Error::<Runtime>::NotMember
+		);
+
+		// the candidate elected after the members is the runner-up
+		run_blocks(7 * HOURS);
+		assert_eq!(Poc::members(), vec![alice, bob, charlie]);
+		assert_eq!(Poc::runners_up(), vec![eve]);
+		assert_ok!(Poc::do_try_state());
+
+		// the empty reward source pays no council rewards
+		System::assert_has_event(Event::Poc(crate::Event::CouncilRewardUnpaid(1, alice, 10)));
+
+		// runners-up leave the list with their candidacy
+		assert_ok!(Poc::stop_candidacy(Origin::signed(eve)));
+		assert_eq!(Poc::runners_up(), vec![]);
+		assert_ok!(Poc::start_candidacy(Origin::signed(eve)));
+		run_blocks(7 * HOURS);
+		assert_eq!(Poc::runners_up(), vec![eve]);
+
+		// members cannot just withdraw their candidacy
+		assert_err!(
+			Poc::stop_candidacy(Origin::signed(alice)),
+			crate::Error::<Runtime>::CannotLeave
+		);
+
+		// but can resign, losing 20% of the deposit, and the runner-up takes the seat
+		let free_balance = Balances::free_balance(&alice);
+		let issuance = Balances::total_issuance();
+		assert_ok!(Poc::resign(Origin::signed(alice)));
+		System::assert_has_event(Event::Poc(crate::Event::MemberResigned(alice, 50_000)));
+		System::assert_last_event(Event::Poc(crate::Event::RunnerUpPromoted(eve)));
+		assert_eq!(Balances::reserved_balance(&alice), 0 as u64);
+		assert_eq!(Balances::free_balance(&alice), free_balance + 200_000);
+		assert_eq!(Balances::total_issuance(), issuance - 50_000);
+		assert_eq!(Poc::candidates(alice), 0);
+		assert_eq!(Poc::n_candidates(), 3);
+		assert_eq!(Poc::members(), vec![bob, charlie, eve]);
+		assert_eq!(TechCouncil::members(), vec![bob, charlie, eve]);
+		assert_eq!(Poc::runners_up(), vec![]);
+		assert_ok!(Poc::do_try_state());
+
+		// without runners-up the seat stays vacant until the next era
+		assert_ok!(Poc::resign(Origin::signed(bob)));
+		System::assert_last_event(Event::Poc(crate::Event::MemberResigned(bob, 50_000)));
+		assert_eq!(Poc::members(), vec![charlie, eve]);
+		assert_eq!(TechCouncil::members(), vec![charlie, eve]);
+		assert_ok!(Poc::do_try_state());
+	});
+}
+
+#[test]
+fn member_slashing() {
+	new_test_ext().execute_with(|| {
+		let alice = 0 as u64;
+		let bob = 1 as u64;
+		let charlie = 2 as u64;
+		let eve = 3 as u64;
+
+		for candidate in [alice, bob, charlie, eve].iter() {
+			assert_ok!(Poc::start_candidacy(Origin::signed(*candidate)));
+		}
+		for (voter, amount) in [(alice, 400), (bob, 300), (charlie, 200), (eve, 100)].iter() {
+			assert_ok!(
+				Poc::commit(
+					Origin::signed(*voter),
+					(*amount as u64).into(),
+					crate::LockDuration::OneMonth,
+					vec![*voter],
+				)
+			);
+		}
+		// charlie also backs both alice and bob
+		assert_ok!(
+			Poc::commit(
+				Origin::signed(charlie),
+				(1_000 as u64).into(),
+				crate::LockDuration::OneYear,
+				vec![alice, bob],
+			)
+		);
+		run_blocks(7 * HOURS);
+		assert_eq!(Poc::members(), vec![alice, bob, charlie]);
+		assert_eq!(Poc::runners_up(), vec![eve]);
+
+		// only the slash origin can slash, and only members
+		assert_err!(
+			Poc::slash_member(Origin::signed(bob), alice, Perbill::from_percent(50), true, 5),
+			sp_runtime::traits::BadOrigin
+		);
+		assert_err!(
+			Poc::slash_member(Origin::root(), eve, Perbill::from_percent(50), true, 5),
+			crate::Error::<Runtime>::NotMember
+		);
+		// the commitment count has to cover every commitment
+		assert_err!(
+			Poc::slash_member(Origin::root(), alice, Perbill::from_percent(50), true, 4),
+			crate::Error::<Runtime>::InvalidWitness
+		);
+
+		// half the deposit is slashed, and half the backing split between the
+		// approved candidates
+		let issuance = Balances::total_issuance();
+		let locked = Poc::locked_amount();
+		assert_ok!(Poc::slash_member(Origin::root(), alice, Perbill::from_percent(50), true, 5));
+		System::assert_has_event(Event::Poc(crate::Event::MemberSlashed(alice, 125_000, 450)));
+		System::assert_last_event(Event::Poc(crate::Event::RunnerUpPromoted(eve)));
+		assert_eq!(Balances::reserved_balance(&alice), 0);
+		assert_eq!(Balances::total_issuance(), issuance - 125_450);
+		assert_eq!(Poc::locked_amount(), locked - 450);
+		assert_eq!(Poc::commitments(alice, 0).amount, 200);
+		assert_eq!(Poc::commitments(charlie, 1).amount, 750);
+		assert_eq!(Poc::votes(alice), 7_700);
+		assert_eq!(Poc::votes(bob), 7_800);
+		assert_eq!(Poc::candidates(alice), 0);
+		assert_eq!(Poc::members(), vec![bob, charlie, eve]);
+		assert_eq!(TechCouncil::members(), vec![bob, charlie, eve]);
+		assert_eq!(
+			Poc::slashing_history(alice),
+			vec![crate::SlashRecord {
+				era: 1,
+				fraction: Perbill::from_percent(50),
+				deposit_slashed: 125_000,
+				backers_slashed: 450,
+			}]
+		);
+		assert_ok!(Poc::do_try_state());
+
+		// the backers can be spared
+		assert_ok!(Poc::slash_member(Origin::root(), bob, Perbill::from_percent(10), false, 0));
+		System::assert_last_event(Event::Poc(crate::Event::MemberSlashed(bob, 25_000, 0)));
+		assert_eq!(Poc::commitments(bob, 0).amount, 300);
+		assert_eq!(Poc::members(), vec![charlie, eve]);
+		assert_eq!(Poc::slashing_history(bob).len(), 1);
+		assert_ok!(Poc::do_try_state());
+	});
+}
+
+#[test]
+fn parameters() {
+	new_test_ext().execute_with(|| {
+		let alice = 0 as u64;
+		let bob = 1 as u64;
+
+		// alice votes for bob with 10x voting power
+		assert_ok!(
+			Poc::commit(
+				Origin::signed(alice),
+				(100_000 as u64).into(),
+				crate::LockDuration::OneYear,
+				vec![bob],
+			)
+		);
+		assert_eq!(Poc::votes(bob), 1_000_000);
+
+		// defaults come from the config
+		let mut parameters = Poc::parameters();
+		assert_eq!(parameters.era_duration, 7 * HOURS);
+		assert_eq!(parameters.min_lock_amount, 100);
+		assert_eq!(parameters.one_year.voting_multiplier, 10);
+
+		// only the parameters origin can change them
+		parameters.era_duration = 14 * HOURS;
+		parameters.min_lock_amount = 1_000;
+		parameters.one_year.voting_multiplier = 20;
+		assert_err!(
+			Poc::set_parameters(Origin::signed(alice), parameters.clone()),
+			sp_runtime::traits::BadOrigin
+		);
+
+		// nonsensical values are rejected
+		let mut invalid = parameters.clone();
+		invalid.era_duration = 0;
+		assert_err!(
+			Poc::set_parameters(Origin::root(), invalid),
+			crate::Error::<Runtime>::InvalidParameters
+		);
+		let mut invalid = parameters.clone();
+		invalid.total_locked_cap = invalid.min_lock_amount;
+		assert_err!(
+			Poc::set_parameters(Origin::root(), invalid),
+			crate::Error::<Runtime>::InvalidParameters
+		);
+
+		// valid values are scheduled for the next era
+		assert_ok!(Poc::set_parameters(Origin::root(), parameters.clone()));
+		System::assert_last_event(Event::Poc(crate::Event::ParametersScheduled(1)));
+		assert_eq!(Poc::pending_parameters(), Some(parameters.clone()));
+		assert_eq!(Poc::parameters().era_duration, 7 * HOURS);
+		assert_eq!(Poc::votes(bob), 1_000_000);
+
+		// and take effect with it, reweighting the votes
+		run_blocks(7 * HOURS);
+		assert_eq!(Poc::current_era().index, 1);
+		assert_eq!(Poc::parameters(), parameters);
+		assert_eq!(Poc::pending_parameters(), None);
+		assert_eq!(Poc::votes(bob), 2_000_000);
+		assert_eq!(Poc::account_voting_weight(&alice), 2_000_000);
+		assert_ok!(Poc::do_try_state());
+
+		// only the committed part of an amount is weighed by the multipliers
+		assert_eq!(Poc::convicted_weight(&alice, 50_000), 1_000_000);
+		assert_eq!(Poc::convicted_weight(&alice, 150_000), 2_050_000);
+		assert_eq!(Poc::convicted_weight(&bob, 50_000), 50_000);
+
+		// the new minimum lock amount applies
+		assert_err!(
+			Poc::commit(
+				Origin::signed(bob),
+				(500 as u64).into(),
+				crate::LockDuration::OneMonth,
+				vec![bob],
+			),
+			crate::Error::<Runtime>::InsufficientAmount
+		);
+
+		// the era is now twice as long
+		run_blocks(7 * HOURS);
+		assert_eq!(Poc::current_era().index, 1);
+		run_blocks(7 * HOURS);
+		assert_eq!(Poc::current_era().index, 2);
+
+		// and its rewards are paid with the terms in force during it
+		assert_eq!(
+			Poc::era_reward_terms(1),
+			Some(crate::RewardTerms { era_duration: 14 * HOURS, nominator_apy: Perbill::from_percent(10) })
+		);
+		assert_ok!(Poc::do_try_state());
+	});
+}
+
+#[test]
+fn vote_tallies() {
+	new_test_ext().execute_with(|| {
+		let alice = 0 as u64;
+		let bob = 1 as u64;
+		let charlie = 2 as u64;
+
+		// alice votes for bob with 10x voting power
+		assert_ok!(
+			Poc::commit(
+				Origin::signed(alice),
+				(100_000 as u64).into(),
+				crate::LockDuration::OneYear,
+				vec![bob],
+			)
+		);
+		assert_eq!(Poc::votes(bob), 1_000_000);
+
+		// charlie votes for bob with 1x voting power
+		assert_ok!(
+			Poc::commit(
+				Origin::signed(charlie),
+				(100_000 as u64).into(),
+				crate::LockDuration::OneMonth,
+				vec![bob],
+			)
+		);
+		assert_eq!(Poc::votes(bob), 1_100_000);
+
+		// adding funds increases the tally
+		assert_ok!(Poc::add_funds(Origin::signed(alice), 0, (1_000 as u64).into()));
+		assert_eq!(Poc::votes(bob), 1_110_000);
+
+		// changing the vote moves the voting weight
+		assert_ok!(Poc::vote_candidates(Origin::signed(alice), 0, vec![charlie]));
+		assert_eq!(Poc::votes(bob), 100_000);
+		assert_eq!(Poc::votes(charlie), 1_010_000);
+
+		// unbonding removes the voting weight
+		assert_ok!(Poc::unbond(Origin::signed(charlie), 0));
+		assert_eq!(Poc::votes(bob), 0);
+		assert!(!crate::Votes::<Runtime>::contains_key(bob));
+
+		// re-committing through add_funds restores it
+		assert_ok!(Poc::add_funds(Origin::signed(charlie), 0, (1_000 as u64).into()));
+		assert_eq!(Poc::votes(bob), 101_000);
+		assert_ok!(Poc::do_try_state());
+
+		// tallies of non-candidates are kept and count once they apply
+		assert_ok!(Poc::start_candidacy(Origin::signed(bob)));
+		assert_ok!(Poc::start_candidacy(Origin::signed(charlie)));
+		assert_ok!(Poc::stop_candidacy(Origin::signed(charlie)));
+		assert_eq!(Poc::votes(charlie), 1_010_000);
+		run_blocks(7 * HOURS);
+		assert_eq!(Poc::members(), vec![bob]);
+
+		assert_ok!(Poc::start_candidacy(Origin::signed(charlie)));
+		run_blocks(7 * HOURS);
+		assert_eq!(Poc::members(), vec![bob, charlie]);
+		assert_ok!(Poc::do_try_state());
+	});
+}
+
+#[test]
+fn approvals() {
+	new_test_ext().execute_with(|| {
+		let alice = 0 as u64;
+		let bob = 1 as u64;
+		let charlie = 2 as u64;
+		let eve = 3 as u64;
+
+		// a commitment needs to approve somebody
+		assert_err!(
+			Poc::commit(
+				Origin::signed(alice),
+				(100_000 as u64).into(),
+				crate::LockDuration::OneYear,
+				vec![],
+			),
+			crate::Error::<Runtime>::NoVotes
+		);
+
+		// but no more than `MaxVotes` candidates
+		assert_err!(
+			Poc::commit(
+				Origin::signed(alice),
+				(100_000 as u64).into(),
+				crate::LockDuration::OneYear,
+				vec![alice, bob, charlie, eve],
+			),
+			crate::Error::<Runtime>::TooManyVotes
+		);
+
+		// every approved candidate gets the full voting weight, once
+		assert_ok!(
+			Poc::commit(
+				Origin::signed(alice),
+				(100_000 as u64).into(),
+				crate::LockDuration::OneYear,
+				vec![charlie, bob, charlie],
+			)
+		);
+		assert_eq!(Poc::commitments(alice, 0).candidates, vec![bob, charlie]);
+		assert_eq!(Poc::votes(bob), 1_000_000);
+		assert_eq!(Poc::votes(charlie), 1_000_000);
+
+		// changing the approvals moves the voting weight
+		assert_ok!(Poc::vote_candidates(Origin::signed(alice), 0, vec![charlie, eve]));
+		System::assert_last_event(Event::Poc(crate::Event::Voted(alice, 0, vec![charlie, eve], 1_000_000)));
+		assert_eq!(Poc::votes(bob), 0);
+		assert_eq!(Poc::votes(charlie), 1_000_000);
+		assert_eq!(Poc::votes(eve), 1_000_000);
+		assert_ok!(Poc::do_try_state());
+	});
+}
+
+#[test]
+fn phragmen_elections() {
+	new_test_ext().execute_with(|| {
+		let alice = 0 as u64;
+		let bob = 1 as u64;
+		let charlie = 2 as u64;
+		let eve = 3 as u64;
+
+		assert_ok!(Poc::start_candidacy(Origin::signed(alice)));
+		assert_ok!(Poc::start_candidacy(Origin::signed(bob)));
+		assert_ok!(Poc::start_candidacy(Origin::signed(charlie)));
+		assert_ok!(Poc::start_candidacy(Origin::signed(eve)));
+
+		// charlie backs three candidates with 30M voting power
+		assert_ok!(
+			Poc::commit(
+				Origin::signed(charlie),
+				(300_000 as u64).into(),
+				crate::LockDuration::TenYears,
+				vec![alice, bob, charlie],
+			)
+		);
+		assert_ok!(
+			Poc::commit(
+				Origin::signed(alice),
+				(100_000 as u64).into(),
+				crate::LockDuration::OneYear,
+				vec![alice],
+			)
+		);
+		assert_ok!(
+			Poc::commit(
+				Origin::signed(bob),
+				(100 as u64).into(),
+				crate::LockDuration::OneMonth,
+				vec![bob],
+			)
+		);
+		// eve backs herself with 16M voting power
+		assert_ok!(
+			Poc::commit(
+				Origin::signed(eve),
+				(160_000 as u64).into(),
+				crate::LockDuration::TenYears,
+				vec![eve],
+			)
+		);
+
+		// charlie's candidates have the highest tallies
+		assert_eq!(Poc::votes(alice), 31_000_000);
+		assert_eq!(Poc::votes(bob), 30_000_100);
+		assert_eq!(Poc::votes(charlie), 30_000_000);
+		assert_eq!(Poc::votes(eve), 16_000_000);
+
+		// but his voting power is split among the seats he wins
+		run_blocks(7 * HOURS);
+		assert_eq!(Poc::members(), vec![alice, bob, eve]);
+		assert_eq!(TechCouncil::members(), vec![alice, bob, eve]);
+		System::assert_has_event(Event::Poc(crate::Event::Elected(1, eve, 16_000_000)));
+		assert_ok!(Poc::do_try_state());
+	});
+}
+
+#[test]
+fn standings_and_rewards_queries() {
+	new_test_ext().execute_with(|| {
+		let alice = 0 as u64;
+		let bob = 1 as u64;
+		let charlie = 2 as u64;
+		let eve = 3 as u64;
+
+		assert_ok!(Poc::start_candidacy(Origin::signed(alice)));
+		assert_ok!(Poc::start_candidacy(Origin::signed(eve)));
+		assert_ok!(
+			Poc::commit(
+				Origin::signed(bob),
+				(100_000 as u64).into(),
+				crate::LockDuration::OneYear,
+				vec![alice],
+			)
+		);
+		assert_ok!(
+			Poc::commit(
+				Origin::signed(charlie),
+				(100_000 as u64).into(),
+				crate::LockDuration::OneMonth,
+				vec![eve],
+			)
+		);
+
+		// standings before the era change
+		assert_eq!(Poc::candidate_rankings(), vec![(alice, 1_000_000), (eve, 100_000)]);
+		assert_eq!(Poc::projected_members(), vec![(alice, 1_000_000), (eve, 100_000)]);
+		assert_eq!(Poc::account_voting_weight(&bob), 1_000_000);
+		assert_eq!(Poc::account_voting_weight(&eve), 0);
+
+		// rewards are pending once an era completes
+		assert_eq!(Poc::pending_rewards(&bob), 0);
+		run_blocks(2 * 7 * HOURS);
+		assert_eq!(Poc::pending_rewards(&bob), 8);
+		assert_eq!(Poc::pending_rewards(&charlie), 0);
+		assert_ok!(Poc::claim_rewards(Origin::signed(bob), 0, (1, 1), false));
+		assert_eq!(Poc::pending_rewards(&bob), 0);
+
+		// unbonding commitments report when they can be withdrawn
+		assert_eq!(Poc::unbonding_ends(&bob), vec![]);
+		let block = System::block_number();
+		assert_ok!(Poc::unbond(Origin::signed(bob), 0));
+		assert_eq!(Poc::unbonding_ends(&bob), vec![(0, block + 365 * DAYS as u64)]);
+		assert_eq!(Poc::account_voting_weight(&bob), 0);
+	});
+}
+
+#[test]
+fn commitments_are_bounded() {
+	new_test_ext().execute_with(|| {
+		let alice = 0 as u64;
+		let bob = 1 as u64;
+
+		// commitments can be made up to `MaxCommitments`
+		for _ in 0..MaxCommitments::get() {
+			assert_ok!(
+				Poc::commit(
+					Origin::signed(alice),
+					(100 as u64).into(),
+					crate::LockDuration::OneMonth,
+					vec![bob],
+				)
+			);
+		}
+		assert_eq!(Poc::n_commitments(), MaxCommitments::get());
+		assert_err!(
+			Poc::commit(
+				Origin::signed(bob),
+				(100 as u64).into(),
+				crate::LockDuration::OneMonth,
+				vec![bob],
+			),
+			crate::Error::<Runtime>::MaxCommitmentsReached
+		);
+
+		// withdrawing frees a slot
+		assert_ok!(Poc::unbond(Origin::signed(alice), 0));
+		skip_blocks(30 * DAYS);
+		assert_ok!(Poc::withdraw(Origin::signed(alice), 0));
+		assert_eq!(Poc::n_commitments(), MaxCommitments::get() - 1);
+		assert_ok!(
+			Poc::commit(
+				Origin::signed(bob),
+				(100 as u64).into(),
+				crate::LockDuration::OneMonth,
+				vec![bob],
+			)
+		);
+		assert_ok!(Poc::do_try_state());
+	});
+}
+
+#[test]
+fn vote_tallies_migration() {
+	use frame_support::traits::StorageVersion;
+
+	new_test_ext().execute_with(|| {
+		let alice = 0 as u64;
+		let bob = 1 as u64;
+
+		// simulate a chain from before the tallies existed
+		crate::migrations::v2::insert_commitment::<Runtime>(&alice, crate::migrations::CommitmentV3 {
+			state: crate::LockState::Committed,
+			duration: crate::LockDuration::TenYears,
+			amount: 100_000,
+			candidate: bob,
+		});
+		StorageVersion::new(0).put::<Poc>();
+
+		crate::migrations::migrate_to_v1::<Runtime>();
+		assert_eq!(Poc::votes(bob), 10_000_000);
+		assert_eq!(StorageVersion::get::<Poc>(), 1);
+	});
+}
+
+#[test]
+fn commitments_migration() {
+	use frame_support::traits::{Currency, OnRuntimeUpgrade, StorageVersion};
+
+	new_test_ext().execute_with(|| {
+		let alice = 0 as u64;
+		let bob = 1 as u64;
+
+		// simulate a chain with a single commitment per account, where committed funds were burnt
+		let _ = Balances::slash(&alice, 100_000);
+		crate::migrations::v2::insert_commitment::<Runtime>(&alice, crate::migrations::CommitmentV3 {
+			state: crate::LockState::Committed,
+			duration: crate::LockDuration::OneYear,
+			amount: 100_000,
+			candidate: bob,
+		});
+		crate::LockedAmount::<Runtime>::put(100_000);
+		StorageVersion::new(0).put::<Poc>();
+		assert_eq!(Balances::total_issuance(), 3_900_000 as u64);
+
+		Poc::on_runtime_upgrade();
+		assert_eq!(StorageVersion::get::<Poc>(), crate::STORAGE_VERSION);
+		assert_ok!(Poc::do_try_state());
+		assert_eq!(Poc::n_commitments(), 1);
+
+		// the commitment is moved to the first commitment id
+		assert_eq!(Poc::commitments(alice, 0).amount, 100_000 as u64);
+		assert_eq!(Poc::commitments(alice, 0).candidates, vec![bob]);
+		assert_eq!(Poc::next_commitment_id(alice), 1);
+		assert_eq!(Poc::votes(bob), 1_000_000 as u64);
+
+		// and starts earning claimable rewards from the next era
+		assert_eq!(Poc::commitments(alice, 0).since, 1);
+		assert_eq!(Poc::pending_rewardable_amount(), 100_000 as u64);
+
+		// the funds are re-credited and locked
+		assert_eq!(Balances::free_balance(&alice), 1_000_000 as u64);
+		assert_eq!(Balances::usable_balance(&alice), 900_000 as u64);
+		assert_eq!(Balances::total_issuance(), 4_000_000 as u64);
+
+		// and released on withdrawal
+		assert_ok!(Poc::unbond(Origin::signed(alice), 0));
+		skip_blocks(365 * DAYS);
+		assert_ok!(Poc::withdraw(Origin::signed(alice), 0));
+		assert_eq!(Balances::usable_balance(&alice), 1_000_000 as u64);
+		assert_eq!(Poc::locked_amount(), 0 as u64);
+	});
+}
+
+#[test]
+fn duration_tallies_migration() {
+	use codec::Encode;
+	use frame_support::{storage::migration, traits::StorageVersion, Blake2_128Concat, StorageHasher};
+
+	new_test_ext().execute_with(|| {
+		let alice = 0 as u64;
+		let bob = 1 as u64;
+
+		// simulate a chain where the tallies held voting weights and unbonding
+		// recorded its start
+		crate::Commitments::<Runtime>::insert(alice, 0, crate::Commitment {
+			duration: crate::LockDuration::OneYear,
+			amount: 100_000,
+			candidates: vec![bob],
+			..Default::default()
+		});
+		crate::Commitments::<Runtime>::insert(alice, 1, crate::Commitment {
+			state: crate::LockState::Unbonding(5),
+			duration: crate::LockDuration::OneMonth,
+			amount: 100_000,
+			candidates: vec![bob],
+			..Default::default()
+		});
+		migration::put_storage_value(b"Poc", b"Votes", &bob.using_encoded(Blake2_128Concat::hash), 1_000_000 as u64);
+		StorageVersion::new(6).put::<Poc>();
+
+		crate::migrations::migrate_to_v7::<Runtime>();
+		assert_eq!(StorageVersion::get::<Poc>(), 7);
+		assert_eq!(crate::Votes::<Runtime>::get(bob).one_year, 100_000);
+		assert_eq!(Poc::votes(bob), 1_000_000);
+		assert_eq!(Poc::unbonding_ends(&alice), vec![(1, 5 + 30 * DAYS as u64)]);
+	});
+}
+
+#[test]
+fn account_votes_migration() {
+	use frame_support::traits::StorageVersion;
+
+	new_test_ext().execute_with(|| {
+		let alice = 0 as u64;
+		let bob = 1 as u64;
+
+		// simulate a chain where the votes of an account were not tallied
+		crate::Commitments::<Runtime>::insert(alice, 0, crate::Commitment {
+			duration: crate::LockDuration::OneYear,
+			amount: 100_000,
+			candidates: vec![bob],
+			..Default::default()
+		});
+		crate::Commitments::<Runtime>::insert(alice, 1, crate::Commitment {
+			state: crate::LockState::Unbonding(5),
+			duration: crate::LockDuration::OneMonth,
+			amount: 100_000,
+			candidates: vec![bob],
+			..Default::default()
+		});
+		StorageVersion::new(7).put::<Poc>();
+		assert_eq!(Poc::account_voting_weight(&alice), 0);
+
+		// unbonding commitments do not vote
+		crate::migrations::migrate_to_v8::<Runtime>();
+		assert_eq!(StorageVersion::get::<Poc>(), 8);
+		assert_eq!(crate::AccountVotes::<Runtime>::get(alice).one_year, 100_000);
+		assert_eq!(Poc::account_voting_weight(&alice), 1_000_000);
+	});
+}
+
+#[test]
+fn voter_approvals_migration() {
+	use frame_support::traits::StorageVersion;
+
+	new_test_ext().execute_with(|| {
+		let alice = 0 as u64;
+		let bob = 1 as u64;
+		let charlie = 2 as u64;
+
+		// simulate a chain where the candidates approved by an account were not recorded
+		crate::Commitments::<Runtime>::insert(alice, 0, crate::Commitment {
+			duration: crate::LockDuration::OneYear,
+			amount: 100_000,
+			candidates: vec![bob, charlie],
+			..Default::default()
+		});
+		crate::Commitments::<Runtime>::insert(alice, 1, crate::Commitment {
+			duration: crate::LockDuration::OneMonth,
+			amount: 100_000,
+			candidates: vec![bob],
+			..Default::default()
+		});
+		crate::Commitments::<Runtime>::insert(alice, 2, crate::Commitment {
+			state: crate::LockState::Unbonding(5),
+			duration: crate::LockDuration::OneMonth,
+			amount: 100_000,
+			candidates: vec![alice],
+			..Default::default()
+		});
+		StorageVersion::new(8).put::<Poc>();
+
+		// unbonding commitments do not vote
+		crate::migrations::migrate_to_v9::<Runtime>();
+		assert_eq!(StorageVersion::get::<Poc>(), 9);
+		assert_eq!(crate::VoterApprovals::<Runtime>::get(alice), vec![(bob, 2), (charlie, 1)]);
+	});
+}
 
 fn run_blocks(n: u32) {
 	use frame_support::traits::OnInitialize;
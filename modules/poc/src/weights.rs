@@ -30,15 +30,19 @@ pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> crate::WeightInfo for WeightInfo<T> {
 	fn on_initialize_empty() -> Weight {
 		(19_557_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
-	fn on_initialize_era(c: u32, ) -> Weight {
+	fn on_initialize_era(c: u32, v: u32, ) -> Weight {
 		(21_538_473_000 as Weight)
 			// Standard Error: 27_000
 			.saturating_add((63_000 as Weight).saturating_mul(c as Weight))
-			.saturating_add(T::DbWeight::get().reads(2025 as Weight))
-			.saturating_add(T::DbWeight::get().writes(25 as Weight))
+			// Standard Error: 9_000
+			.saturating_add((187_000 as Weight).saturating_mul(v as Weight))
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(v as Weight)))
+			.saturating_add(T::DbWeight::get().writes(26 as Weight))
 	}
 	fn start_candidacy() -> Weight {
 		(66_356_000 as Weight)
@@ -46,34 +50,74 @@ impl<T: frame_system::Config> crate::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().writes(3 as Weight))
 	}
 	fn stop_candidacy() -> Weight {
-		(77_247_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(4 as Weight))
-			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+		(81_930_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
 	}
-	fn commit() -> Weight {
-		(65_845_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(3 as Weight))
-			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	fn resign() -> Weight {
+		(124_503_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(7 as Weight))
+	}
+	fn set_parameters() -> Weight {
+		(24_086_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn slash_member(c: u32, ) -> Weight {
+		(131_270_000 as Weight)
+			// Standard Error: 9_000
+			.saturating_add((61_418_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			.saturating_add(T::DbWeight::get().reads((9 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(T::DbWeight::get().writes(8 as Weight))
+			.saturating_add(T::DbWeight::get().writes((7 as Weight).saturating_mul(c as Weight)))
+	}
+	fn commit(v: u32, ) -> Weight {
+		(62_114_000 as Weight)
+			// Standard Error: 11_000
+			.saturating_add((3_861_000 as Weight).saturating_mul(v as Weight))
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(v as Weight)))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(v as Weight)))
 	}
 	fn add_funds() -> Weight {
 		(70_473_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(3 as Weight))
-			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
 	}
 	fn unbond() -> Weight {
 		(40_417_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(1 as Weight))
-			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
 	}
 	fn withdraw() -> Weight {
 		(68_720_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(3 as Weight))
-			.saturating_add(T::DbWeight::get().writes(3 as Weight))
-	}
-	fn vote_candidate() -> Weight {
-		(87_216_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	fn unbond_early() -> Weight {
+		(98_314_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			.saturating_add(T::DbWeight::get().writes(7 as Weight))
+	}
+	fn vote_candidates(v: u32, ) -> Weight {
+		(79_532_000 as Weight)
+			// Standard Error: 14_000
+			.saturating_add((7_642_000 as Weight).saturating_mul(v as Weight))
 			.saturating_add(T::DbWeight::get().reads(4 as Weight))
-			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(v as Weight)))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(v as Weight)))
+	}
+	fn claim_rewards(e: u32, ) -> Weight {
+		(45_000_000 as Weight)
+			.saturating_add((12_000_000 as Weight).saturating_mul(e as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().reads((3 as Weight).saturating_mul(e as Weight)))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(e as Weight)))
 	}
 }
 
@@ -81,15 +125,19 @@ use frame_support::weights::{constants::RocksDbWeight as DbWeight};
 impl crate::WeightInfo for () {
 	fn on_initialize_empty() -> Weight {
 		(19_557_000 as Weight)
-			.saturating_add(DbWeight::get().reads(2 as Weight))
+			.saturating_add(DbWeight::get().reads(3 as Weight))
 			.saturating_add(DbWeight::get().writes(1 as Weight))
 	}
-	fn on_initialize_era(c: u32, ) -> Weight {
+	fn on_initialize_era(c: u32, v: u32, ) -> Weight {
 		(21_538_473_000 as Weight)
 			// Standard Error: 27_000
 			.saturating_add((63_000 as Weight).saturating_mul(c as Weight))
-			.saturating_add(DbWeight::get().reads(2025 as Weight))
-			.saturating_add(DbWeight::get().writes(25 as Weight))
+			// Standard Error: 9_000
+			.saturating_add((187_000 as Weight).saturating_mul(v as Weight))
+			.saturating_add(DbWeight::get().reads(5 as Weight))
+			.saturating_add(DbWeight::get().reads((2 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(DbWeight::get().reads((1 as Weight).saturating_mul(v as Weight)))
+			.saturating_add(DbWeight::get().writes(26 as Weight))
 	}
 	fn start_candidacy() -> Weight {
 		(66_356_000 as Weight)
@@ -97,33 +145,73 @@ impl crate::WeightInfo for () {
 			.saturating_add(DbWeight::get().writes(3 as Weight))
 	}
 	fn stop_candidacy() -> Weight {
-		(77_247_000 as Weight)
-			.saturating_add(DbWeight::get().reads(4 as Weight))
-			.saturating_add(DbWeight::get().writes(3 as Weight))
+		(81_930_000 as Weight)
+			.saturating_add(DbWeight::get().reads(5 as Weight))
+			.saturating_add(DbWeight::get().writes(4 as Weight))
 	}
-	fn commit() -> Weight {
-		(65_845_000 as Weight)
-			.saturating_add(DbWeight::get().reads(3 as Weight))
-			.saturating_add(DbWeight::get().writes(3 as Weight))
+	fn resign() -> Weight {
+		(124_503_000 as Weight)
+			.saturating_add(DbWeight::get().reads(6 as Weight))
+			.saturating_add(DbWeight::get().writes(7 as Weight))
+	}
+	fn set_parameters() -> Weight {
+		(24_086_000 as Weight)
+			.saturating_add(DbWeight::get().reads(1 as Weight))
+			.saturating_add(DbWeight::get().writes(1 as Weight))
+	}
+	fn slash_member(c: u32, ) -> Weight {
+		(131_270_000 as Weight)
+			// Standard Error: 9_000
+			.saturating_add((61_418_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(DbWeight::get().reads(8 as Weight))
+			.saturating_add(DbWeight::get().reads((9 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(DbWeight::get().writes(8 as Weight))
+			.saturating_add(DbWeight::get().writes((7 as Weight).saturating_mul(c as Weight)))
+	}
+	fn commit(v: u32, ) -> Weight {
+		(62_114_000 as Weight)
+			// Standard Error: 11_000
+			.saturating_add((3_861_000 as Weight).saturating_mul(v as Weight))
+			.saturating_add(DbWeight::get().reads(6 as Weight))
+			.saturating_add(DbWeight::get().reads((1 as Weight).saturating_mul(v as Weight)))
+			.saturating_add(DbWeight::get().writes(5 as Weight))
+			.saturating_add(DbWeight::get().writes((1 as Weight).saturating_mul(v as Weight)))
 	}
 	fn add_funds() -> Weight {
 		(70_473_000 as Weight)
-			.saturating_add(DbWeight::get().reads(3 as Weight))
-			.saturating_add(DbWeight::get().writes(3 as Weight))
+			.saturating_add(DbWeight::get().reads(6 as Weight))
+			.saturating_add(DbWeight::get().writes(5 as Weight))
 	}
 	fn unbond() -> Weight {
 		(40_417_000 as Weight)
-			.saturating_add(DbWeight::get().reads(1 as Weight))
-			.saturating_add(DbWeight::get().writes(1 as Weight))
+			.saturating_add(DbWeight::get().reads(3 as Weight))
+			.saturating_add(DbWeight::get().writes(2 as Weight))
 	}
 	fn withdraw() -> Weight {
 		(68_720_000 as Weight)
-			.saturating_add(DbWeight::get().reads(3 as Weight))
-			.saturating_add(DbWeight::get().writes(3 as Weight))
-	}
-	fn vote_candidate() -> Weight {
-		(87_216_000 as Weight)
+			.saturating_add(DbWeight::get().reads(5 as Weight))
+			.saturating_add(DbWeight::get().writes(4 as Weight))
+	}
+	fn unbond_early() -> Weight {
+		(98_314_000 as Weight)
+			.saturating_add(DbWeight::get().reads(8 as Weight))
+			.saturating_add(DbWeight::get().writes(7 as Weight))
+	}
+	fn vote_candidates(v: u32, ) -> Weight {
+		(79_532_000 as Weight)
+			// Standard Error: 14_000
+			.saturating_add((7_642_000 as Weight).saturating_mul(v as Weight))
 			.saturating_add(DbWeight::get().reads(4 as Weight))
-			.saturating_add(DbWeight::get().writes(3 as Weight))
+			.saturating_add(DbWeight::get().reads((2 as Weight).saturating_mul(v as Weight)))
+			.saturating_add(DbWeight::get().writes(2 as Weight))
+			.saturating_add(DbWeight::get().writes((2 as Weight).saturating_mul(v as Weight)))
+	}
+	fn claim_rewards(e: u32, ) -> Weight {
+		(45_000_000 as Weight)
+			.saturating_add((12_000_000 as Weight).saturating_mul(e as Weight))
+			.saturating_add(DbWeight::get().reads(3 as Weight))
+			.saturating_add(DbWeight::get().reads((3 as Weight).saturating_mul(e as Weight)))
+			.saturating_add(DbWeight::get().writes(1 as Weight))
+			.saturating_add(DbWeight::get().writes((2 as Weight).saturating_mul(e as Weight)))
 	}
 }
@@ -3,7 +3,10 @@
 
 use codec::{Decode, Encode};
 use frame_support::pallet_prelude::Weight;
-use primitives::evm::{CallInfo, EvmAddress};
+use primitives::{
+	evm::{CallInfo, EvmAddress},
+	CurrencyId,
+};
 use sp_core::H160;
 use sp_runtime::{
 	traits::{AtLeast32BitUnsigned, MaybeSerializeDeserialize},
@@ -84,6 +87,121 @@ pub trait EVMBridge<AccountId, Balance> {
 	fn set_origin(origin: AccountId);
 }
 
+/// Metadata of a currency registered in the asset registry.
+#[derive(Encode, Decode, Eq, PartialEq, Clone, RuntimeDebug)]
+pub struct AssetMetadata<Balance> {
+	pub name: Vec<u8>,
+	pub symbol: Vec<u8>,
+	pub decimals: u8,
+	/// The existential deposit of the currency.
+	pub minimal_balance: Balance,
+}
+
+/// An abstraction of the asset registry
+pub trait AssetRegistry<Balance> {
+	/// Get the cached metadata of a registered currency.
+	fn asset_metadata(currency_id: CurrencyId) -> Option<AssetMetadata<Balance>>;
+}
+
+impl<Balance> AssetRegistry<Balance> for () {
+	fn asset_metadata(_currency_id: CurrencyId) -> Option<AssetMetadata<Balance>> {
+		None
+	}
+}
+
+/// Name, symbol and decimals of a currency.
+pub trait CurrencyMetadata {
+	fn name(currency_id: CurrencyId) -> Option<Vec<u8>>;
+	fn symbol(currency_id: CurrencyId) -> Option<Vec<u8>>;
+	fn decimals(currency_id: CurrencyId) -> Option<u8>;
+}
+
+/// An abstraction of the DEX
+pub trait DEXManager<AccountId, CurrencyId, Balance> {
+	/// Get the liquidity pool of `currency_id_a` and `currency_id_b`, in
+	/// the order they were passed in.
+	fn get_liquidity_pool(currency_id_a: CurrencyId, currency_id_b: CurrencyId) -> (Balance, Balance);
+
+	/// Quote the amount received for `supply_amount` along `path`.
+	fn get_swap_target_amount(
+		path: &[CurrencyId],
+		supply_amount: Balance,
+		price_impact_limit: Option<Ratio>,
+	) -> Option<Balance>;
+
+	/// Quote the amount to supply to receive `target_amount` along `path`.
+	fn get_swap_supply_amount(
+		path: &[CurrencyId],
+		target_amount: Balance,
+		price_impact_limit: Option<Ratio>,
+	) -> Option<Balance>;
+
+	/// Swap exactly `supply_amount` along `path`, returning the received
+	/// amount.
+	fn swap_with_exact_supply(
+		who: &AccountId,
+		path: &[CurrencyId],
+		supply_amount: Balance,
+		min_target_amount: Balance,
+		price_impact_limit: Option<Ratio>,
+	) -> Result<Balance, DispatchError>;
+
+	/// Swap along `path` to receive exactly `target_amount`, returning the
+	/// supplied amount.
+	fn swap_with_exact_target(
+		who: &AccountId,
+		path: &[CurrencyId],
+		target_amount: Balance,
+		max_supply_amount: Balance,
+		price_impact_limit: Option<Ratio>,
+	) -> Result<Balance, DispatchError>;
+}
+
+impl<AccountId, CurrencyId, Balance> DEXManager<AccountId, CurrencyId, Balance> for ()
+where
+	Balance: Default,
+{
+	fn get_liquidity_pool(_currency_id_a: CurrencyId, _currency_id_b: CurrencyId) -> (Balance, Balance) {
+		Default::default()
+	}
+
+	fn get_swap_target_amount(
+		_path: &[CurrencyId],
+		_supply_amount: Balance,
+		_price_impact_limit: Option<Ratio>,
+	) -> Option<Balance> {
+		None
+	}
+
+	fn get_swap_supply_amount(
+		_path: &[CurrencyId],
+		_target_amount: Balance,
+		_price_impact_limit: Option<Ratio>,
+	) -> Option<Balance> {
+		None
+	}
+
+	fn swap_with_exact_supply(
+		_who: &AccountId,
+		_path: &[CurrencyId],
+		_supply_amount: Balance,
+		_min_target_amount: Balance,
+		_price_impact_limit: Option<Ratio>,
+	) -> Result<Balance, DispatchError> {
+		Err(DispatchError::Other("DEX is not available"))
+	}
+
+	fn swap_with_exact_target(
+		_who: &AccountId,
+		_path: &[CurrencyId],
+		_target_amount: Balance,
+		_max_supply_amount: Balance,
+		_price_impact_limit: Option<Ratio>,
+	) -> Result<Balance, DispatchError> {
+		Err(DispatchError::Other("DEX is not available"))
+	}
+}
+
 /// An abstraction of EVMStateRentTrait
 pub trait EVMStateRentTrait<AccountId, Balance> {
 	/// Query the constants `NewContractExtraBytes` value from evm module.
@@ -110,6 +228,34 @@ pub trait TransactionPayment<AccountId, Balance, NegativeImbalance> {
 	fn refund_fee(who: &AccountId, weight: Weight, payed: NegativeImbalance) -> Result<(), TransactionValidityError>;
 }
 
+/// Pays the transaction fees of calls on behalf of their signers.
+pub trait FeeSponsor<AccountId, Call, Balance> {
+	/// The account paying the fees of `call` signed by `who`, if the call is
+	/// sponsored.
+	fn sponsor_of(who: &AccountId, call: &Call) -> Option<AccountId>;
+	/// Called once the sponsor of `call` signed by `who` has paid `fee`.
+	fn on_sponsored(who: &AccountId, call: &Call, fee: Balance);
+}
+
+impl<AccountId, Call, Balance> FeeSponsor<AccountId, Call, Balance> for () {
+	fn sponsor_of(_who: &AccountId, _call: &Call) -> Option<AccountId> {
+		None
+	}
+
+	fn on_sponsored(_who: &AccountId, _call: &Call, _fee: Balance) {}
+}
+
+/// Whether calls are paused, by the names of their pallet and function.
+pub trait TransactionPaused {
+	fn is_paused(pallet_name: &str, function_name: &str) -> bool;
+}
+
+impl TransactionPaused for () {
+	fn is_paused(_pallet_name: &str, _function_name: &str) -> bool {
+		false
+	}
+}
+
 #[cfg(feature = "std")]
 use frame_support::traits::Imbalance;
 #[cfg(feature = "std")]
@@ -0,0 +1,17 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::all)]
+
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	pub trait TransactionPauseApi {
+		/// Whether only the maintenance calls are allowed.
+		fn maintenance_mode() -> bool;
+
+		/// Names of the pallets with all their calls paused.
+		fn paused_pallets() -> Vec<Vec<u8>>;
+
+		/// Pallet and function names of the calls paused one by one.
+		fn paused_transactions() -> Vec<(Vec<u8>, Vec<u8>)>;
+	}
+}
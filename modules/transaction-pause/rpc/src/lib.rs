@@ -0,0 +1,85 @@
+//! RPC interface for the transaction pause module.
+
+use jsonrpc_core::{Error, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+use std::{marker::PhantomData, sync::Arc};
+
+pub use module_transaction_pause_rpc_runtime_api::TransactionPauseApi as TransactionPauseRuntimeApi;
+pub use rpc_impl_TransactionPauseApi::gen_server::TransactionPauseApi as TransactionPauseApiServer;
+
+/// Calls halted by the Tech Council.
+#[derive(Debug, Eq, PartialEq, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PausedTransactions {
+	/// Whether only the maintenance calls are allowed
+	pub maintenance_mode: bool,
+	/// Pallets with all their calls paused
+	pub pallets: Vec<String>,
+	/// Calls paused one by one, as (pallet, function)
+	pub calls: Vec<(String, String)>,
+}
+
+#[rpc(server)]
+pub trait TransactionPauseApi<BlockHash> {
+	/// Maintenance mode and the paused pallets and calls.
+	#[rpc(name = "transactionPause_pausedTransactions")]
+	fn paused_transactions(&self, at: Option<BlockHash>) -> Result<PausedTransactions>;
+}
+
+fn runtime_err<T: std::fmt::Debug>(err: T) -> Error {
+	Error {
+		code: ErrorCode::InternalError,
+		message: "Unable to query paused transactions.".into(),
+		data: Some(format!("{:?}", err).into()),
+	}
+}
+
+fn to_string(name: Vec<u8>) -> String {
+	String::from_utf8_lossy(&name).into_owned()
+}
+
+pub struct TransactionPause<C, B> {
+	client: Arc<C>,
+	_marker: PhantomData<B>,
+}
+
+impl<C, B> TransactionPause<C, B> {
+	pub fn new(client: Arc<C>) -> Self {
+		Self {
+			client,
+			_marker: Default::default(),
+		}
+	}
+}
+
+impl<C, Block> TransactionPauseApi<<Block as BlockT>::Hash> for TransactionPause<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: TransactionPauseRuntimeApi<Block>,
+{
+	fn paused_transactions(&self, at: Option<<Block as BlockT>::Hash>) -> Result<PausedTransactions> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		Ok(PausedTransactions {
+			maintenance_mode: api.maintenance_mode(&at).map_err(runtime_err)?,
+			pallets: api
+				.paused_pallets(&at)
+				.map_err(runtime_err)?
+				.into_iter()
+				.map(to_string)
+				.collect(),
+			calls: api
+				.paused_transactions(&at)
+				.map_err(runtime_err)?
+				.into_iter()
+				.map(|(pallet_name, function_name)| (to_string(pallet_name), to_string(function_name)))
+				.collect(),
+		})
+	}
+}
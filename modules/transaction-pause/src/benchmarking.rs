@@ -0,0 +1,43 @@
+#![cfg(feature = "runtime-benchmarks")]
+
+use crate::*;
+use frame_benchmarking::{benchmarks, impl_benchmark_test_suite};
+use frame_support::traits::EnsureOrigin;
+
+benchmarks! {
+	pause_transaction {
+		let origin = T::UpdateOrigin::successful_origin();
+
+	}: _<T::Origin>(origin, b"Balances".to_vec(), b"transfer".to_vec())
+
+	unpause_transaction {
+		let origin = T::UpdateOrigin::successful_origin();
+		<PausedTransactions<T>>::insert(b"Balances".to_vec(), b"transfer".to_vec(), ());
+
+	}: _<T::Origin>(origin, b"Balances".to_vec(), b"transfer".to_vec())
+
+	pause_pallet {
+		let origin = T::UpdateOrigin::successful_origin();
+
+	}: _<T::Origin>(origin, b"Balances".to_vec())
+
+	unpause_pallet {
+		let origin = T::UpdateOrigin::successful_origin();
+		<PausedPallets<T>>::insert(b"Balances".to_vec(), ());
+
+	}: _<T::Origin>(origin, b"Balances".to_vec())
+
+	enter_maintenance_mode {
+		let origin = T::UpdateOrigin::successful_origin();
+
+	}: _<T::Origin>(origin)
+
+	exit_maintenance_mode {
+		let origin = T::UpdateOrigin::successful_origin();
+		<MaintenanceMode<T>>::put(true);
+
+	}: _<T::Origin>(origin)
+}
+
+// auto-generate benchmark tests
+impl_benchmark_test_suite!(Pallet, mock::new_test_ext(), mock::Runtime);
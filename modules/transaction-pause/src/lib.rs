@@ -0,0 +1,256 @@
+//! # Transaction Pause
+//!
+//! Emergency switches to halt transactions during an incident, without
+//! upgrading the runtime.
+//!
+//! Single calls or whole pallets are paused by name. Maintenance mode pauses
+//! every call but the ones of the pallets the runtime keeps to produce blocks
+//! and govern. Neither those pallets nor this module are ever paused, so that
+//! blocks keep being produced and the calls can be resumed.
+//!
+//! `NonPausedTransactionFilter` is meant as the `BaseCallFilter` of the runtime.
+//! The EVM precompiles standing for pallet calls check the same switches
+//! through `TransactionPaused`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use frame_support::{
+	pallet_prelude::*,
+	traits::{CallMetadata, Contains, GetCallMetadata, PalletInfoAccess},
+	weights::Weight,
+};
+use frame_system::pallet_prelude::*;
+use sp_std::prelude::*;
+use support::TransactionPaused;
+
+mod benchmarking;
+mod mock;
+mod tests;
+pub mod weights;
+
+pub use module::*;
+
+pub trait WeightInfo {
+	fn pause_transaction() -> Weight;
+	fn unpause_transaction() -> Weight;
+	fn pause_pallet() -> Weight;
+	fn unpause_pallet() -> Weight;
+	fn enter_maintenance_mode() -> Weight;
+	fn exit_maintenance_mode() -> Weight;
+}
+
+#[frame_support::pallet]
+pub mod module {
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+		/// Origin allowed to pause and unpause transactions
+		type UpdateOrigin: EnsureOrigin<Self::Origin>;
+		/// Names of the pallets whose calls are still allowed in maintenance
+		/// mode, and which cannot be paused
+		type MaintenancePallets: Get<&'static [&'static str]>;
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The calls of this module and of the maintenance pallets cannot be
+		/// paused
+		CannotPause,
+		/// The pallet or function name is not valid UTF-8
+		InvalidCharacter,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Pallet name,Function name
+		TransactionPaused(Vec<u8>, Vec<u8>),
+		/// Pallet name,Function name
+		TransactionUnpaused(Vec<u8>, Vec<u8>),
+		/// Pallet name
+		PalletPaused(Vec<u8>),
+		/// Pallet name
+		PalletUnpaused(Vec<u8>),
+		MaintenanceModeEntered,
+		MaintenanceModeExited,
+	}
+
+	/// Paused calls, by pallet and function name.
+	#[pallet::storage]
+	pub type PausedTransactions<T: Config> = StorageDoubleMap<_,
+		Twox64Concat, Vec<u8>,
+		Twox64Concat, Vec<u8>,
+		(),
+		OptionQuery>;
+
+	/// Pallets with all their calls paused, by name.
+	#[pallet::storage]
+	pub type PausedPallets<T: Config> = StorageMap<_,
+		Twox64Concat, Vec<u8>, (),
+		OptionQuery>;
+
+	/// Whether only the calls of the `MaintenancePallets` are allowed.
+	#[pallet::storage]
+	#[pallet::getter(fn maintenance_mode)]
+	pub type MaintenanceMode<T: Config> = StorageValue<_,
+		bool, ValueQuery>;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(PhantomData<T>);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+
+		/// Pause a call of a pallet, by their names.
+		#[pallet::weight(T::WeightInfo::pause_transaction())]
+		pub fn pause_transaction(
+			origin: OriginFor<T>,
+			pallet_name: Vec<u8>,
+			function_name: Vec<u8>,
+		) -> DispatchResultWithPostInfo {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			Self::ensure_pausable(&pallet_name)?;
+			sp_std::str::from_utf8(&function_name).map_err(|_| Error::<T>::InvalidCharacter)?;
+
+			if !<PausedTransactions<T>>::contains_key(&pallet_name, &function_name) {
+				<PausedTransactions<T>>::insert(&pallet_name, &function_name, ());
+				Self::deposit_event(Event::TransactionPaused(pallet_name, function_name));
+			}
+			Ok(().into())
+		}
+
+		#[pallet::weight(T::WeightInfo::unpause_transaction())]
+		pub fn unpause_transaction(
+			origin: OriginFor<T>,
+			pallet_name: Vec<u8>,
+			function_name: Vec<u8>,
+		) -> DispatchResultWithPostInfo {
+			T::UpdateOrigin::ensure_origin(origin)?;
+
+			if <PausedTransactions<T>>::take(&pallet_name, &function_name).is_some() {
+				Self::deposit_event(Event::TransactionUnpaused(pallet_name, function_name));
+			}
+			Ok(().into())
+		}
+
+		/// Pause every call of a pallet, by its name.
+		#[pallet::weight(T::WeightInfo::pause_pallet())]
+		pub fn pause_pallet(origin: OriginFor<T>, pallet_name: Vec<u8>) -> DispatchResultWithPostInfo {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			Self::ensure_pausable(&pallet_name)?;
+
+			if !<PausedPallets<T>>::contains_key(&pallet_name) {
+				<PausedPallets<T>>::insert(&pallet_name, ());
+				Self::deposit_event(Event::PalletPaused(pallet_name));
+			}
+			Ok(().into())
+		}
+
+		/// Resume the calls of a pallet, except the ones paused one by one.
+		#[pallet::weight(T::WeightInfo::unpause_pallet())]
+		pub fn unpause_pallet(origin: OriginFor<T>, pallet_name: Vec<u8>) -> DispatchResultWithPostInfo {
+			T::UpdateOrigin::ensure_origin(origin)?;
+
+			if <PausedPallets<T>>::take(&pallet_name).is_some() {
+				Self::deposit_event(Event::PalletUnpaused(pallet_name));
+			}
+			Ok(().into())
+		}
+
+		/// Pause every call but the ones of the `MaintenancePallets`.
+		#[pallet::weight(T::WeightInfo::enter_maintenance_mode())]
+		pub fn enter_maintenance_mode(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			T::UpdateOrigin::ensure_origin(origin)?;
+
+			if !Self::maintenance_mode() {
+				<MaintenanceMode<T>>::put(true);
+				Self::deposit_event(Event::MaintenanceModeEntered);
+			}
+			Ok(().into())
+		}
+
+		#[pallet::weight(T::WeightInfo::exit_maintenance_mode())]
+		pub fn exit_maintenance_mode(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			T::UpdateOrigin::ensure_origin(origin)?;
+
+			if Self::maintenance_mode() {
+				<MaintenanceMode<T>>::put(false);
+				Self::deposit_event(Event::MaintenanceModeExited);
+			}
+			Ok(().into())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Whether `call` is paused, either by its name or by maintenance mode.
+	pub fn is_paused(call: &<T as frame_system::Config>::Call) -> bool
+	where
+		<T as frame_system::Config>::Call: GetCallMetadata,
+	{
+		let CallMetadata {
+			function_name,
+			pallet_name,
+		} = call.get_call_metadata();
+		Self::is_paused_by_name(pallet_name, function_name)
+	}
+
+	/// Whether the call `function_name` of the pallet `pallet_name` is paused.
+	pub fn is_paused_by_name(pallet_name: &str, function_name: &str) -> bool {
+		if !Self::is_pausable(pallet_name) {
+			return false;
+		}
+		if Self::maintenance_mode() {
+			return true;
+		}
+
+		<PausedPallets<T>>::contains_key(pallet_name.as_bytes())
+			|| <PausedTransactions<T>>::contains_key(pallet_name.as_bytes(), function_name.as_bytes())
+	}
+
+	/// Names of the pallets with all their calls paused.
+	pub fn paused_pallets() -> Vec<Vec<u8>> {
+		<PausedPallets<T>>::iter_keys().collect()
+	}
+
+	/// Pallet and function names of the calls paused one by one.
+	pub fn paused_transactions() -> Vec<(Vec<u8>, Vec<u8>)> {
+		<PausedTransactions<T>>::iter()
+			.map(|(pallet_name, function_name, _)| (pallet_name, function_name))
+			.collect()
+	}
+
+	fn ensure_pausable(pallet_name: &[u8]) -> DispatchResult {
+		let pallet_name = sp_std::str::from_utf8(pallet_name).map_err(|_| Error::<T>::InvalidCharacter)?;
+		ensure!(Self::is_pausable(pallet_name), Error::<T>::CannotPause);
+		Ok(())
+	}
+
+	fn is_pausable(pallet_name: &str) -> bool {
+		pallet_name != <Self as PalletInfoAccess>::name() && !T::MaintenancePallets::get().contains(&pallet_name)
+	}
+}
+
+impl<T: Config> TransactionPaused for Pallet<T> {
+	fn is_paused(pallet_name: &str, function_name: &str) -> bool {
+		Self::is_paused_by_name(pallet_name, function_name)
+	}
+}
+
+/// Calls which are not paused.
+pub struct NonPausedTransactionFilter<T>(PhantomData<T>);
+impl<T: Config> Contains<<T as frame_system::Config>::Call> for NonPausedTransactionFilter<T>
+where
+	<T as frame_system::Config>::Call: GetCallMetadata,
+{
+	fn contains(call: &<T as frame_system::Config>::Call) -> bool {
+		!Pallet::<T>::is_paused(call)
+	}
+}
@@ -0,0 +1,144 @@
+#![cfg(test)]
+
+use crate::mock::*;
+use crate::{Error, NonPausedTransactionFilter};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{Contains, OriginTrait},
+};
+use sp_runtime::traits::BadOrigin;
+use support::TransactionPaused;
+
+const TRANSFER: &Call = &Call::Balances(pallet_balances::Call::transfer(BOB, 100));
+const TRANSFER_KEEP_ALIVE: &Call = &Call::Balances(pallet_balances::Call::transfer_keep_alive(BOB, 100));
+const SET_BALANCE: &Call = &Call::Balances(pallet_balances::Call::set_balance(BOB, 100, 0));
+const REMARK: &Call = &Call::System(frame_system::Call::remark(Vec::new()));
+
+#[test]
+fn pauses_transactions() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			TransactionPause::pause_transaction(Origin::signed(ALICE), b"Balances".to_vec(), b"transfer".to_vec()),
+			BadOrigin
+		);
+
+		assert!(NonPausedTransactionFilter::<Runtime>::contains(TRANSFER));
+		assert_ok!(TransactionPause::pause_transaction(
+			Origin::root(),
+			b"Balances".to_vec(),
+			b"transfer".to_vec()
+		));
+		System::assert_last_event(Event::TransactionPause(crate::Event::TransactionPaused(
+			b"Balances".to_vec(),
+			b"transfer".to_vec(),
+		)));
+		assert!(!NonPausedTransactionFilter::<Runtime>::contains(TRANSFER));
+		assert!(NonPausedTransactionFilter::<Runtime>::contains(TRANSFER_KEEP_ALIVE));
+		// as the EVM precompiles check it
+		assert!(<TransactionPause as TransactionPaused>::is_paused("Balances", "transfer"));
+		assert_eq!(
+			TransactionPause::paused_transactions(),
+			vec![(b"Balances".to_vec(), b"transfer".to_vec())]
+		);
+
+		assert_ok!(TransactionPause::unpause_transaction(
+			Origin::root(),
+			b"Balances".to_vec(),
+			b"transfer".to_vec()
+		));
+		System::assert_last_event(Event::TransactionPause(crate::Event::TransactionUnpaused(
+			b"Balances".to_vec(),
+			b"transfer".to_vec(),
+		)));
+		assert!(NonPausedTransactionFilter::<Runtime>::contains(TRANSFER));
+		assert!(TransactionPause::paused_transactions().is_empty());
+	});
+}
+
+#[test]
+fn pauses_pallets() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TransactionPause::pause_pallet(Origin::root(), b"Balances".to_vec()));
+		System::assert_last_event(Event::TransactionPause(crate::Event::PalletPaused(
+			b"Balances".to_vec(),
+		)));
+		assert!(!NonPausedTransactionFilter::<Runtime>::contains(TRANSFER));
+		assert!(!NonPausedTransactionFilter::<Runtime>::contains(TRANSFER_KEEP_ALIVE));
+		assert!(NonPausedTransactionFilter::<Runtime>::contains(REMARK));
+		assert_eq!(TransactionPause::paused_pallets(), vec![b"Balances".to_vec()]);
+
+		// calls paused one by one stay paused
+		assert_ok!(TransactionPause::pause_transaction(
+			Origin::root(),
+			b"Balances".to_vec(),
+			b"transfer".to_vec()
+		));
+		assert_ok!(TransactionPause::unpause_pallet(Origin::root(), b"Balances".to_vec()));
+		System::assert_last_event(Event::TransactionPause(crate::Event::PalletUnpaused(
+			b"Balances".to_vec(),
+		)));
+		assert!(!NonPausedTransactionFilter::<Runtime>::contains(TRANSFER));
+		assert!(NonPausedTransactionFilter::<Runtime>::contains(TRANSFER_KEEP_ALIVE));
+	});
+}
+
+#[test]
+fn cannot_pause_itself_or_maintenance_pallets() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			TransactionPause::pause_pallet(Origin::root(), b"TransactionPause".to_vec()),
+			Error::<Runtime>::CannotPause
+		);
+		assert_noop!(
+			TransactionPause::pause_transaction(
+				Origin::root(),
+				b"TransactionPause".to_vec(),
+				b"exit_maintenance_mode".to_vec()
+			),
+			Error::<Runtime>::CannotPause
+		);
+
+		// pausing the maintenance pallets could stop block production
+		assert_noop!(
+			TransactionPause::pause_pallet(Origin::root(), b"System".to_vec()),
+			Error::<Runtime>::CannotPause
+		);
+		assert_noop!(
+			TransactionPause::pause_transaction(Origin::root(), b"System".to_vec(), b"remark".to_vec()),
+			Error::<Runtime>::CannotPause
+		);
+		assert!(NonPausedTransactionFilter::<Runtime>::contains(REMARK));
+
+		assert_noop!(
+			TransactionPause::pause_transaction(Origin::root(), b"Balances".to_vec(), vec![0xff]),
+			Error::<Runtime>::InvalidCharacter
+		);
+	});
+}
+
+#[test]
+fn maintenance_mode_only_allows_maintenance_calls() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			TransactionPause::enter_maintenance_mode(Origin::signed(ALICE)),
+			BadOrigin
+		);
+
+		assert_ok!(TransactionPause::enter_maintenance_mode(Origin::root()));
+		System::assert_last_event(Event::TransactionPause(crate::Event::MaintenanceModeEntered));
+		assert!(TransactionPause::maintenance_mode());
+		assert!(!NonPausedTransactionFilter::<Runtime>::contains(TRANSFER));
+		assert!(!NonPausedTransactionFilter::<Runtime>::contains(SET_BALANCE));
+		assert!(NonPausedTransactionFilter::<Runtime>::contains(REMARK));
+		assert!(NonPausedTransactionFilter::<Runtime>::contains(
+			&Call::TransactionPause(crate::Call::exit_maintenance_mode())
+		));
+
+		// paused calls are filtered from dispatch
+		assert!(!Origin::signed(ALICE).filter_call(TRANSFER));
+
+		assert_ok!(TransactionPause::exit_maintenance_mode(Origin::root()));
+		System::assert_last_event(Event::TransactionPause(crate::Event::MaintenanceModeExited));
+		assert!(NonPausedTransactionFilter::<Runtime>::contains(TRANSFER));
+	});
+}
@@ -0,0 +1,29 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::all)]
+
+use codec::Codec;
+use primitives::CurrencyId;
+use sp_runtime::Perbill;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	pub trait MultiCurrencyFeeApi<AccountId, Balance> where
+		AccountId: Codec,
+		Balance: Codec,
+	{
+		/// The fee of `uxt` signed by `who` in every currency it could be paid with, in the order
+		/// they are tried, and the currency that would actually be charged.
+		fn query_fee_in_currencies(
+			uxt: Block::Extrinsic,
+			len: u32,
+			who: AccountId,
+		) -> (Vec<(CurrencyId, Balance)>, Option<CurrencyId>);
+	}
+
+	pub trait FeeDiscountApi<AccountId> where
+		AccountId: Codec,
+	{
+		/// The share of the transaction fees currently waived for `who`.
+		fn fee_discount(who: AccountId) -> Perbill;
+	}
+}
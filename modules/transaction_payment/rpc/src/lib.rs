@@ -0,0 +1,157 @@
+//! RPC interface for the transaction payment module.
+
+use codec::{Codec, Decode};
+use jsonrpc_core::{Error, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+use primitives::CurrencyId;
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::Bytes;
+use sp_rpc::number::NumberOrHex;
+use sp_runtime::{
+	generic::BlockId,
+	traits::{Block as BlockT, MaybeDisplay},
+	Perbill,
+};
+use std::{convert::TryInto, marker::PhantomData, sync::Arc};
+
+pub use module_transaction_payment_rpc_runtime_api::FeeDiscountApi as FeeDiscountRuntimeApi;
+pub use module_transaction_payment_rpc_runtime_api::MultiCurrencyFeeApi as MultiCurrencyFeeRuntimeApi;
+pub use rpc_impl_FeeDiscountApi::gen_server::FeeDiscountApi as FeeDiscountApiServer;
+pub use rpc_impl_MultiCurrencyFeeApi::gen_server::MultiCurrencyFeeApi as MultiCurrencyFeeApiServer;
+
+/// The fee of a transaction in the currencies it could be paid with.
+#[derive(Debug, Eq, PartialEq, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MultiCurrencyFee {
+	/// Fee in every currency the transaction could be paid with, in the order
+	/// they are tried
+	pub fees: Vec<(CurrencyId, NumberOrHex)>,
+	/// Currency that would actually be charged, if any can pay the fee
+	pub charged_currency_id: Option<CurrencyId>,
+}
+
+/// Transaction fees in the currencies of the payer.
+#[rpc(server)]
+pub trait MultiCurrencyFeeApi<BlockHash, AccountId> {
+	/// The fee of an encoded extrinsic signed by `who` in every currency it
+	/// could be paid with, and the currency that would actually be charged.
+	#[rpc(name = "payment_queryFeeInCurrencies")]
+	fn query_fee_in_currencies(
+		&self,
+		encoded_xt: Bytes,
+		who: AccountId,
+		at: Option<BlockHash>,
+	) -> Result<MultiCurrencyFee>;
+}
+
+/// Transaction fee discounts.
+#[rpc(server)]
+pub trait FeeDiscountApi<BlockHash, AccountId> {
+	/// The share of the transaction fees currently waived for `who`.
+	#[rpc(name = "payment_feeDiscount")]
+	fn fee_discount(&self, who: AccountId, at: Option<BlockHash>) -> Result<Perbill>;
+}
+
+fn runtime_err<T: std::fmt::Debug>(err: T) -> Error {
+	Error {
+		code: ErrorCode::InternalError,
+		message: "Unable to query the fee in currencies.".into(),
+		data: Some(format!("{:?}", err).into()),
+	}
+}
+
+fn to_number<Balance: TryInto<NumberOrHex> + MaybeDisplay + Copy>(balance: Balance) -> Result<NumberOrHex> {
+	balance.try_into().map_err(|_| Error {
+		code: ErrorCode::InvalidParams,
+		message: format!("{} doesn't fit in NumberOrHex representation", balance),
+		data: None,
+	})
+}
+
+pub struct MultiCurrencyFeeQuery<C, B, Balance> {
+	client: Arc<C>,
+	_marker: PhantomData<(B, Balance)>,
+}
+
+impl<C, B, Balance> MultiCurrencyFeeQuery<C, B, Balance> {
+	pub fn new(client: Arc<C>) -> Self {
+		Self {
+			client,
+			_marker: Default::default(),
+		}
+	}
+}
+
+impl<C, Block, AccountId, Balance> MultiCurrencyFeeApi<<Block as BlockT>::Hash, AccountId>
+	for MultiCurrencyFeeQuery<C, Block, Balance>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: MultiCurrencyFeeRuntimeApi<Block, AccountId, Balance>,
+	AccountId: Codec,
+	Balance: Codec + MaybeDisplay + Copy + TryInto<NumberOrHex> + Send + Sync + 'static,
+{
+	fn query_fee_in_currencies(
+		&self,
+		encoded_xt: Bytes,
+		who: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<MultiCurrencyFee> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		let encoded_len = encoded_xt.len() as u32;
+		let uxt: Block::Extrinsic = Decode::decode(&mut &*encoded_xt).map_err(|e| Error {
+			code: ErrorCode::InvalidParams,
+			message: "Unable to decode the extrinsic.".into(),
+			data: Some(format!("{:?}", e).into()),
+		})?;
+
+		let (fees, charged_currency_id) = api
+			.query_fee_in_currencies(&at, uxt, encoded_len, who)
+			.map_err(runtime_err)?;
+
+		Ok(MultiCurrencyFee {
+			fees: fees
+				.into_iter()
+				.map(|(currency_id, fee)| Ok((currency_id, to_number(fee)?)))
+				.collect::<Result<_>>()?,
+			charged_currency_id,
+		})
+	}
+}
+
+pub struct FeeDiscountQuery<C, B> {
+	client: Arc<C>,
+	_marker: PhantomData<B>,
+}
+
+impl<C, B> FeeDiscountQuery<C, B> {
+	pub fn new(client: Arc<C>) -> Self {
+		Self {
+			client,
+			_marker: Default::default(),
+		}
+	}
+}
+
+impl<C, Block, AccountId> FeeDiscountApi<<Block as BlockT>::Hash, AccountId> for FeeDiscountQuery<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: FeeDiscountRuntimeApi<Block, AccountId>,
+	AccountId: Codec,
+{
+	fn fee_discount(&self, who: AccountId, at: Option<<Block as BlockT>::Hash>) -> Result<Perbill> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		api.fee_discount(&at, who).map_err(|e| Error {
+			code: ErrorCode::InternalError,
+			message: "Unable to query the fee discount.".into(),
+			data: Some(format!("{:?}", e).into()),
+		})
+	}
+}
@@ -10,7 +10,7 @@ impl crate::WeightInfo for () {
 	fn on_finalize() -> Weight {
 		(39_708_000 as Weight)
 			.saturating_add(DbWeight::get().reads(2 as Weight))
-			.saturating_add(DbWeight::get().writes(1 as Weight))
+			.saturating_add(DbWeight::get().writes(2 as Weight))
 	}
 
 	fn set_default_fee_token() -> Weight {
@@ -11,7 +11,9 @@
 use frame_support::{
 	dispatch::{DispatchResult, Dispatchable},
 	pallet_prelude::*,
-	traits::{Currency, ExistenceRequirement, Imbalance, OnUnbalanced, ReservableCurrency, WithdrawReasons},
+	traits::{
+		Currency, EstimateCallFee, ExistenceRequirement, Imbalance, OnUnbalanced, ReservableCurrency, WithdrawReasons,
+	},
 	weights::{DispatchInfo, GetDispatchInfo, Pays, PostDispatchInfo, WeightToFeePolynomial},
 };
 use frame_system::pallet_prelude::*;
@@ -22,15 +24,15 @@ use primitives::{Balance, CurrencyId};
 use sp_runtime::{
 	traits::{
 		CheckedSub, Convert, DispatchInfoOf, PostDispatchInfoOf, SaturatedConversion, Saturating, SignedExtension,
-		Zero,
+		UniqueSaturatedInto, Zero,
 	},
 	transaction_validity::{
 		InvalidTransaction, TransactionPriority, TransactionValidity, TransactionValidityError, ValidTransaction,
 	},
-	FixedPointNumber, FixedPointOperand, FixedU128, Perquintill,
+	FixedPointNumber, FixedPointOperand, FixedU128, Perbill, Perquintill,
 };
 use sp_std::{prelude::*, vec};
-use support::{TransactionPayment};
+use support::{DEXManager, FeeSponsor, Ratio, TransactionPayment};
 use frame_support::traits::SameOrOther;
 
 mod default_weight;
@@ -47,6 +49,18 @@ pub trait WeightInfo {
 /// Fee multiplier.
 pub type Multiplier = FixedU128;
 
+/// Discounts the transaction fees of accounts.
+pub trait FeeDiscount<AccountId> {
+	/// The share of its transaction fees `who` is discounted.
+	fn fee_discount(who: &AccountId) -> Perbill;
+}
+
+impl<AccountId> FeeDiscount<AccountId> for () {
+	fn fee_discount(_who: &AccountId) -> Perbill {
+		Perbill::zero()
+	}
+}
+
 type PalletBalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 type NegativeImbalanceOf<T> =
 	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::NegativeImbalance;
@@ -238,6 +252,24 @@ pub mod module {
 		/// block's weight.
 		type FeeMultiplierUpdate: MultiplierUpdate;
 
+		/// DEX to swap non-native currencies into the native currency to pay
+		/// fees with.
+		type DEX: DEXManager<Self::AccountId, CurrencyId, Balance>;
+
+		/// The max slippage allowed when swapping to pay fees with DEX.
+		#[pallet::constant]
+		type MaxSlippageSwapWithDEX: Get<Ratio>;
+
+		/// Pays the fees of sponsored calls instead of their signers.
+		type FeeSponsor: FeeSponsor<Self::AccountId, <Self as frame_system::Config>::Call, PalletBalanceOf<Self>>;
+
+		/// Discounts the fees of accounts, tips excluded.
+		type FeeDiscount: FeeDiscount<Self::AccountId>;
+
+		/// The max total fee discount granted in a block.
+		#[pallet::constant]
+		type MaxFeeDiscountPerBlock: Get<PalletBalanceOf<Self>>;
+
 		/// Weight information for the extrinsics in this module.
 		type WeightInfo: WeightInfo;
 	}
@@ -251,6 +283,11 @@ pub mod module {
 	#[pallet::getter(fn next_fee_multiplier)]
 	pub type NextFeeMultiplier<T: Config> = StorageValue<_, Multiplier, ValueQuery, DefaultFeeMultiplier>;
 
+	/// Total fee discount granted in the current block.
+	#[pallet::storage]
+	#[pallet::getter(fn block_fee_discount)]
+	pub type BlockFeeDiscount<T: Config> = StorageValue<_, PalletBalanceOf<T>, ValueQuery>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn default_fee_currency_id)]
 	pub type DefaultFeeCurrencyId<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, CurrencyId, OptionQuery>;
@@ -269,6 +306,7 @@ pub mod module {
 			NextFeeMultiplier::<T>::mutate(|fm| {
 				*fm = T::FeeMultiplierUpdate::convert(*fm);
 			});
+			BlockFeeDiscount::<T>::kill();
 		}
 
 		#[cfg(feature = "std")]
@@ -347,9 +385,12 @@ where
 	///
 	/// All dispatchables must be annotated with weight and will have some
 	/// fee info. This function always returns.
+	///
+	/// The fee is discounted for the signer `who` of the extrinsic, if any.
 	pub fn query_info<Extrinsic: GetDispatchInfo>(
 		unchecked_extrinsic: Extrinsic,
 		len: u32,
+		who: Option<&T::AccountId>,
 	) -> RuntimeDispatchInfo<PalletBalanceOf<T>>
 	where
 		T: Send + Sync,
@@ -364,7 +405,10 @@ where
 		// potential gain in the future.
 		let dispatch_info = <Extrinsic as GetDispatchInfo>::get_dispatch_info(&unchecked_extrinsic);
 
-		let partial_fee = Self::compute_fee(len, &dispatch_info, 0u32.into());
+		let partial_fee = match who {
+			Some(who) => Self::compute_discounted_fee(len, &dispatch_info, 0u32.into(), who),
+			None => Self::compute_fee(len, &dispatch_info, 0u32.into()),
+		};
 		let DispatchInfo { weight, class, .. } = dispatch_info;
 
 		RuntimeDispatchInfo {
@@ -513,7 +557,94 @@ where
 		T::WeightToFee::calc(&capped_weight)
 	}
 
-	pub fn ensure_can_charge_fee(who: &T::AccountId, fee: PalletBalanceOf<T>, reason: WithdrawReasons) {
+	/// The discount of a fee of `who`, tip excluded, within the discount
+	/// budget left in the block.
+	pub fn fee_discount(who: &T::AccountId, fee: PalletBalanceOf<T>) -> PalletBalanceOf<T> {
+		let budget = T::MaxFeeDiscountPerBlock::get().saturating_sub(Self::block_fee_discount());
+		(T::FeeDiscount::fee_discount(who) * fee).min(budget)
+	}
+
+	/// Compute the fee `who` pays for a transaction, its discount deducted.
+	/// The tip is not discounted.
+	pub fn compute_discounted_fee(
+		len: u32,
+		info: &DispatchInfoOf<T::Call>,
+		tip: PalletBalanceOf<T>,
+		who: &T::AccountId,
+	) -> PalletBalanceOf<T>
+	where
+		T::Call: Dispatchable<Info = DispatchInfo>,
+	{
+		let fee = Self::compute_fee(len, info, tip);
+		fee.saturating_sub(Self::fee_discount(who, fee.saturating_sub(tip)))
+	}
+
+	/// Query the share of its fees `who` is discounted.
+	pub fn query_fee_discount(who: &T::AccountId) -> Perbill {
+		T::FeeDiscount::fee_discount(who)
+	}
+
+	/// Query the fee of an extrinsic signed by `who` in every currency it could
+	/// be paid with, in the order `ensure_can_charge_fee` tries them, along
+	/// with the currency that would actually be charged, if any.
+	///
+	/// A non-native fee is the amount swapped into the native fee with DEX.
+	pub fn query_fee_in_currencies<Extrinsic: GetDispatchInfo>(
+		unchecked_extrinsic: Extrinsic,
+		len: u32,
+		who: &T::AccountId,
+	) -> (Vec<(CurrencyId, Balance)>, Option<CurrencyId>)
+	where
+		T::Call: Dispatchable<Info = DispatchInfo>,
+	{
+		let dispatch_info = <Extrinsic as GetDispatchInfo>::get_dispatch_info(&unchecked_extrinsic);
+		let fee = Self::compute_discounted_fee(len, &dispatch_info, Zero::zero(), who);
+
+		let native_currency_id = T::NativeCurrencyId::get();
+		let price_impact_limit = Some(T::MaxSlippageSwapWithDEX::get());
+		let shortfall = Self::native_fee_shortfall(who, fee);
+
+		let mut fees = vec![];
+		let mut charged_currency_id = None;
+		for currency_id in Self::fee_currency_order(who) {
+			if currency_id == native_currency_id {
+				fees.push((currency_id, fee.unique_saturated_into()));
+				if charged_currency_id.is_none()
+					&& Self::native_is_enough(who, fee, WithdrawReasons::TRANSACTION_PAYMENT)
+				{
+					charged_currency_id = Some(currency_id);
+				}
+			} else {
+				let trading_path = Self::fee_trading_path(currency_id);
+				if let Some(amount) =
+					T::DEX::get_swap_supply_amount(&trading_path, fee.unique_saturated_into(), price_impact_limit)
+				{
+					fees.push((currency_id, amount));
+				}
+
+				// `ensure_can_charge_fee` swaps the shortfall only
+				if charged_currency_id.is_none() && !shortfall.is_zero() {
+					let can_swap = T::DEX::get_swap_supply_amount(
+						&trading_path,
+						shortfall.unique_saturated_into(),
+						price_impact_limit,
+					)
+					.map_or(false, |supply_amount| {
+						supply_amount <= <T as Config>::MultiCurrency::free_balance(currency_id, who)
+					});
+					if can_swap {
+						charged_currency_id = Some(currency_id);
+					}
+				}
+			}
+		}
+
+		(fees, charged_currency_id)
+	}
+
+	/// The currencies fees of `who` are paid with, in the order they are
+	/// tried.
+	fn fee_currency_order(who: &T::AccountId) -> Vec<CurrencyId> {
 		let native_currency_id = T::NativeCurrencyId::get();
 		let other_currency_ids = T::AllNonNativeCurrencyIds::get();
 		let mut charge_fee_order: Vec<CurrencyId> =
@@ -523,43 +654,66 @@ where
 				vec![vec![native_currency_id], other_currency_ids].concat()
 			};
 		charge_fee_order.dedup();
+		charge_fee_order
+	}
 
-		// let price_impact_limit = Some(T::MaxSlippageSwapWithDEX::get());
+	/// The DEX path swapping `currency_id` into the native currency.
+	fn fee_trading_path(currency_id: CurrencyId) -> Vec<CurrencyId> {
+		let native_currency_id = T::NativeCurrencyId::get();
+		let stable_currency_id = T::StableCurrencyId::get();
+		if currency_id == stable_currency_id {
+			vec![stable_currency_id, native_currency_id]
+		} else {
+			vec![currency_id, stable_currency_id, native_currency_id]
+		}
+	}
+
+	fn native_is_enough(who: &T::AccountId, fee: PalletBalanceOf<T>, reason: WithdrawReasons) -> bool {
+		<T as Config>::Currency::free_balance(who)
+			.checked_sub(&fee)
+			.map_or(false, |new_free_balance| {
+				<T as Config>::Currency::ensure_can_withdraw(who, fee, reason, new_free_balance).is_ok()
+			})
+	}
+
+	/// The native amount `who` lacks to pay `fee`, keeping the existential
+	/// deposit so the fee can be withdrawn without reaping the account.
+	fn native_fee_shortfall(who: &T::AccountId, fee: PalletBalanceOf<T>) -> PalletBalanceOf<T> {
+		fee.saturating_add(<T as Config>::Currency::minimum_balance())
+			.saturating_sub(<T as Config>::Currency::free_balance(who))
+	}
+
+	pub fn ensure_can_charge_fee(who: &T::AccountId, fee: PalletBalanceOf<T>, reason: WithdrawReasons) {
+		let native_currency_id = T::NativeCurrencyId::get();
+		let price_impact_limit = Some(T::MaxSlippageSwapWithDEX::get());
 
 		// iterator charge fee order to get enough fee
-		for currency_id in charge_fee_order {
+		for currency_id in Self::fee_currency_order(who) {
 			if currency_id == native_currency_id {
 				// check native balance if is enough
-				let native_is_enough =
-					<T as Config>::Currency::free_balance(who)
-						.checked_sub(&fee)
-						.map_or(false, |new_free_balance| {
-							<T as Config>::Currency::ensure_can_withdraw(who, fee, reason, new_free_balance).is_ok()
-						});
-				if native_is_enough {
+				if Self::native_is_enough(who, fee, reason) {
 					// native balance is enough, break iteration
 					break;
 				}
 			} else {
-				// // try to use non-native currency to swap native currency by exchange with DEX
-				// let trading_path = if currency_id == stable_currency_id {
-				// 	vec![stable_currency_id, native_currency_id]
-				// } else {
-				// 	vec![currency_id, stable_currency_id, native_currency_id]
-				// };
-                //
-				// if T::DEX::swap_with_exact_target(
-				// 	who,
-				// 	&trading_path,
-				// 	fee.unique_saturated_into(),
-				// 	<T as Config>::MultiCurrency::free_balance(currency_id, who),
-				// 	price_impact_limit,
-				// )
-				// .is_ok()
-				// {
-				// 	// successfully swap, break iteration
-				// 	break;
-				// }
+				// try to use non-native currency to swap native currency by exchange with DEX
+				let trading_path = Self::fee_trading_path(currency_id);
+
+				// swap for the shortfall
+				let shortfall = Self::native_fee_shortfall(who, fee);
+
+				if T::DEX::swap_with_exact_target(
+					who,
+					&trading_path,
+					shortfall.unique_saturated_into(),
+					<T as Config>::MultiCurrency::free_balance(currency_id, who),
+					price_impact_limit,
+				)
+				.is_ok()
+				{
+					// successfully swap, break iteration
+					break;
+				}
 			}
 		}
 	}
@@ -581,6 +735,21 @@ where
 	}
 }
 
+impl<T, AnyCall> EstimateCallFee<AnyCall, PalletBalanceOf<T>> for Pallet<T>
+where
+	T: Config,
+	AnyCall: GetDispatchInfo + Encode,
+	PalletBalanceOf<T>: FixedPointOperand,
+	<T as frame_system::Config>::Call: Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>,
+{
+	/// Estimate the fee of `call` without a tip, given its `post_info`.
+	fn estimate_call_fee(call: &AnyCall, post_info: PostDispatchInfo) -> PalletBalanceOf<T> {
+		let len = call.encoded_size() as u32;
+		let info = call.get_dispatch_info();
+		Self::compute_actual_fee(len, &info, &post_info, Zero::zero())
+	}
+}
+
 /// Require the transactor pay for themselves and maybe include a tip to
 /// gain additional priority in the queue.
 #[derive(Encode, Decode, Clone, Eq, PartialEq)]
@@ -607,16 +776,48 @@ where
 		Self(fee)
 	}
 
+	/// Withdraw the fee of `call` from its sponsor, or the discounted fee from
+	/// `who` if the call is not sponsored. A sponsored call is invalid if its
+	/// sponsor cannot pay. Returns the fee, the payer, the withdrawn imbalance
+	/// and the discount.
 	fn withdraw_fee(
 		&self,
 		who: &T::AccountId,
-		_call: &<T as frame_system::Config>::Call,
+		call: &<T as frame_system::Config>::Call,
 		info: &DispatchInfoOf<<T as frame_system::Config>::Call>,
 		len: usize,
-	) -> Result<(PalletBalanceOf<T>, Option<NegativeImbalanceOf<T>>), TransactionValidityError> {
+	) -> Result<
+		(
+			PalletBalanceOf<T>,
+			T::AccountId,
+			Option<NegativeImbalanceOf<T>>,
+			PalletBalanceOf<T>,
+		),
+		TransactionValidityError,
+	> {
 		let tip = self.0;
 		let fee = Pallet::<T>::compute_fee(len as u32, info, tip);
 
+		// sponsors never pay tips, the signer would otherwise be free to drain them
+		if tip.is_zero() {
+			if let Some(sponsor) = T::FeeSponsor::sponsor_of(who, call) {
+				log::debug!(target: "fee", "who: {:?}, sponsor: {:?}, fee: {:?}", who, sponsor, fee);
+				// the discount of the signer does not apply to the fees it does not pay
+				return match <T as Config>::Currency::withdraw(
+					&sponsor,
+					fee,
+					WithdrawReasons::TRANSACTION_PAYMENT,
+					ExistenceRequirement::KeepAlive,
+				) {
+					Ok(imbalance) => Ok((fee, sponsor, Some(imbalance), Zero::zero())),
+					Err(_) => Err(InvalidTransaction::Payment.into()),
+				};
+			}
+		}
+
+		let discount = Pallet::<T>::fee_discount(who, fee.saturating_sub(tip));
+		let fee = fee.saturating_sub(discount);
+
 		let reason = if tip.is_zero() {
 			WithdrawReasons::TRANSACTION_PAYMENT
 		} else {
@@ -628,7 +829,7 @@ where
 
 		// withdraw native currency as fee
 		match <T as Config>::Currency::withdraw(who, fee, reason, ExistenceRequirement::KeepAlive) {
-			Ok(imbalance) => Ok((fee, Some(imbalance))),
+			Ok(imbalance) => Ok((fee, who.clone(), Some(imbalance), discount)),
 			Err(_) => Err(InvalidTransaction::Payment.into()),
 		}
 	}
@@ -676,6 +877,7 @@ where
 		Self::AccountId,
 		Option<NegativeImbalanceOf<T>>,
 		PalletBalanceOf<T>,
+		PalletBalanceOf<T>,
 	);
 
 	fn additional_signed(&self) -> sp_std::result::Result<(), TransactionValidityError> {
@@ -689,7 +891,7 @@ where
 		info: &DispatchInfoOf<Self::Call>,
 		len: usize,
 	) -> TransactionValidity {
-		let (fee, _) = self.withdraw_fee(who, call, info, len)?;
+		let (fee, _, _, _) = self.withdraw_fee(who, call, info, len)?;
 		Ok(ValidTransaction {
 			priority: Self::get_priority(len, info, fee),
 			..Default::default()
@@ -703,8 +905,15 @@ where
 		info: &DispatchInfoOf<Self::Call>,
 		len: usize,
 	) -> Result<Self::Pre, TransactionValidityError> {
-		let (fee, imbalance) = self.withdraw_fee(who, call, info, len)?;
-		Ok((self.0, who.clone(), imbalance, fee))
+		let (fee, payer, imbalance, discount) = self.withdraw_fee(who, call, info, len)?;
+		if payer != *who {
+			T::FeeSponsor::on_sponsored(who, call, fee);
+		}
+		if !discount.is_zero() {
+			BlockFeeDiscount::<T>::mutate(|total| *total = total.saturating_add(discount));
+		}
+		// the refund goes back to whoever paid
+		Ok((self.0, payer, imbalance, fee, discount))
 	}
 
 	fn post_dispatch(
@@ -714,9 +923,10 @@ where
 		len: usize,
 		_result: &DispatchResult,
 	) -> Result<(), TransactionValidityError> {
-		let (tip, who, imbalance, fee) = pre;
+		let (tip, who, imbalance, fee, discount) = pre;
 		if let Some(payed) = imbalance {
-			let actual_fee = Pallet::<T>::compute_actual_fee(len as u32, info, post_info, tip);
+			// the discount granted up front is kept even if the actual fee is lower
+			let actual_fee = Pallet::<T>::compute_actual_fee(len as u32, info, post_info, tip).saturating_sub(discount);
 			let refund = fee.saturating_sub(actual_fee);
 			let actual_payment = match <T as Config>::Currency::deposit_into_existing(&who, refund) {
 				Ok(refund_imbalance) => {
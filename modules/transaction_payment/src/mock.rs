@@ -4,13 +4,17 @@
 
 use super::*;
 use crate as transaction_payment;
-use frame_support::{construct_runtime, ord_parameter_types, parameter_types, weights::WeightToFeeCoefficients};
+use frame_support::{
+	construct_runtime, ord_parameter_types, parameter_types, weights::WeightToFeeCoefficients, PalletId,
+};
 use orml_traits::parameter_type_with_key;
-use primitives::{evm::EvmAddress, mocks::MockAddressMapping, Amount, TokenSymbol};
+use primitives::{evm::EvmAddress, mocks::MockAddressMapping, Amount, TokenSymbol, TradingPair};
 use smallvec::smallvec;
 use sp_core::{crypto::AccountId32, H256};
 use sp_runtime::{
-	testing::Header, traits::IdentityLookup, DispatchError, DispatchResult, Perbill,
+	testing::Header,
+	traits::{IdentityLookup, One},
+	DispatchError, DispatchResult, Perbill,
 };
 use sp_std::cell::RefCell;
 use support::{EVMBridge, InvokeContext};
@@ -20,6 +24,7 @@ pub type BlockNumber = u64;
 
 pub const ALICE: AccountId = AccountId::new([1u8; 32]);
 pub const BOB: AccountId = AccountId::new([2u8; 32]);
+pub const CHARLIE: AccountId = AccountId::new([3u8; 32]);
 
 pub const DUST: CurrencyId = CurrencyId::Token(TokenSymbol::DUST);
 pub const USDD: CurrencyId = CurrencyId::Token(TokenSymbol::USDD);
@@ -156,6 +161,25 @@ impl module_currencies::Config for Runtime {
 	type WeightInfo = ();
 	type AddressMapping = MockAddressMapping;
 	type EVMBridge = MockEVMBridge;
+	type AssetRegistry = ();
+	type Erc20ReservesBeforeTracking = ();
+}
+
+parameter_types! {
+	pub EnabledTradingPairs: Vec<TradingPair> = vec![TradingPair::from_currency_ids(DUST, USDD).unwrap()];
+	pub const GetExchangeFee: (u32, u32) = (1, 100);
+	pub const TradingPathLimit: u32 = 3;
+	pub const DEXPalletId: PalletId = PalletId(*b"dust/dex");
+}
+
+impl module_dex::Config for Runtime {
+	type Event = Event;
+	type Currency = Currencies;
+	type EnabledTradingPairs = EnabledTradingPairs;
+	type GetExchangeFee = GetExchangeFee;
+	type TradingPathLimit = TradingPathLimit;
+	type PalletId = DEXPalletId;
+	type WeightInfo = ();
 }
 
 thread_local! {
@@ -170,6 +194,7 @@ parameter_types! {
 	pub AllNonNativeCurrencyIds: Vec<CurrencyId> = vec![USDD];
 	pub const StableCurrencyId: CurrencyId = USDD;
 	pub static TransactionByteFee: u128 = 1;
+	pub MaxSlippageSwapWithDEX: Ratio = Ratio::one();
 }
 
 impl Config for Runtime {
@@ -182,9 +207,49 @@ impl Config for Runtime {
 	type TransactionByteFee = TransactionByteFee;
 	type WeightToFee = WeightToFee;
 	type FeeMultiplierUpdate = ();
+	type DEX = DEXModule;
+	type MaxSlippageSwapWithDEX = MaxSlippageSwapWithDEX;
+	type FeeSponsor = MockFeeSponsor;
+	type FeeDiscount = MockFeeDiscount;
+	type MaxFeeDiscountPerBlock = MaxFeeDiscountPerBlock;
 	type WeightInfo = ();
 }
 
+parameter_types! {
+	pub const MaxFeeDiscountPerBlock: Balance = 1000;
+}
+
+/// Discounts the fees of CHARLIE by half.
+pub struct MockFeeDiscount;
+impl FeeDiscount<AccountId> for MockFeeDiscount {
+	fn fee_discount(who: &AccountId) -> Perbill {
+		if *who == CHARLIE {
+			Perbill::from_percent(50)
+		} else {
+			Perbill::zero()
+		}
+	}
+}
+
+thread_local! {
+	pub static SPONSORED_FEES: RefCell<Vec<(AccountId, Balance)>> = RefCell::new(vec![]);
+}
+
+/// Sponsors `remark` calls of BOB and CHARLIE with the balance of ALICE.
+pub struct MockFeeSponsor;
+impl FeeSponsor<AccountId, Call, Balance> for MockFeeSponsor {
+	fn sponsor_of(who: &AccountId, call: &Call) -> Option<AccountId> {
+		match call {
+			Call::System(frame_system::Call::remark(..)) if *who == BOB || *who == CHARLIE => Some(ALICE),
+			_ => None,
+		}
+	}
+
+	fn on_sponsored(who: &AccountId, _call: &Call, fee: Balance) {
+		SPONSORED_FEES.with(|v| v.borrow_mut().push((who.clone(), fee)));
+	}
+}
+
 thread_local! {
 	static WEIGHT_TO_FEE: RefCell<u128> = RefCell::new(1);
 }
@@ -217,6 +282,7 @@ construct_runtime!(
 		PalletBalances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
 		Tokens: orml_tokens::{Pallet, Storage, Event<T>, Config<T>},
 		Currencies: module_currencies::{Pallet, Call, Event<T>},
+		DEXModule: module_dex::{Pallet, Call, Storage, Event<T>},
 	}
 );
 
@@ -8,9 +8,9 @@ use frame_support::{
 	weights::{DispatchClass, DispatchInfo, Pays},
 };
 use mock::{
-	AccountId, BlockWeights, Call, Currencies,
+	AccountId, BlockWeights, Call, Currencies, DEXModule,
 	ExtBuilder, Origin, Runtime, TransactionPayment,
-	DUST, USDD, ALICE, BOB
+	DUST, USDD, ALICE, BOB, CHARLIE, SPONSORED_FEES
 };
 use orml_traits::MultiCurrency;
 use sp_runtime::{testing::TestXt, traits::One};
@@ -87,6 +87,120 @@ fn refund_fee_according_to_actual_when_post_dispatch_and_native_currency_is_enou
 	});
 }
 
+#[test]
+fn charges_fee_from_sponsor() {
+	ExtBuilder::default().build().execute_with(|| {
+		let call: &<Runtime as frame_system::Config>::Call = &Call::System(frame_system::Call::remark(vec![]));
+		assert_eq!(Currencies::free_balance(DUST, &BOB), 0);
+
+		// the sponsor pays the fee and receives the refund
+		let fee = 23 * 2 + 1000; // len * byte + weight
+		let pre = ChargeTransactionPayment::<Runtime>::from(0)
+			.pre_dispatch(&BOB, call, &INFO, 23)
+			.unwrap();
+		assert_eq!(Currencies::free_balance(DUST, &ALICE), 100000 - fee);
+		assert_eq!(SPONSORED_FEES.with(|v| v.borrow().clone()), vec![(BOB, fee)]);
+
+		let refund = 200; // 1000 - 800
+		assert!(ChargeTransactionPayment::<Runtime>::post_dispatch(pre, &INFO, &POST_INFO, 23, &Ok(())).is_ok());
+		assert_eq!(Currencies::free_balance(DUST, &ALICE), 100000 - fee + refund);
+		assert_eq!(Currencies::free_balance(DUST, &BOB), 0);
+
+		// sponsors never pay tips
+		assert_err!(
+			ChargeTransactionPayment::<Runtime>::from(10).validate(&BOB, call, &INFO, 23),
+			TransactionValidityError::Invalid(InvalidTransaction::Payment)
+		);
+
+		// calls of other signers are not sponsored
+		assert!(ChargeTransactionPayment::<Runtime>::from(0)
+			.pre_dispatch(&ALICE, call, &INFO, 23)
+			.is_ok());
+		assert_eq!(SPONSORED_FEES.with(|v| v.borrow().len()), 1);
+
+		// the signer does not pay in place of a sponsor that cannot
+		let sponsor_balance = Currencies::free_balance(DUST, &ALICE);
+		assert_ok!(<Currencies as MultiCurrency<_>>::transfer(
+			DUST,
+			&ALICE,
+			&BOB,
+			sponsor_balance
+		));
+		assert_err!(
+			ChargeTransactionPayment::<Runtime>::from(0).validate(&BOB, call, &INFO, 23),
+			TransactionValidityError::Invalid(InvalidTransaction::Payment)
+		);
+		assert_eq!(Currencies::free_balance(DUST, &BOB), sponsor_balance);
+	});
+}
+
+#[test]
+fn discounts_fee_within_block_budget() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(<Currencies as MultiCurrency<_>>::transfer(
+			DUST, &ALICE, &CHARLIE, 10000
+		));
+		assert_eq!(
+			TransactionPayment::query_fee_discount(&CHARLIE),
+			Perbill::from_percent(50)
+		);
+		assert_eq!(TransactionPayment::query_fee_discount(&ALICE), Perbill::zero());
+
+		// half of the fee is discounted, the tip is not
+		let fee = 23 * 2 + 1000; // len * byte + weight
+		let pre = ChargeTransactionPayment::<Runtime>::from(10)
+			.pre_dispatch(&CHARLIE, CALL, &INFO, 23)
+			.unwrap();
+		assert_eq!(Currencies::free_balance(DUST, &CHARLIE), 10000 - fee / 2 - 10);
+		assert_eq!(TransactionPayment::block_fee_discount(), fee / 2);
+
+		// the discount is kept when refunding
+		let refund = 200; // 1000 - 800
+		assert!(ChargeTransactionPayment::<Runtime>::post_dispatch(pre, &INFO, &POST_INFO, 23, &Ok(())).is_ok());
+		assert_eq!(
+			Currencies::free_balance(DUST, &CHARLIE),
+			10000 - (fee - fee / 2 - refund) - 10
+		);
+
+		// the block budget caps the discount
+		let balance = Currencies::free_balance(DUST, &CHARLIE);
+		assert!(ChargeTransactionPayment::<Runtime>::from(0)
+			.pre_dispatch(&CHARLIE, CALL, &INFO, 23)
+			.is_ok());
+		assert_eq!(
+			Currencies::free_balance(DUST, &CHARLIE),
+			balance - (fee - (1000 - fee / 2))
+		);
+		assert_eq!(TransactionPayment::block_fee_discount(), 1000);
+
+		let balance = Currencies::free_balance(DUST, &CHARLIE);
+		assert!(ChargeTransactionPayment::<Runtime>::from(0)
+			.pre_dispatch(&CHARLIE, CALL, &INFO, 23)
+			.is_ok());
+		assert_eq!(Currencies::free_balance(DUST, &CHARLIE), balance - fee);
+
+		// the budget is renewed every block
+		<TransactionPayment as Hooks<u64>>::on_finalize(1);
+		assert_eq!(TransactionPayment::block_fee_discount(), 0);
+
+		// the fees sponsored for CHARLIE are not discounted
+		let remark: &<Runtime as frame_system::Config>::Call = &Call::System(frame_system::Call::remark(vec![]));
+		let sponsor_balance = Currencies::free_balance(DUST, &ALICE);
+		assert!(ChargeTransactionPayment::<Runtime>::from(0)
+			.pre_dispatch(&CHARLIE, remark, &INFO, 23)
+			.is_ok());
+		assert_eq!(Currencies::free_balance(DUST, &ALICE), sponsor_balance - fee);
+		assert_eq!(TransactionPayment::block_fee_discount(), 0);
+
+		// accounts without a discount pay the full fee
+		assert!(ChargeTransactionPayment::<Runtime>::from(0)
+			.pre_dispatch(&ALICE, CALL, &INFO, 23)
+			.is_ok());
+		assert_eq!(Currencies::free_balance(DUST, &ALICE), 100000 - 10000 - 2 * fee);
+		assert_eq!(TransactionPayment::block_fee_discount(), 0);
+	});
+}
+
 #[test]
 fn charges_fee_when_validate_and_native_is_not_enough() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -101,30 +215,56 @@ fn charges_fee_when_validate_and_native_is_not_enough() {
 				TransactionValidityError::Invalid(InvalidTransaction::Payment)
 		);
 
-		// // add liquidity to DEX
-		// assert_ok!(DEXModule::add_liquidity(
-		// 	Origin::signed(ALICE),
-		// 	DUST,
-		// 	USDD,
-		// 	10000,
-		// 	1000,
-		// 	false
-		// ));
-		// assert_eq!(DEXModule::get_liquidity_pool(DUST, USDD), (10000, 1000));
-
-		// let fee = 500 * 2 + 1000; // len * byte + weight
-		// assert_eq!(
-		// 	ChargeTransactionPayment::<Runtime>::from(0)
-		// 		.validate(&BOB, CALL2, &INFO, 500)
-		// 		.unwrap()
-		// 		.priority,
-		// 	fee
-		// );
-
-
-		// assert_eq!(Currencies::free_balance(DUST, &BOB), 0);
-		// assert_eq!(Currencies::free_balance(USDD, &BOB), 749);
-		// assert_eq!(DEXModule::get_liquidity_pool(DUST, USDD), (10000 - 2000, 1251));
+		// add liquidity to DEX
+		assert_ok!(DEXModule::add_liquidity(
+			Origin::signed(ALICE),
+			DUST,
+			USDD,
+			10000,
+			1000,
+			0
+		));
+		assert_eq!(DEXModule::get_liquidity_pool(DUST, USDD), (10000, 1000));
+
+		let fee = 500 * 2 + 1000; // len * byte + weight
+		assert_eq!(
+			ChargeTransactionPayment::<Runtime>::from(0)
+				.validate(&BOB, CALL2, &INFO, 500)
+				.unwrap()
+				.priority,
+			fee
+		);
+
+		assert_eq!(Currencies::free_balance(DUST, &BOB), 0);
+		assert_eq!(Currencies::free_balance(USDD, &BOB), 747);
+		assert_eq!(DEXModule::get_liquidity_pool(DUST, USDD), (10000 - 2000, 1253));
+	});
+}
+
+#[test]
+fn charges_fee_with_default_fee_token_via_stable_currency() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(<Currencies as MultiCurrency<_>>::transfer(USDD, &ALICE, &BOB, 1000));
+		assert_ok!(DEXModule::add_liquidity(
+			Origin::signed(ALICE),
+			DUST,
+			USDD,
+			10000,
+			1000,
+			0
+		));
+		assert_ok!(TransactionPayment::set_default_fee_token(
+			Origin::signed(BOB),
+			Some(USDD)
+		));
+
+		let fee = 500 * 2 + 1000; // len * byte + weight
+		assert!(ChargeTransactionPayment::<Runtime>::from(0)
+			.pre_dispatch(&BOB, CALL2, &INFO, 500)
+			.is_ok());
+		assert_eq!(Currencies::free_balance(DUST, &BOB), 0);
+		assert_eq!(Currencies::free_balance(USDD, &BOB), 747);
+		assert_eq!(DEXModule::get_liquidity_pool(DUST, USDD), (10000 - fee, 1253));
 	});
 }
 
@@ -163,7 +303,7 @@ fn query_info_works() {
 			NextFeeMultiplier::<Runtime>::put(Multiplier::saturating_from_rational(3, 2));
 
 			assert_eq!(
-				TransactionPayment::query_info(xt, len),
+				TransactionPayment::query_info(xt.clone(), len, None),
 				RuntimeDispatchInfo {
 					weight: info.weight,
 					class: info.class,
@@ -172,9 +312,91 @@ fn query_info_works() {
 						+ info.weight.min(BlockWeights::get().max_block) as u128 * 2 * 3 / 2 /* weight */
 				},
 			);
+
+			// the fee of the signer is discounted
+			let fee = TransactionPayment::compute_fee(len, &info, 0);
+			let discount = TransactionPayment::fee_discount(&CHARLIE, fee);
+			assert!(!discount.is_zero());
+			assert_eq!(
+				TransactionPayment::query_info(xt.clone(), len, Some(&ALICE)).partial_fee,
+				fee
+			);
+			assert_eq!(
+				TransactionPayment::query_info(xt, len, Some(&CHARLIE)).partial_fee,
+				fee - discount
+			);
 		});
 }
 
+#[test]
+fn query_fee_in_currencies_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		let xt = TestXt::new(CALL2.clone(), Some((111111, ())));
+		let info = xt.get_dispatch_info();
+		let len = xt.encode().len() as u32;
+		let fee = TransactionPayment::compute_fee(len, &info, 0);
+
+		// without a pool only the native fee is known
+		assert_eq!(
+			TransactionPayment::query_fee_in_currencies(xt.clone(), len, &ALICE),
+			(vec![(DUST, fee)], Some(DUST))
+		);
+		assert_eq!(
+			TransactionPayment::query_fee_in_currencies(xt.clone(), len, &BOB),
+			(vec![(DUST, fee)], None)
+		);
+
+		assert_ok!(<Currencies as MultiCurrency<_>>::transfer(USDD, &ALICE, &BOB, 1000));
+		assert_ok!(DEXModule::add_liquidity(
+			Origin::signed(ALICE),
+			DUST,
+			USDD,
+			10000,
+			1000,
+			0
+		));
+		let usdd_fee = DEXModule::get_swap_supply_amount(&[USDD, DUST], fee, Some(Ratio::one())).unwrap();
+
+		// BOB has no native balance and swaps USDD
+		assert_eq!(
+			TransactionPayment::query_fee_in_currencies(xt.clone(), len, &BOB),
+			(vec![(DUST, fee), (USDD, usdd_fee)], Some(USDD))
+		);
+
+		// the fee of CHARLIE is discounted
+		let discounted_fee = fee - TransactionPayment::fee_discount(&CHARLIE, fee);
+		assert_eq!(
+			TransactionPayment::query_fee_in_currencies(xt.clone(), len, &CHARLIE),
+			(
+				vec![
+					(DUST, discounted_fee),
+					(
+						USDD,
+						DEXModule::get_swap_supply_amount(&[USDD, DUST], discounted_fee, Some(Ratio::one())).unwrap()
+					)
+				],
+				None
+			)
+		);
+
+		// the default fee currency is tried first
+		assert_ok!(TransactionPayment::set_default_fee_token(
+			Origin::signed(ALICE),
+			Some(USDD)
+		));
+		assert_eq!(
+			TransactionPayment::query_fee_in_currencies(xt.clone(), len, &ALICE),
+			(vec![(USDD, usdd_fee), (DUST, fee)], Some(DUST))
+		);
+
+		// the estimate matches what is charged
+		assert!(ChargeTransactionPayment::<Runtime>::from(0)
+			.pre_dispatch(&BOB, CALL2, &info, len as usize)
+			.is_ok());
+		assert_eq!(Currencies::free_balance(USDD, &BOB), 1000 - usdd_fee);
+	});
+}
+
 #[test]
 fn compute_fee_works_without_multiplier() {
 	ExtBuilder::default()
@@ -276,6 +498,32 @@ fn compute_fee_works_with_negative_multiplier() {
 		});
 }
 
+#[test]
+fn estimate_call_fee_works() {
+	ExtBuilder::default()
+		.base_weight(100)
+		.byte_fee(10)
+		.build()
+		.execute_with(|| {
+			let len = CALL.encoded_size() as u32;
+			let info = CALL.get_dispatch_info();
+			assert_eq!(
+				<TransactionPayment as EstimateCallFee<_, _>>::estimate_call_fee(CALL, POST_INFO),
+				Pallet::<Runtime>::compute_actual_fee(len, &info, &POST_INFO, 0)
+			);
+
+			// calls which end up free are estimated free
+			let post_info = PostDispatchInfo {
+				actual_weight: None,
+				pays_fee: Pays::No,
+			};
+			assert_eq!(
+				<TransactionPayment as EstimateCallFee<_, _>>::estimate_call_fee(CALL, post_info),
+				0
+			);
+		});
+}
+
 #[test]
 fn compute_fee_does_not_overflow() {
 	ExtBuilder::default()
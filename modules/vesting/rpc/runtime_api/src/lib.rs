@@ -0,0 +1,15 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::all)]
+
+use codec::Codec;
+
+sp_api::decl_runtime_apis! {
+	pub trait VestingApi<AccountId, CurrencyId, Balance> where
+		AccountId: Codec,
+		CurrencyId: Codec,
+		Balance: Codec,
+	{
+		/// Amount of `who` still vesting in a currency, and amount vested but not claimed yet.
+		fn vesting_balance(who: AccountId, currency_id: CurrencyId) -> (Balance, Balance);
+	}
+}
@@ -0,0 +1,92 @@
+//! RPC interface for the vesting module.
+
+use codec::Codec;
+use jsonrpc_core::{Error, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_rpc::number::NumberOrHex;
+use sp_runtime::{
+	generic::BlockId,
+	traits::{Block as BlockT, MaybeDisplay},
+};
+use std::{convert::TryInto, marker::PhantomData, sync::Arc};
+
+pub use module_vesting_rpc_runtime_api::VestingApi as VestingRuntimeApi;
+pub use rpc_impl_VestingApi::gen_server::VestingApi as VestingApiServer;
+
+/// Vesting funds of an account in a currency.
+#[derive(Debug, Eq, PartialEq, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct VestingBalance {
+	/// Amount still vesting
+	pub locked: NumberOrHex,
+	/// Amount vested, which a claim would unlock
+	pub claimable: NumberOrHex,
+}
+
+#[rpc(server)]
+pub trait VestingApi<BlockHash, AccountId, CurrencyId> {
+	/// Locked and claimable vesting funds of an account in a currency.
+	#[rpc(name = "vesting_vestingBalance")]
+	fn vesting_balance(&self, who: AccountId, currency_id: CurrencyId, at: Option<BlockHash>)
+		-> Result<VestingBalance>;
+}
+
+fn runtime_err<T: std::fmt::Debug>(err: T) -> Error {
+	Error {
+		code: ErrorCode::InternalError,
+		message: "Unable to query the vesting balance.".into(),
+		data: Some(format!("{:?}", err).into()),
+	}
+}
+
+fn to_number<Balance: TryInto<NumberOrHex> + MaybeDisplay + Copy>(balance: Balance) -> Result<NumberOrHex> {
+	balance.try_into().map_err(|_| Error {
+		code: ErrorCode::InvalidParams,
+		message: format!("{} doesn't fit in NumberOrHex representation", balance),
+		data: None,
+	})
+}
+
+pub struct Vesting<C, B, Balance> {
+	client: Arc<C>,
+	_marker: PhantomData<(B, Balance)>,
+}
+
+impl<C, B, Balance> Vesting<C, B, Balance> {
+	pub fn new(client: Arc<C>) -> Self {
+		Self {
+			client,
+			_marker: Default::default(),
+		}
+	}
+}
+
+impl<C, Block, AccountId, CurrencyId, Balance> VestingApi<<Block as BlockT>::Hash, AccountId, CurrencyId>
+	for Vesting<C, Block, Balance>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: VestingRuntimeApi<Block, AccountId, CurrencyId, Balance>,
+	AccountId: Codec,
+	CurrencyId: Codec,
+	Balance: Codec + MaybeDisplay + Copy + TryInto<NumberOrHex> + Send + Sync + 'static,
+{
+	fn vesting_balance(
+		&self,
+		who: AccountId,
+		currency_id: CurrencyId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<VestingBalance> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		let (locked, claimable) = api.vesting_balance(&at, who, currency_id).map_err(runtime_err)?;
+		Ok(VestingBalance {
+			locked: to_number(locked)?,
+			claimable: to_number(claimable)?,
+		})
+	}
+}
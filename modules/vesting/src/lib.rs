@@ -0,0 +1,318 @@
+//! # Vesting
+//!
+//! Lock the funds of an account, in any currency, until they vest.
+//!
+//! A vesting schedule releases `per_period` every `period` blocks from its
+//! `start`, `period_count` times. Nothing vests before the `cliff` is over,
+//! after which the periods elapsed so far vest at once.
+//!
+//! The vested funds stay locked until claimed.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use frame_support::{pallet_prelude::*, traits::LockIdentifier, transactional, weights::Weight};
+use frame_system::pallet_prelude::*;
+use orml_traits::{GetByKey, MultiCurrency, MultiLockableCurrency};
+use sp_runtime::traits::{
+	AtLeast32BitUnsigned, CheckedAdd, CheckedMul, Saturating, StaticLookup, UniqueSaturatedInto, Zero,
+};
+use sp_std::prelude::*;
+
+mod mock;
+mod tests;
+pub mod weights;
+
+pub use module::*;
+
+pub trait WeightInfo {
+	fn vested_transfer() -> Weight;
+	fn claim(s: u32) -> Weight;
+}
+
+/// Lock on the funds which are not claimed yet.
+pub const VESTING_LOCK_ID: LockIdentifier = *b"dustvest";
+
+pub type BalanceOf<T> = <<T as Config>::Currency as MultiCurrency<<T as frame_system::Config>::AccountId>>::Balance;
+pub type CurrencyIdOf<T> =
+	<<T as Config>::Currency as MultiCurrency<<T as frame_system::Config>::AccountId>>::CurrencyId;
+pub type VestingScheduleOf<T> = VestingSchedule<<T as frame_system::Config>::BlockNumber, BalanceOf<T>>;
+/// Account,Currency,Start,Cliff,Period,Period count,Per period
+pub type ScheduledItem<T> = (
+	<T as frame_system::Config>::AccountId,
+	CurrencyIdOf<T>,
+	<T as frame_system::Config>::BlockNumber,
+	<T as frame_system::Config>::BlockNumber,
+	<T as frame_system::Config>::BlockNumber,
+	u32,
+	BalanceOf<T>,
+);
+
+/// Funds released periodically, after an optional cliff.
+///
+/// A linear schedule has no cliff, a cliff schedule vests everything at
+/// once with a single period.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct VestingSchedule<BlockNumber, Balance> {
+	/// Block the first period starts at
+	pub start: BlockNumber,
+	/// Blocks after `start` during which nothing vests
+	pub cliff: BlockNumber,
+	/// Blocks between two releases
+	pub period: BlockNumber,
+	/// Number of releases
+	pub period_count: u32,
+	/// Amount vested every period
+	pub per_period: Balance,
+}
+
+impl<BlockNumber: AtLeast32BitUnsigned + Copy, Balance: AtLeast32BitUnsigned + Copy>
+	VestingSchedule<BlockNumber, Balance>
+{
+	/// Block the whole schedule is vested at, `None` on overflow.
+	pub fn end(&self) -> Option<BlockNumber> {
+		let end = self
+			.period
+			.checked_mul(&self.period_count.into())?
+			.checked_add(&self.start)?;
+		self.start.checked_add(&self.cliff).map(|cliff_end| end.max(cliff_end))
+	}
+
+	/// Amount vested over the whole schedule, `None` on overflow.
+	pub fn total_amount(&self) -> Option<Balance> {
+		self.per_period.checked_mul(&self.period_count.into())
+	}
+
+	/// Amount still locked at block `now`.
+	///
+	/// Only valid for a schedule whose `end` and `total_amount` don't overflow.
+	pub fn locked_amount(&self, now: BlockNumber) -> Balance {
+		let total = self.total_amount().unwrap_or_else(Zero::zero);
+		if now < self.start.saturating_add(self.cliff) {
+			return total;
+		}
+		let periods = now.saturating_sub(self.start) / self.period;
+		let periods: u32 = periods.min(self.period_count.into()).unique_saturated_into();
+		total.saturating_sub(self.per_period.saturating_mul(periods.into()))
+	}
+}
+
+#[frame_support::pallet]
+pub mod module {
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+		type Currency: MultiLockableCurrency<Self::AccountId, Moment = Self::BlockNumber>;
+		/// Smallest amount which can be vested, by currency
+		type MinVestedTransfer: GetByKey<CurrencyIdOf<Self>, BalanceOf<Self>>;
+		/// Origin allowed to make vested transfers, from the account it returns
+		type VestedTransferOrigin: EnsureOrigin<Self::Origin, Success = Self::AccountId>;
+		/// How many schedules an account can have in a currency.
+		#[pallet::constant]
+		type MaxVestingSchedules: Get<u32>;
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Vesting period is zero
+		ZeroVestingPeriod,
+		/// Vesting period count is zero
+		ZeroVestingPeriodCount,
+		/// The end or the total amount of the schedule overflows
+		NumOverflow,
+		/// Vested amount is below `MinVestedTransfer`
+		AmountLow,
+		/// Already have maximum allowed number of schedules in the currency
+		TooManyVestingSchedules,
+		/// Not enough free balance to lock the vested amount
+		InsufficientBalanceToLock,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(fn deposit_event)]
+	#[pallet::metadata(T::AccountId = "AccountId", CurrencyIdOf<T> = "CurrencyId", VestingScheduleOf<T> = "VestingScheduleOf", BalanceOf<T> = "Balance")]
+	pub enum Event<T: Config> {
+		/// From,To,Currency,Schedule
+		VestingScheduleAdded(T::AccountId, T::AccountId, CurrencyIdOf<T>, VestingScheduleOf<T>),
+		/// Account,Currency,Amount still locked
+		Claimed(T::AccountId, CurrencyIdOf<T>, BalanceOf<T>),
+	}
+
+	/// Vesting schedules of an account in a currency.
+	#[pallet::storage]
+	#[pallet::getter(fn vesting_schedules)]
+	pub type VestingSchedules<T: Config> = StorageDoubleMap<_,
+		Blake2_128Concat, T::AccountId,
+		Twox64Concat, CurrencyIdOf<T>,
+		Vec<VestingScheduleOf<T>>,
+		ValueQuery>;
+
+	/// Amount held by the vesting lock of an account in a currency, as of
+	/// the last claim or vested transfer.
+	#[pallet::storage]
+	#[pallet::getter(fn vesting_lock)]
+	pub type VestingLocks<T: Config> = StorageDoubleMap<_,
+		Blake2_128Concat, T::AccountId,
+		Twox64Concat, CurrencyIdOf<T>,
+		BalanceOf<T>,
+		ValueQuery>;
+
+	#[pallet::genesis_config]
+	pub struct GenesisConfig<T: Config> {
+		pub vesting: Vec<ScheduledItem<T>>,
+	}
+
+	#[cfg(feature = "std")]
+	impl<T: Config> Default for GenesisConfig<T> {
+		fn default() -> Self {
+			GenesisConfig { vesting: vec![] }
+		}
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
+		fn build(&self) {
+			self.vesting
+				.iter()
+				.for_each(|(who, currency_id, start, cliff, period, period_count, per_period)| {
+					let schedule = VestingSchedule {
+						start: *start,
+						cliff: *cliff,
+						period: *period,
+						period_count: *period_count,
+						per_period: *per_period,
+					};
+					Pallet::<T>::ensure_valid_schedule(*currency_id, &schedule).expect("Invalid vesting schedule");
+
+					let mut schedules = Pallet::<T>::vesting_schedules(who, currency_id);
+					schedules.push(schedule);
+					assert!(
+						schedules.len() as u32 <= T::MaxVestingSchedules::get(),
+						"Too many vesting schedules"
+					);
+					let locked = Pallet::<T>::locked_amount(&schedules, Zero::zero());
+					assert!(
+						T::Currency::free_balance(*currency_id, who) >= locked,
+						"Account does not have enough balance to vest"
+					);
+
+					Pallet::<T>::update_lock(who, *currency_id, locked).expect("Vesting lock shouldn't fail");
+					<VestingSchedules<T>>::insert(who, currency_id, schedules);
+				});
+		}
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(PhantomData<T>);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+
+		/// Transfer the whole amount of a schedule to `dest`, locked until it vests.
+		#[pallet::weight(T::WeightInfo::vested_transfer())]
+		#[transactional]
+		pub fn vested_transfer(
+			origin: OriginFor<T>,
+			dest: <T::Lookup as StaticLookup>::Source,
+			currency_id: CurrencyIdOf<T>,
+			schedule: VestingScheduleOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let from = T::VestedTransferOrigin::ensure_origin(origin)?;
+			let to = T::Lookup::lookup(dest)?;
+			let total = Self::ensure_valid_schedule(currency_id, &schedule)?;
+
+			let mut schedules = Self::vesting_schedules(&to, currency_id);
+			ensure!(
+				(schedules.len() as u32) < T::MaxVestingSchedules::get(),
+				Error::<T>::TooManyVestingSchedules
+			);
+			schedules.push(schedule.clone());
+
+			T::Currency::transfer(currency_id, &from, &to, total)?;
+			let locked = Self::prune_and_lock(&to, currency_id, schedules)?;
+			ensure!(
+				T::Currency::free_balance(currency_id, &to) >= locked,
+				Error::<T>::InsufficientBalanceToLock
+			);
+
+			Self::deposit_event(Event::VestingScheduleAdded(from, to, currency_id, schedule));
+			Ok(().into())
+		}
+
+		/// Unlock the funds of the caller vested so far in a currency.
+		#[pallet::weight(T::WeightInfo::claim(T::MaxVestingSchedules::get()))]
+		pub fn claim(origin: OriginFor<T>, currency_id: CurrencyIdOf<T>) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let locked = Self::prune_and_lock(&who, currency_id, Self::vesting_schedules(&who, currency_id))?;
+
+			Self::deposit_event(Event::Claimed(who, currency_id, locked));
+			Ok(().into())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Amount of `who` still vesting in a currency, and amount vested but not claimed yet.
+	pub fn vesting_balance(who: &T::AccountId, currency_id: CurrencyIdOf<T>) -> (BalanceOf<T>, BalanceOf<T>) {
+		let now = frame_system::Pallet::<T>::block_number();
+		let locked = Self::locked_amount(&Self::vesting_schedules(who, currency_id), now);
+		let claimable = Self::vesting_lock(who, currency_id).saturating_sub(locked);
+		(locked, claimable)
+	}
+
+	fn locked_amount(schedules: &[VestingScheduleOf<T>], now: T::BlockNumber) -> BalanceOf<T> {
+		schedules.iter().fold(Zero::zero(), |locked: BalanceOf<T>, schedule| {
+			locked.saturating_add(schedule.locked_amount(now))
+		})
+	}
+
+	/// Drop the vested schedules of `who` and lock what is still vesting.
+	fn prune_and_lock(
+		who: &T::AccountId,
+		currency_id: CurrencyIdOf<T>,
+		mut schedules: Vec<VestingScheduleOf<T>>,
+	) -> Result<BalanceOf<T>, DispatchError> {
+		let now = frame_system::Pallet::<T>::block_number();
+		schedules.retain(|schedule| !schedule.locked_amount(now).is_zero());
+		let locked = Self::locked_amount(&schedules, now);
+
+		Self::update_lock(who, currency_id, locked)?;
+		if schedules.is_empty() {
+			<VestingSchedules<T>>::remove(who, currency_id);
+		} else {
+			<VestingSchedules<T>>::insert(who, currency_id, schedules);
+		}
+		Ok(locked)
+	}
+
+	fn update_lock(who: &T::AccountId, currency_id: CurrencyIdOf<T>, locked: BalanceOf<T>) -> DispatchResult {
+		if locked.is_zero() {
+			T::Currency::remove_lock(VESTING_LOCK_ID, currency_id, who)?;
+			<VestingLocks<T>>::remove(who, currency_id);
+		} else {
+			T::Currency::set_lock(VESTING_LOCK_ID, currency_id, who, locked)?;
+			<VestingLocks<T>>::insert(who, currency_id, locked);
+		}
+		Ok(())
+	}
+
+	/// Check the schedule and return its total amount.
+	fn ensure_valid_schedule(
+		currency_id: CurrencyIdOf<T>,
+		schedule: &VestingScheduleOf<T>,
+	) -> Result<BalanceOf<T>, DispatchError> {
+		ensure!(!schedule.period.is_zero(), Error::<T>::ZeroVestingPeriod);
+		ensure!(!schedule.period_count.is_zero(), Error::<T>::ZeroVestingPeriodCount);
+		schedule.end().ok_or(Error::<T>::NumOverflow)?;
+		let total = schedule.total_amount().ok_or(Error::<T>::NumOverflow)?;
+		ensure!(total >= T::MinVestedTransfer::get(&currency_id), Error::<T>::AmountLow);
+		Ok(total)
+	}
+}
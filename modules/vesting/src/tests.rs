@@ -0,0 +1,216 @@
+//! Unit tests for the vesting module.
+
+#![cfg(test)]
+
+use super::*;
+use frame_support::{assert_noop, assert_ok};
+use mock::{Event, *};
+use sp_runtime::traits::BadOrigin;
+
+fn linear(start: BlockNumber, period_count: u32, per_period: Balance) -> VestingSchedule<BlockNumber, Balance> {
+	VestingSchedule {
+		start,
+		cliff: 0,
+		period: 10,
+		period_count,
+		per_period,
+	}
+}
+
+fn transfer(from: &AccountId, to: &AccountId, amount: Balance) -> DispatchResult {
+	<Tokens as MultiCurrency<_>>::transfer(DUST, from, to, amount)
+}
+
+#[test]
+fn vested_transfer_locks_until_claimed() {
+	ExtBuilder::default().build().execute_with(|| {
+		let schedule = linear(1, 5, 20);
+		assert_ok!(Vesting::vested_transfer(
+			Origin::signed(ALICE),
+			BOB,
+			DUST,
+			schedule.clone()
+		));
+		System::assert_last_event(Event::Vesting(crate::Event::VestingScheduleAdded(
+			ALICE, BOB, DUST, schedule,
+		)));
+		assert_eq!(Tokens::free_balance(DUST, &ALICE), 900);
+		assert_eq!(Tokens::free_balance(DUST, &BOB), 100);
+		assert_eq!(Tokens::accounts(BOB, DUST).frozen, 100);
+		assert_eq!(Vesting::vesting_balance(&BOB, DUST), (100, 0));
+
+		// a period later, the vested funds are claimable
+		System::set_block_number(11);
+		assert_eq!(Vesting::vesting_balance(&BOB, DUST), (80, 20));
+		assert!(transfer(&BOB, &ALICE, 20).is_err());
+
+		assert_ok!(Vesting::claim(Origin::signed(BOB), DUST));
+		System::assert_last_event(Event::Vesting(crate::Event::Claimed(BOB, DUST, 80)));
+		assert_eq!(Vesting::vesting_balance(&BOB, DUST), (80, 0));
+		assert!(transfer(&BOB, &ALICE, 21).is_err());
+		assert_ok!(transfer(&BOB, &ALICE, 20));
+
+		// the lock and the schedule are gone once fully vested
+		System::set_block_number(51);
+		assert_eq!(Vesting::vesting_balance(&BOB, DUST), (0, 80));
+		assert_ok!(Vesting::claim(Origin::signed(BOB), DUST));
+		assert_eq!(Tokens::accounts(BOB, DUST).frozen, 0);
+		assert!(Vesting::vesting_schedules(BOB, DUST).is_empty());
+		assert_eq!(Vesting::vesting_lock(BOB, DUST), 0);
+	});
+}
+
+#[test]
+fn genesis_cliff_vests_at_once() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(Tokens::accounts(CHARLIE, DUST).frozen, 80);
+		assert_ok!(transfer(&CHARLIE, &ALICE, 20));
+		assert!(transfer(&CHARLIE, &ALICE, 1).is_err());
+
+		// nothing vests before the end of the cliff
+		System::set_block_number(9);
+		assert_eq!(Vesting::vesting_balance(&CHARLIE, DUST), (80, 0));
+		assert_ok!(Vesting::claim(Origin::signed(CHARLIE), DUST));
+		assert_eq!(Tokens::accounts(CHARLIE, DUST).frozen, 80);
+
+		System::set_block_number(10);
+		assert_eq!(Vesting::vesting_balance(&CHARLIE, DUST), (0, 80));
+		assert_ok!(Vesting::claim(Origin::signed(CHARLIE), DUST));
+		assert_ok!(transfer(&CHARLIE, &ALICE, 80));
+	});
+}
+
+#[test]
+fn cliff_releases_elapsed_periods() {
+	let schedule = VestingSchedule {
+		start: 0u64,
+		cliff: 25,
+		period: 10,
+		period_count: 4,
+		per_period: 10u128,
+	};
+	assert_eq!(schedule.end(), Some(40));
+	assert_eq!(schedule.locked_amount(24), 40);
+	assert_eq!(schedule.locked_amount(25), 20);
+	assert_eq!(schedule.locked_amount(39), 10);
+	assert_eq!(schedule.locked_amount(40), 0);
+
+	// the cliff can outlast the periods
+	let schedule = VestingSchedule { cliff: 50, ..schedule };
+	assert_eq!(schedule.end(), Some(50));
+	assert_eq!(schedule.locked_amount(49), 40);
+	assert_eq!(schedule.locked_amount(50), 0);
+}
+
+#[test]
+fn vests_each_currency_apart() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(Vesting::vested_transfer(
+			Origin::signed(ALICE),
+			BOB,
+			DUST,
+			linear(1, 1, 50)
+		));
+		assert_ok!(Vesting::vested_transfer(
+			Origin::signed(ALICE),
+			BOB,
+			USDD,
+			linear(1, 2, 50)
+		));
+		assert_eq!(Tokens::accounts(BOB, USDD).frozen, 100);
+
+		System::set_block_number(11);
+		assert_eq!(Vesting::vesting_balance(&BOB, DUST), (0, 50));
+		assert_eq!(Vesting::vesting_balance(&BOB, USDD), (50, 50));
+
+		assert_ok!(Vesting::claim(Origin::signed(BOB), USDD));
+		assert_eq!(Tokens::accounts(BOB, USDD).frozen, 50);
+		assert_eq!(Tokens::accounts(BOB, DUST).frozen, 50);
+		assert_eq!(Vesting::vesting_balance(&BOB, DUST), (0, 50));
+	});
+}
+
+#[test]
+fn vested_transfer_fails_for_invalid_schedules() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Vesting::vested_transfer(Origin::none(), BOB, DUST, linear(1, 1, 50)),
+			BadOrigin
+		);
+		assert_noop!(
+			Vesting::vested_transfer(
+				Origin::signed(ALICE),
+				BOB,
+				DUST,
+				VestingSchedule {
+					period: 0,
+					..linear(1, 1, 50)
+				}
+			),
+			Error::<Runtime>::ZeroVestingPeriod
+		);
+		assert_noop!(
+			Vesting::vested_transfer(Origin::signed(ALICE), BOB, DUST, linear(1, 0, 50)),
+			Error::<Runtime>::ZeroVestingPeriodCount
+		);
+		assert_noop!(
+			Vesting::vested_transfer(Origin::signed(ALICE), BOB, DUST, linear(1, 2, Balance::MAX)),
+			Error::<Runtime>::NumOverflow
+		);
+		assert_noop!(
+			Vesting::vested_transfer(Origin::signed(ALICE), BOB, DUST, linear(u64::MAX, 1, 50)),
+			Error::<Runtime>::NumOverflow
+		);
+
+		// the minimum depends on the currency
+		assert_noop!(
+			Vesting::vested_transfer(Origin::signed(ALICE), BOB, DUST, linear(1, 3, 3)),
+			Error::<Runtime>::AmountLow
+		);
+		assert_ok!(Vesting::vested_transfer(
+			Origin::signed(ALICE),
+			BOB,
+			USDD,
+			linear(1, 3, 3)
+		));
+
+		assert_noop!(
+			Vesting::vested_transfer(Origin::signed(ALICE), BOB, DUST, linear(1, 1, 1_001)),
+			orml_tokens::Error::<Runtime>::BalanceTooLow
+		);
+	});
+}
+
+#[test]
+fn vested_transfer_fails_with_too_many_schedules() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(Vesting::vested_transfer(
+			Origin::signed(ALICE),
+			BOB,
+			DUST,
+			linear(1, 1, 50)
+		));
+		assert_ok!(Vesting::vested_transfer(
+			Origin::signed(ALICE),
+			BOB,
+			DUST,
+			linear(1, 2, 50)
+		));
+		assert_noop!(
+			Vesting::vested_transfer(Origin::signed(ALICE), BOB, DUST, linear(1, 1, 50)),
+			Error::<Runtime>::TooManyVestingSchedules
+		);
+
+		// claiming drops the vested schedules
+		System::set_block_number(11);
+		assert_ok!(Vesting::claim(Origin::signed(BOB), DUST));
+		assert_eq!(Vesting::vesting_schedules(BOB, DUST).len(), 1);
+		assert_ok!(Vesting::vested_transfer(
+			Origin::signed(ALICE),
+			BOB,
+			DUST,
+			linear(11, 1, 50)
+		));
+		assert_eq!(Tokens::accounts(BOB, DUST).frozen, 100);
+	});
+}
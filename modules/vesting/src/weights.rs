@@ -0,0 +1,43 @@
+//! Estimated weights for module_vesting.
+//!
+//! These are hand-written estimates, not benchmark output. Replace them with
+//! generated weights once the pallet has benchmarks.
+
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::Weight};
+use sp_std::marker::PhantomData;
+
+/// Weight functions for module_vesting.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> crate::WeightInfo for WeightInfo<T> {
+	fn vested_transfer() -> Weight {
+		(97_452_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+	}
+	fn claim(s: u32) -> Weight {
+		(41_905_000 as Weight)
+			// Standard Error: 11_000
+			.saturating_add((152_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+}
+
+use frame_support::weights::constants::RocksDbWeight as DbWeight;
+impl crate::WeightInfo for () {
+	fn vested_transfer() -> Weight {
+		(97_452_000 as Weight)
+			.saturating_add(DbWeight::get().reads(6 as Weight))
+			.saturating_add(DbWeight::get().writes(6 as Weight))
+	}
+	fn claim(s: u32) -> Weight {
+		(41_905_000 as Weight)
+			// Standard Error: 11_000
+			.saturating_add((152_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(DbWeight::get().reads(4 as Weight))
+			.saturating_add(DbWeight::get().writes(4 as Weight))
+	}
+}
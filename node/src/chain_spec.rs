@@ -1,10 +1,10 @@
 use sp_core::{Pair, Public, sr25519, H160, Bytes};
 use dust_runtime::{
 	AccountId, CurrencyId,
-	BabeConfig, BalancesConfig, GenesisConfig, SudoConfig, SystemConfig,
+	BabeConfig, BalancesConfig, GenesisConfig, SystemConfig,
 	IndicesConfig, EVMConfig, StakingConfig, SessionConfig, AuthorityDiscoveryConfig,
 	WASM_BINARY,
-	TokenSymbol, TokensConfig, DUST,
+	TokenSymbol, TokensConfig, VestingConfig, DAYS, DUST,
 	StakerStatus,
 	ImOnlineId, AuthorityDiscoveryId,
 	MaxNativeTokenExistentialDeposit,
@@ -25,7 +25,7 @@ use serde::{Deserialize, Serialize};
 use hex_literal::hex;
 use sp_core::{crypto::UncheckedInto, bytes::from_hex};
 
-use dust_primitives::{AccountPublic, Balance, Nonce};
+use dust_primitives::{AccountPublic, Balance, BlockNumber, Nonce};
 use dust_runtime::BABE_GENESIS_EPOCH_CONFIG;
 
 // The URL for the telemetry server.
@@ -98,8 +98,6 @@ pub fn development_config() -> Result<ChainSpec, String> {
 			vec![
 				get_authority_keys_from_seed("Alice"),
 			],
-			// Sudo account
-			get_account_id_from_seed::<sr25519::Public>("Alice"),
 			// Pre-funded accounts
 			vec![
 				get_account_id_from_seed::<sr25519::Public>("Alice"),
@@ -136,8 +134,6 @@ pub fn local_testnet_config() -> Result<ChainSpec, String> {
 				get_authority_keys_from_seed("Alice"),
 				get_authority_keys_from_seed("Bob"),
 			],
-			// Sudo account
-			get_account_id_from_seed::<sr25519::Public>("Alice"),
 			// Pre-funded accounts
 			vec![
 				get_account_id_from_seed::<sr25519::Public>("Alice"),
@@ -211,8 +207,6 @@ pub fn public_testnet_config() -> Result<ChainSpec, String> {
 					hex!["00858734321b53f0987a45906cbb91fe7ce1588fce03758c7c07f09022372c30"].unchecked_into(),
 				),
 			],
-			// Sudo
-			hex!["0c994e7589709a85128a6695254af16227f7873816ae0269aa705861c315ba1e"].into(),
 			// Endowed accounts
 			vec![
 				hex!["0c994e7589709a85128a6695254af16227f7873816ae0269aa705861c315ba1e"].into(),
@@ -285,8 +279,6 @@ pub fn mainnet_config() -> Result<ChainSpec, String> {
 					hex!["36719e3625298854880ade3a3a19bd69384550967939b5988d8831b3a7b11128"].unchecked_into(),
 				),
 			],
-			// Sudo
-			hex!["bc30713c8c949b55557d600b0e9e3ed26e9eb60f031404f953281750c6ec4e2d"].into(),
 			// Endowed accounts
 			vec![
 				// Investors
@@ -300,11 +292,26 @@ pub fn mainnet_config() -> Result<ChainSpec, String> {
 				(hex!["029ddde77f1a64af663e40494369feb06402df9c5b2b7bcb8547ecbb4424fe54"].into(), 250_000_000 as u128),
 				(hex!["98f84017133351d6769c227a6dc1d504ffaa9de4b287ce37ce30fc944221fb74"].into(), 250_000_000 as u128),
 				(hex!["1e291d5ba71592e71c5f1d32f3e8d4e96fd895c67291fa3836b36fcf5689e049"].into(), 250_000_000 as u128),
-				// Sudo
+				// Former sudo key
 				(hex!["bc30713c8c949b55557d600b0e9e3ed26e9eb60f031404f953281750c6ec4e2d"].into(), 100_000_000 as u128),
 				// Developer pool & faucet
 				(hex!["9468fc327624243168529b89eed8254b893364a3ae35fe2e31566ab9e4266e0b"].into(), 10_000_000 as u128),
 			],
+			// Vesting schedules of the endowed accounts:
+			// account, currency, start, cliff, period, period count, amount per period
+			vec![
+				// Investors: monthly over 2 years, after a 6 month cliff
+				(hex!["d8999d2094d6f7a7a5cc61932b51df2786c21d1c1058ec0eebbd98635c9a8e21"].into(), CurrencyId::Token(TokenSymbol::DUST), 0, 180 * DAYS, 30 * DAYS, 24, 180_870_569 * DUST),
+				// Lockup & core nominators: all at once after a year
+				(hex!["6e96d4d1c70847745aa40e07694867eb4eda164a1cc3be336439aaa416e4e642"].into(), CurrencyId::Token(TokenSymbol::DUST), 0, 365 * DAYS, 365 * DAYS, 1, 500_000_000 * DUST),
+				(hex!["dad98c159046c5be8ea8781863add146a24eda17f609bfa3075e3ec7f519d070"].into(), CurrencyId::Token(TokenSymbol::DUST), 0, 365 * DAYS, 365 * DAYS, 1, 500_000_000 * DUST),
+				(hex!["f0a7b25715e5cfc9b1573033a1959e0488a8739ed5e7cd56ad803abf135e687e"].into(), CurrencyId::Token(TokenSymbol::DUST), 0, 365 * DAYS, 365 * DAYS, 1, 500_000_000 * DUST),
+				(hex!["029ddde77f1a64af663e40494369feb06402df9c5b2b7bcb8547ecbb4424fe54"].into(), CurrencyId::Token(TokenSymbol::DUST), 0, 365 * DAYS, 365 * DAYS, 1, 250_000_000 * DUST),
+				(hex!["98f84017133351d6769c227a6dc1d504ffaa9de4b287ce37ce30fc944221fb74"].into(), CurrencyId::Token(TokenSymbol::DUST), 0, 365 * DAYS, 365 * DAYS, 1, 250_000_000 * DUST),
+				(hex!["1e291d5ba71592e71c5f1d32f3e8d4e96fd895c67291fa3836b36fcf5689e049"].into(), CurrencyId::Token(TokenSymbol::DUST), 0, 365 * DAYS, 365 * DAYS, 1, 250_000_000 * DUST),
+				// Former sudo key: monthly over 10 months
+				(hex!["bc30713c8c949b55557d600b0e9e3ed26e9eb60f031404f953281750c6ec4e2d"].into(), CurrencyId::Token(TokenSymbol::DUST), 0, 0, 30 * DAYS, 10, 10_000_000 * DUST),
+			],
 		),
 		// Bootnodes
 		vec![
@@ -326,7 +333,6 @@ pub fn mainnet_config() -> Result<ChainSpec, String> {
 fn testnet_genesis(
 	wasm_binary: &[u8],
 	initial_authorities: Vec<(AccountId, AccountId, GrandpaId, BabeId, ImOnlineId, AuthorityDiscoveryId)>,
-	root_key: AccountId,
 	endowed_accounts: Vec<AccountId>,
 ) -> GenesisConfig {
 
@@ -408,19 +414,20 @@ fn testnet_genesis(
 				})
 				.collect(),
 		},
+		vesting: VestingConfig { vesting: vec![] },
 		evm: EVMConfig {
 			accounts: evm_genesis_accounts,
 		},
-		sudo: SudoConfig { key: root_key },
 		tech_council: Default::default(),
+		treasury: Default::default(),
 	}
 }
 
 fn mainnet_genesis(
 	wasm_binary: &[u8],
 	initial_authorities: Vec<(AccountId, AccountId, GrandpaId, BabeId, ImOnlineId, AuthorityDiscoveryId)>,
-	root_key: AccountId,
 	endowed_accounts: Vec<(AccountId, Balance)>,
+	vesting: Vec<(AccountId, CurrencyId, BlockNumber, BlockNumber, BlockNumber, u32, Balance)>,
 ) -> GenesisConfig {
 
 	let evm_genesis_accounts = evm_genesis();
@@ -493,11 +500,12 @@ fn mainnet_genesis(
 		tokens: TokensConfig {
 			balances: vec![]
 		},
+		vesting: VestingConfig { vesting },
 		evm: EVMConfig {
 			accounts: evm_genesis_accounts,
 		},
-		sudo: SudoConfig { key: root_key },
 		tech_council: Default::default(),
+		treasury: Default::default(),
 	}
 }
 
@@ -14,7 +14,7 @@ pub use sc_rpc_api::DenyUnsafe;
 use sc_transaction_pool_api::TransactionPool;
 
 use dust_runtime::{
-	AccountId, Balance, Nonce, BlockNumber, Hash,
+	AccountId, Balance, CurrencyId, Nonce, BlockNumber, Hash,
 	opaque::Block,
 };
 
@@ -28,6 +28,13 @@ use sc_finality_grandpa::{
 	SharedVoterState
 };
 pub use evm_rpc::{EVMApi, EVMApiServer, EVMRuntimeRPCApi};
+pub use poc_rpc::{Poc, PocApiServer, PocRuntimeApi};
+pub use transaction_payment_rpc::{
+	FeeDiscountApiServer, FeeDiscountQuery, FeeDiscountRuntimeApi, MultiCurrencyFeeApiServer, MultiCurrencyFeeQuery,
+	MultiCurrencyFeeRuntimeApi,
+};
+pub use transaction_pause_rpc::{TransactionPause, TransactionPauseApiServer, TransactionPauseRuntimeApi};
+pub use vesting_rpc::{Vesting, VestingApiServer, VestingRuntimeApi};
 pub use sc_rpc::SubscriptionTaskExecutor;
 
 /// Extra dependencies for BABE.
@@ -80,6 +87,11 @@ pub fn create_full<C, P, SC, B>(
 	C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
 	C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
 	C::Api: EVMRuntimeRPCApi<Block, Balance>,
+	C::Api: PocRuntimeApi<Block, AccountId, Balance, BlockNumber>,
+	C::Api: MultiCurrencyFeeRuntimeApi<Block, AccountId, Balance>,
+	C::Api: FeeDiscountRuntimeApi<Block, AccountId>,
+	C::Api: TransactionPauseRuntimeApi<Block>,
+	C::Api: VestingRuntimeApi<Block, AccountId, CurrencyId, Balance>,
 	C::Api: sp_consensus_babe::BabeApi<Block>,
 	C::Api: BlockBuilder<Block>,
 	P: TransactionPool + Sync + Send + 'static,
@@ -145,6 +157,13 @@ pub fn create_full<C, P, SC, B>(
 		subscription_executor,
 		finality_provider,
 	)));
+	io.extend_with(PocApiServer::to_delegate(Poc::<_, _, Balance>::new(client.clone())));
+	io.extend_with(MultiCurrencyFeeApiServer::to_delegate(
+		MultiCurrencyFeeQuery::<_, _, Balance>::new(client.clone()),
+	));
+	io.extend_with(FeeDiscountApiServer::to_delegate(FeeDiscountQuery::new(client.clone())));
+	io.extend_with(TransactionPauseApiServer::to_delegate(TransactionPause::new(client.clone())));
+	io.extend_with(VestingApiServer::to_delegate(Vesting::<_, _, Balance>::new(client.clone())));
 	io.extend_with(EVMApiServer::to_delegate(EVMApi::new(client)));
 
 	Ok(io)
@@ -173,6 +173,8 @@ pub fn new_full(mut config: Configuration) -> Result<TaskManager, ServiceError>
 			warp_sync: Some(warp_sync)
 		})?;
 
+	// The offchain workers of validators mine the unsigned solutions of the
+	// staking election, and submit them through the transaction pool.
 	if config.offchain_worker.enabled {
 		sc_service::build_offchain_workers(
 			&config,
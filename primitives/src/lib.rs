@@ -151,12 +151,16 @@ impl TryFrom<u8> for TokenSymbol {
 	}
 }
 
+/// Identifier of an asset registered in the asset registry.
+pub type ForeignAssetId = u16;
+
 #[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, PartialOrd, Ord)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum CurrencyId {
 	Token(TokenSymbol),
 	DEXShare(TokenSymbol, TokenSymbol),
 	ERC20(EvmAddress),
+	ForeignAsset(ForeignAssetId),
 }
 
 impl CurrencyId {
@@ -187,12 +191,61 @@ impl CurrencyId {
 	}
 }
 
+/// A pair of currencies traded on the DEX, sorted so that every pair has a
+/// single representation.
+#[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, PartialOrd, Ord)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct TradingPair(CurrencyId, CurrencyId);
+
+impl TradingPair {
+	pub fn from_currency_ids(currency_id_a: CurrencyId, currency_id_b: CurrencyId) -> Option<Self> {
+		if currency_id_a.is_token_currency_id() && currency_id_b.is_token_currency_id() && currency_id_a != currency_id_b
+		{
+			if currency_id_a > currency_id_b {
+				Some(TradingPair(currency_id_b, currency_id_a))
+			} else {
+				Some(TradingPair(currency_id_a, currency_id_b))
+			}
+		} else {
+			None
+		}
+	}
+
+	pub fn first(&self) -> CurrencyId {
+		self.0
+	}
+
+	pub fn second(&self) -> CurrencyId {
+		self.1
+	}
+
+	pub fn dex_share_currency_id(&self) -> CurrencyId {
+		CurrencyId::join_dex_share_currency_id(self.0, self.1).expect("trading pair is always made of tokens; qed")
+	}
+}
+
+/// The type tag of `CurrencyId::ERC20` in the 32-byte encoding.
+///
+/// The 32-byte encoding of a `CurrencyId` is laid out as follows:
+/// - Token: `[0u8; 29] ++ [0, symbol, 0]`
+/// - DEXShare: `[0u8; 29] ++ [1, left, right]`
+/// - ERC20: `[0u8; 11] ++ [2] ++ contract_address`
+/// - ForeignAsset: `[0u8; 29] ++ [3] ++ foreign_asset_id.to_be_bytes()`
+pub const CURRENCY_ID_ERC20_TAG: u8 = 2;
+/// The type tag of `CurrencyId::ForeignAsset` in the 32-byte encoding.
+pub const CURRENCY_ID_FOREIGN_ASSET_TAG: u8 = 3;
+
 /// Note the pre-deployed ERC20 contracts depend on `CurrencyId` implementation,
 /// and need to be updated if any change.
 impl TryFrom<[u8; 32]> for CurrencyId {
 	type Error = ();
 
 	fn try_from(v: [u8; 32]) -> Result<Self, Self::Error> {
+		// ERC20
+		if v.starts_with(&[0u8; 11][..]) && v[11] == CURRENCY_ID_ERC20_TAG {
+			return Ok(CurrencyId::ERC20(EvmAddress::from_slice(&v[12..])));
+		}
+
 		if !v.starts_with(&[0u8; 29][..]) {
 			return Err(());
 		}
@@ -209,6 +262,11 @@ impl TryFrom<[u8; 32]> for CurrencyId {
 			return Ok(CurrencyId::DEXShare(left, right));
 		}
 
+		// foreign asset
+		if v[29] == CURRENCY_ID_FOREIGN_ASSET_TAG {
+			return Ok(CurrencyId::ForeignAsset(ForeignAssetId::from_be_bytes([v[30], v[31]])));
+		}
+
 		Err(())
 	}
 }
@@ -227,7 +285,14 @@ impl From<CurrencyId> for [u8; 32] {
 				bytes[30] = left as u8;
 				bytes[31] = right as u8;
 			}
-			_ => {}
+			CurrencyId::ERC20(address) => {
+				bytes[11] = CURRENCY_ID_ERC20_TAG;
+				bytes[12..].copy_from_slice(address.as_bytes());
+			}
+			CurrencyId::ForeignAsset(foreign_asset_id) => {
+				bytes[29] = CURRENCY_ID_FOREIGN_ASSET_TAG;
+				bytes[30..].copy_from_slice(&foreign_asset_id.to_be_bytes());
+			}
 		}
 		bytes
 	}
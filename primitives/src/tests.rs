@@ -1,6 +1,7 @@
 use super::*;
 
 use frame_support::{assert_err, assert_ok};
+use proptest::prelude::*;
 
 #[test]
 fn currency_id_to_bytes_works() {
@@ -36,3 +37,98 @@ fn currency_id_encode_decode_bytes_works() {
 	let bytes: [u8; 32] = currency_id.into();
 	assert_ok!(bytes.try_into(), currency_id)
 }
+
+#[test]
+fn erc20_currency_id_to_bytes_works() {
+	let address = EvmAddress::from_low_u64_be(0x0100_0000);
+	let mut bytes = [0u8; 32];
+	bytes[11] = CURRENCY_ID_ERC20_TAG;
+	bytes[12..].copy_from_slice(address.as_bytes());
+	assert_eq!(Into::<[u8; 32]>::into(CurrencyId::ERC20(address)), bytes);
+	assert_ok!(bytes.try_into(), CurrencyId::ERC20(address));
+
+	// the zero address is still distinguishable from DUST
+	let bytes: [u8; 32] = CurrencyId::ERC20(EvmAddress::zero()).into();
+	assert_ne!(bytes, [0u8; 32]);
+	assert_ok!(bytes.try_into(), CurrencyId::ERC20(EvmAddress::zero()));
+
+	let mut bytes = [0u8; 32];
+	bytes[10] = 1;
+	bytes[11] = CURRENCY_ID_ERC20_TAG;
+	assert_err!(TryInto::<CurrencyId>::try_into(bytes), ());
+
+	let mut bytes = [0u8; 32];
+	bytes[11] = 3;
+	assert_err!(TryInto::<CurrencyId>::try_into(bytes), ());
+}
+
+#[test]
+fn foreign_asset_currency_id_to_bytes_works() {
+	let mut bytes = [0u8; 32];
+	bytes[29..].copy_from_slice(&[CURRENCY_ID_FOREIGN_ASSET_TAG, 1, 2][..]);
+	assert_eq!(Into::<[u8; 32]>::into(CurrencyId::ForeignAsset(0x0102)), bytes);
+	assert_ok!(bytes.try_into(), CurrencyId::ForeignAsset(0x0102));
+
+	let mut bytes = [0u8; 32];
+	bytes[28] = 1;
+	bytes[29] = CURRENCY_ID_FOREIGN_ASSET_TAG;
+	assert_err!(TryInto::<CurrencyId>::try_into(bytes), ());
+}
+
+#[test]
+fn trading_pair_works() {
+	let dust = CurrencyId::Token(TokenSymbol::DUST);
+	let usdd = CurrencyId::Token(TokenSymbol::USDD);
+	let trading_pair = TradingPair::from_currency_ids(usdd, dust).unwrap();
+
+	assert_eq!(TradingPair::from_currency_ids(dust, usdd), Some(trading_pair));
+	assert_eq!(trading_pair.first(), dust);
+	assert_eq!(trading_pair.second(), usdd);
+	assert_eq!(
+		trading_pair.dex_share_currency_id(),
+		CurrencyId::DEXShare(TokenSymbol::DUST, TokenSymbol::USDD)
+	);
+
+	assert_eq!(TradingPair::from_currency_ids(dust, dust), None);
+	assert_eq!(
+		TradingPair::from_currency_ids(dust, CurrencyId::DEXShare(TokenSymbol::DUST, TokenSymbol::USDD)),
+		None
+	);
+	assert_eq!(TradingPair::from_currency_ids(dust, CurrencyId::ForeignAsset(0)), None);
+}
+
+fn token_symbol() -> impl Strategy<Value = TokenSymbol> {
+	prop_oneof![Just(TokenSymbol::DUST), Just(TokenSymbol::USDD)]
+}
+
+fn currency_id() -> impl Strategy<Value = CurrencyId> {
+	prop_oneof![
+		token_symbol().prop_map(CurrencyId::Token),
+		(token_symbol(), token_symbol()).prop_map(|(left, right)| CurrencyId::DEXShare(left, right)),
+		any::<[u8; 20]>().prop_map(|address| CurrencyId::ERC20(EvmAddress::from(address))),
+		any::<ForeignAssetId>().prop_map(CurrencyId::ForeignAsset),
+	]
+}
+
+proptest! {
+	#[test]
+	fn currency_id_bytes_round_trip(currency_id in currency_id()) {
+		let bytes: [u8; 32] = currency_id.into();
+		prop_assert_eq!(CurrencyId::try_from(bytes), Ok(currency_id));
+	}
+
+	#[test]
+	fn currency_id_bytes_are_canonical(bytes in any::<[u8; 32]>()) {
+		if let Ok(currency_id) = CurrencyId::try_from(bytes) {
+			prop_assert_eq!(Into::<[u8; 32]>::into(currency_id), bytes);
+		}
+	}
+
+	#[test]
+	fn erc20_currency_id_bytes_are_canonical(address in any::<[u8; 20]>()) {
+		let mut bytes = [0u8; 32];
+		bytes[11] = CURRENCY_ID_ERC20_TAG;
+		bytes[12..].copy_from_slice(&address);
+		prop_assert_eq!(CurrencyId::try_from(bytes), Ok(CurrencyId::ERC20(EvmAddress::from(address))));
+	}
+}
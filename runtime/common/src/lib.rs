@@ -25,6 +25,7 @@ pub use module_support::{PrecompileCallerFilter};
 pub mod precompile;
 pub use precompile::{
 	AllPrecompiles,
+	DexPrecompile,
 	MultiCurrencyPrecompile,
 	ScheduleCallPrecompile,
 	StateRentPrecompile,
@@ -64,6 +65,14 @@ impl Convert<u64, Weight> for GasToWeight {
 	}
 }
 
+/// Convert weight to gas
+pub struct WeightToGas;
+impl Convert<Weight, u64> for WeightToGas {
+	fn convert(a: Weight) -> u64 {
+		a as u64
+	}
+}
+
 pub const AVERAGE_ON_INITIALIZE_RATIO: Perbill = Perbill::from_perthousand(25);
 /// We allow `Normal` extrinsics to fill up the block up to 75%, the rest can be
 /// used by  Operational  extrinsics.
@@ -99,15 +108,22 @@ parameter_types! {
 }
 
 parameter_types! {
-	/// A limit for off-chain phragmen unsigned solution submission.
+	/// Largest weight of an election solution mined by the offchain worker,
+	/// or submitted by a signed miner.
 	///
-	/// We want to keep it as high as possible, but can't risk having it reject,
-	/// so we always subtract the base block execution weight.
+	/// It is the most a normal extrinsic can weigh, less the base weight of
+	/// the block, so the solution always fits in the block it is mined for.
 	pub OffchainSolutionWeightLimit: Weight = BlockWeights::get()
 		.get(DispatchClass::Normal)
 		.max_extrinsic
 		.expect("Normal extrinsics have weight limit configured by default; qed")
 		.saturating_sub(BlockExecutionWeight::get());
+	/// Largest encoded length of an election solution mined by the offchain
+	/// worker, 90% of the normal block length so other extrinsics still fit.
+	pub OffchainSolutionLengthLimit: u32 = Perbill::from_rational(90_u32, 100) *
+		*BlockLength::get()
+		.max
+		.get(DispatchClass::Normal);
 }
 
 
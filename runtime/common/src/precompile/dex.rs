@@ -0,0 +1,243 @@
+use frame_support::weights::{constants::RocksDbWeight, Weight};
+use module_dex::WeightInfo;
+use module_evm::{Context, ExitError, ExitSucceed, Precompile};
+use module_support::{DEXManager, TransactionPaused as TransactionPausedT};
+use primitives::evm::AddressMapping as AddressMappingT;
+use sp_core::U256;
+use sp_runtime::traits::Convert;
+use sp_std::{convert::TryFrom, fmt::Debug, marker::PhantomData, prelude::*, result};
+
+use super::input::{Input, InputT};
+use crate::WeightToGas;
+use primitives::{Balance, CurrencyId};
+
+/// The `DEX` impl precompile.
+///
+///
+/// `input` data starts with `action`.
+///
+/// Actions:
+/// - Get liquidity pool. Rest `input` bytes: `currency_id_a`, `currency_id_b`.
+/// - Swap pair with exact supply. Rest `input` bytes: `who`, `currency_id_a`,
+///   `currency_id_b`, `supply_amount`, `min_target_amount`.
+/// - Get swap target amount. Rest `input` bytes: `path_len`, `supply_amount`,
+///   `path`.
+/// - Get swap supply amount. Rest `input` bytes: `path_len`, `target_amount`,
+///   `path`.
+/// - Swap with exact supply. Rest `input` bytes: `who`, `path_len`,
+///   `supply_amount`, `min_target_amount`, `path`.
+/// - Swap with exact target. Rest `input` bytes: `who`, `path_len`,
+///   `target_amount`, `max_supply_amount`, `path`.
+///
+/// The first two actions are the ones the predeployed `DEX` contract calls,
+/// so their numbering and layout must not change.
+///
+/// Queries cost the storage reads they make. Swaps cost the weight of the
+/// matching `module_dex` call, taken from `DexWeightInfo`, and fail while that
+/// call is paused.
+pub struct DexPrecompile<AccountId, AddressMapping, Dex, DexWeightInfo, TransactionPaused>(
+	PhantomData<(AccountId, AddressMapping, Dex, DexWeightInfo, TransactionPaused)>,
+);
+
+/// Name of the DEX pallet in the runtime, as paused by the transaction pause.
+const PALLET_NAME: &str = "Dex";
+
+enum Action {
+	GetLiquidityPool,
+	SwapPairWithExactSupply,
+	GetSwapTargetAmount,
+	GetSwapSupplyAmount,
+	SwapWithExactSupply,
+	SwapWithExactTarget,
+}
+
+impl TryFrom<u8> for Action {
+	type Error = ();
+
+	fn try_from(value: u8) -> Result<Self, Self::Error> {
+		match value {
+			0 => Ok(Action::GetLiquidityPool),
+			1 => Ok(Action::SwapPairWithExactSupply),
+			2 => Ok(Action::GetSwapTargetAmount),
+			3 => Ok(Action::GetSwapSupplyAmount),
+			4 => Ok(Action::SwapWithExactSupply),
+			5 => Ok(Action::SwapWithExactTarget),
+			_ => Err(()),
+		}
+	}
+}
+
+impl<AccountId, AddressMapping, Dex, DexWeightInfo, TransactionPaused> Precompile
+	for DexPrecompile<AccountId, AddressMapping, Dex, DexWeightInfo, TransactionPaused>
+where
+	AccountId: Debug + Clone,
+	AddressMapping: AddressMappingT<AccountId>,
+	Dex: DEXManager<AccountId, CurrencyId, Balance>,
+	DexWeightInfo: WeightInfo,
+	TransactionPaused: TransactionPausedT,
+{
+	fn execute(
+		input: &[u8],
+		target_gas: Option<u64>,
+		_context: &Context,
+	) -> result::Result<(ExitSucceed, Vec<u8>, u64), ExitError> {
+		log::debug!(target: "evm", "dex input: {:?}", input);
+
+		let input = Input::<Action, AccountId, AddressMapping>::new(input);
+
+		let action = input.action()?;
+
+		if let Some(function_name) = Self::call_name(&action) {
+			if TransactionPaused::is_paused(PALLET_NAME, function_name) {
+				return Err(ExitError::Other("Transaction paused".into()));
+			}
+		}
+
+		let gas_cost = WeightToGas::convert(Self::weight_of(&action, &input)?);
+		if let Some(gas_limit) = target_gas {
+			if gas_cost > gas_limit {
+				return Err(ExitError::OutOfGas);
+			}
+		}
+
+		match action {
+			Action::GetLiquidityPool => {
+				let currency_id_a = input.currency_id_at(1)?;
+				let currency_id_b = input.currency_id_at(2)?;
+
+				let (balance_a, balance_b) = Dex::get_liquidity_pool(currency_id_a, currency_id_b);
+				log::debug!(
+					target: "evm",
+					"dex: get_liquidity_pool {:?}, {:?}: {:?}, {:?}",
+					currency_id_a, currency_id_b, balance_a, balance_b,
+				);
+
+				let mut output = vec_u8_from_balance(balance_a);
+				output.extend_from_slice(&vec_u8_from_balance(balance_b));
+				Ok((ExitSucceed::Returned, output, gas_cost))
+			}
+			Action::SwapPairWithExactSupply => {
+				let who = input.account_id_at(1)?;
+				let path = vec![input.currency_id_at(2)?, input.currency_id_at(3)?];
+				let supply_amount = input.balance_at(4)?;
+				let min_target_amount = input.balance_at(5)?;
+
+				log::debug!(target: "evm", "dex: swap_with_exact_supply who: {:?}, path: {:?}", who, path);
+
+				let target_amount = Dex::swap_with_exact_supply(&who, &path, supply_amount, min_target_amount, None)
+					.map_err(|e| {
+						let err_msg: &str = e.into();
+						ExitError::Other(err_msg.into())
+					})?;
+
+				Ok((ExitSucceed::Returned, vec_u8_from_balance(target_amount), gas_cost))
+			}
+			Action::GetSwapTargetAmount => {
+				let path = path_at(&input, 1, 3)?;
+				let supply_amount = input.balance_at(2)?;
+
+				let target_amount = Dex::get_swap_target_amount(&path, supply_amount, None).unwrap_or_default();
+				log::debug!(target: "evm", "dex: get_swap_target_amount {:?}: {:?}", path, target_amount);
+
+				Ok((ExitSucceed::Returned, vec_u8_from_balance(target_amount), gas_cost))
+			}
+			Action::GetSwapSupplyAmount => {
+				let path = path_at(&input, 1, 3)?;
+				let target_amount = input.balance_at(2)?;
+
+				let supply_amount = Dex::get_swap_supply_amount(&path, target_amount, None).unwrap_or_default();
+				log::debug!(target: "evm", "dex: get_swap_supply_amount {:?}: {:?}", path, supply_amount);
+
+				Ok((ExitSucceed::Returned, vec_u8_from_balance(supply_amount), gas_cost))
+			}
+			Action::SwapWithExactSupply => {
+				let who = input.account_id_at(1)?;
+				let path = path_at(&input, 2, 5)?;
+				let supply_amount = input.balance_at(3)?;
+				let min_target_amount = input.balance_at(4)?;
+
+				log::debug!(target: "evm", "dex: swap_with_exact_supply who: {:?}, path: {:?}", who, path);
+
+				let target_amount = Dex::swap_with_exact_supply(&who, &path, supply_amount, min_target_amount, None)
+					.map_err(|e| {
+						let err_msg: &str = e.into();
+						ExitError::Other(err_msg.into())
+					})?;
+
+				Ok((ExitSucceed::Returned, vec_u8_from_balance(target_amount), gas_cost))
+			}
+			Action::SwapWithExactTarget => {
+				let who = input.account_id_at(1)?;
+				let path = path_at(&input, 2, 5)?;
+				let target_amount = input.balance_at(3)?;
+				let max_supply_amount = input.balance_at(4)?;
+
+				log::debug!(target: "evm", "dex: swap_with_exact_target who: {:?}, path: {:?}", who, path);
+
+				let supply_amount = Dex::swap_with_exact_target(&who, &path, target_amount, max_supply_amount, None)
+					.map_err(|e| {
+						let err_msg: &str = e.into();
+						ExitError::Other(err_msg.into())
+					})?;
+
+				Ok((ExitSucceed::Returned, vec_u8_from_balance(supply_amount), gas_cost))
+			}
+		}
+	}
+}
+
+impl<AccountId, AddressMapping, Dex, DexWeightInfo, TransactionPaused>
+	DexPrecompile<AccountId, AddressMapping, Dex, DexWeightInfo, TransactionPaused>
+where
+	AddressMapping: AddressMappingT<AccountId>,
+	DexWeightInfo: WeightInfo,
+{
+	/// The `module_dex` call a swap stands for.
+	fn call_name(action: &Action) -> Option<&'static str> {
+		match action {
+			Action::SwapPairWithExactSupply | Action::SwapWithExactSupply => Some("swap_with_exact_supply"),
+			Action::SwapWithExactTarget => Some("swap_with_exact_target"),
+			_ => None,
+		}
+	}
+
+	fn weight_of(
+		action: &Action,
+		input: &Input<Action, AccountId, AddressMapping>,
+	) -> result::Result<Weight, ExitError> {
+		let weight = match action {
+			Action::GetLiquidityPool => RocksDbWeight::get().reads(1),
+			// one pool read per hop
+			Action::GetSwapTargetAmount | Action::GetSwapSupplyAmount => {
+				let path_len = input.u32_at(1)?;
+				RocksDbWeight::get().reads(path_len.saturating_sub(1).into())
+			}
+			Action::SwapPairWithExactSupply | Action::SwapWithExactSupply => DexWeightInfo::swap_with_exact_supply(),
+			Action::SwapWithExactTarget => DexWeightInfo::swap_with_exact_target(),
+		};
+		Ok(weight)
+	}
+}
+
+/// Read a trading path whose length is at `len_index` and whose currency ids
+/// start at `start_index`.
+fn path_at<Action, AccountId, AddressMapping>(
+	input: &Input<Action, AccountId, AddressMapping>,
+	len_index: usize,
+	start_index: usize,
+) -> result::Result<Vec<CurrencyId>, ExitError>
+where
+	Action: TryFrom<u8>,
+	AddressMapping: AddressMappingT<AccountId>,
+{
+	let path_len = input.u32_at(len_index)?;
+	(0..path_len as usize)
+		.map(|i| input.currency_id_at(start_index + i))
+		.collect()
+}
+
+fn vec_u8_from_balance(balance: Balance) -> Vec<u8> {
+	let mut be_bytes = [0u8; 32];
+	U256::from(balance).to_big_endian(&mut be_bytes[..]);
+	be_bytes.to_vec()
+}
@@ -247,6 +247,12 @@ mod tests {
 		raw_input[30] = 1;
 		let input = TestInput::new(&raw_input[..]);
 		assert_ok!(input.currency_id_at(0), CurrencyId::Token(TokenSymbol::USDD));
+
+		let mut raw_input = [0u8; 32];
+		raw_input[11] = 2;
+		raw_input[31] = 1;
+		let input = TestInput::new(&raw_input[..]);
+		assert_ok!(input.currency_id_at(0), CurrencyId::ERC20(H160::from_low_u64_be(1)));
 	}
 
 	#[test]
@@ -6,20 +6,22 @@ use frame_support::{
 	assert_ok, ord_parameter_types, parameter_types,
 	traits::{GenesisBuild, InstanceFilter, OnFinalize, OnInitialize},
 	weights::IdentityFee,
-	RuntimeDebug,
+	PalletId, RuntimeDebug,
 };
 use frame_system::{EnsureRoot, EnsureSignedBy};
 use orml_traits::{parameter_type_with_key};
 pub use primitives::{
 	evm::AddressMapping, mocks::MockAddressMapping,
-	Amount, BlockNumber, CurrencyId, Header, Nonce, TokenSymbol,
+	Amount, BlockNumber, CurrencyId, Header, Nonce, TokenSymbol, TradingPair,
 };
 use sp_core::{crypto::AccountId32, bytes::from_hex, Bytes, H160, H256};
+use module_support::Ratio;
 use sp_runtime::{
-	traits::{BlakeTwo256, Convert, IdentityLookup},
+	traits::{BlakeTwo256, Convert, IdentityLookup, One},
 	Perbill,
 };
 use sp_std::{collections::btree_map::BTreeMap, str::FromStr};
+use std::cell::RefCell;
 
 pub type AccountId = AccountId32;
 type Balance = u128;
@@ -111,6 +113,23 @@ impl module_currencies::Config for Test {
 	type WeightInfo = ();
 	type AddressMapping = MockAddressMapping;
 	type EVMBridge = EVMBridge;
+	type AssetRegistry = MockAssetRegistry;
+	type Erc20ReservesBeforeTracking = ();
+}
+
+pub struct MockAssetRegistry;
+impl module_support::AssetRegistry<Balance> for MockAssetRegistry {
+	fn asset_metadata(currency_id: CurrencyId) -> Option<module_support::AssetMetadata<Balance>> {
+		match currency_id {
+			USDD => Some(module_support::AssetMetadata {
+				name: b"USD Dust".to_vec(),
+				symbol: b"USDD".to_vec(),
+				decimals: 12,
+				minimal_balance: 1,
+			}),
+			_ => None,
+		}
+	}
 }
 
 impl module_evm_bridge::Config for Test {
@@ -121,6 +140,8 @@ parameter_types! {
 	pub const TransactionByteFee: Balance = 10;
 	pub const GetStableCurrencyId: CurrencyId = CurrencyId::Token(TokenSymbol::USDD);
 	pub AllNonNativeCurrencyIds: Vec<CurrencyId> = vec![CurrencyId::Token(TokenSymbol::USDD)];
+	pub MaxSlippageSwapWithDEX: Ratio = Ratio::one();
+	pub const MaxFeeDiscountPerBlock: Balance = 0;
 }
 
 impl module_transaction_payment::Config for Test {
@@ -133,10 +154,32 @@ impl module_transaction_payment::Config for Test {
 	type TransactionByteFee = TransactionByteFee;
 	type WeightToFee = IdentityFee<Balance>;
 	type FeeMultiplierUpdate = ();
+	type DEX = Dex;
+	type MaxSlippageSwapWithDEX = MaxSlippageSwapWithDEX;
+	type FeeSponsor = ModuleEVM;
+	type FeeDiscount = ();
+	type MaxFeeDiscountPerBlock = MaxFeeDiscountPerBlock;
 	type WeightInfo = ();
 }
 pub type ChargeTransactionPayment = module_transaction_payment::ChargeTransactionPayment<Test>;
 
+parameter_types! {
+	pub EnabledTradingPairs: Vec<TradingPair> = vec![TradingPair::from_currency_ids(DUST, USDD).unwrap()];
+	pub const GetExchangeFee: (u32, u32) = (1, 100);
+	pub const TradingPathLimit: u32 = 3;
+	pub const DEXPalletId: PalletId = PalletId(*b"dust/dex");
+}
+
+impl module_dex::Config for Test {
+	type Event = Event;
+	type Currency = Currencies;
+	type EnabledTradingPairs = EnabledTradingPairs;
+	type GetExchangeFee = GetExchangeFee;
+	type TradingPathLimit = TradingPathLimit;
+	type PalletId = DEXPalletId;
+	type WeightInfo = ();
+}
+
 parameter_types! {
 	pub const ProxyDepositBase: u64 = 1;
 	pub const ProxyDepositFactor: u64 = 1;
@@ -209,7 +252,20 @@ impl pallet_scheduler::Config for Test {
 
 pub type AdaptedBasicCurrency = module_currencies::BasicCurrencyAdapter<Test, Balances, Amount, BlockNumber>;
 
-pub type MultiCurrencyPrecompile = crate::MultiCurrencyPrecompile<AccountId, MockAddressMapping, Currencies>;
+thread_local! {
+	pub static PAUSED_CALLS: RefCell<Vec<(&'static str, &'static str)>> = RefCell::new(vec![]);
+}
+
+/// Pauses the calls listed in `PAUSED_CALLS`.
+pub struct MockTransactionPaused;
+impl module_support::TransactionPaused for MockTransactionPaused {
+	fn is_paused(pallet_name: &str, function_name: &str) -> bool {
+		PAUSED_CALLS.with(|v| v.borrow().contains(&(pallet_name, function_name)))
+	}
+}
+
+pub type MultiCurrencyPrecompile =
+	crate::MultiCurrencyPrecompile<AccountId, MockAddressMapping, Currencies, MockTransactionPaused>;
 
 pub type StateRentPrecompile = crate::StateRentPrecompile<AccountId, MockAddressMapping, ModuleEVM>;
 pub type ScheduleCallPrecompile = crate::ScheduleCallPrecompile<
@@ -222,6 +278,13 @@ pub type ScheduleCallPrecompile = crate::ScheduleCallPrecompile<
 	OriginCaller,
 	Test,
 >;
+pub type DexPrecompile = crate::DexPrecompile<
+	AccountId,
+	MockAddressMapping,
+	Dex,
+	<Test as module_dex::Config>::WeightInfo,
+	MockTransactionPaused,
+>;
 
 parameter_types! {
 	pub NetworkContractSource: H160 = alice();
@@ -258,6 +321,7 @@ impl module_evm::Config for Test {
 		MultiCurrencyPrecompile,
 		StateRentPrecompile,
 		ScheduleCallPrecompile,
+		DexPrecompile,
 	>;
 	type ChainId = ChainId;
 	type GasToWeight = GasToWeight;
@@ -266,6 +330,7 @@ impl module_evm::Config for Test {
 	type NetworkContractSource = NetworkContractSource;
 	type DeveloperDeposit = DeveloperDeposit;
 	type DeploymentFee = DeploymentFee;
+	type OnDeploymentFee = ();
 	type FreeDeploymentOrigin = EnsureSignedBy<CouncilAccount, AccountId>;
 	type WeightInfo = ();
 }
@@ -324,6 +389,7 @@ frame_support::construct_runtime!(
 		Utility: pallet_utility::{Pallet, Call, Event},
 		Scheduler: pallet_scheduler::{Pallet, Call, Storage, Event<T>},
 		ModuleEVM: module_evm::{Pallet, Config<T>, Call, Storage, Event<T>},
+		Dex: module_dex::{Pallet, Call, Storage, Event<T>},
 	}
 );
 
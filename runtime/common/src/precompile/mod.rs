@@ -1,5 +1,6 @@
 //! The precompiles for EVM, includes standard Ethereum precompiles, and more:
 //! - MultiCurrency at address `H160::from_low_u64_be(1024)`.
+//! - DEX at address `H160::from_low_u64_be(1029)`.
 
 #![allow(clippy::upper_case_acronyms)]
 
@@ -22,11 +23,13 @@ use primitives::PRECOMPILE_ADDRESS_START;
 use sp_core::H160;
 use sp_std::{marker::PhantomData, prelude::*};
 
+pub mod dex;
 pub mod input;
 pub mod multicurrency;
 pub mod schedule_call;
 pub mod state_rent;
 
+pub use dex::DexPrecompile;
 pub use multicurrency::MultiCurrencyPrecompile;
 pub use schedule_call::ScheduleCallPrecompile;
 pub use state_rent::StateRentPrecompile;
@@ -36,12 +39,14 @@ pub struct AllPrecompiles<
 	MultiCurrencyPrecompile,
 	StateRentPrecompile,
 	ScheduleCallPrecompile,
+	DexPrecompile,
 >(
 	PhantomData<(
 		PrecompileCallerFilter,
 		MultiCurrencyPrecompile,
 		StateRentPrecompile,
 		ScheduleCallPrecompile,
+		DexPrecompile,
 	)>,
 );
 
@@ -50,16 +55,19 @@ impl<
 		MultiCurrencyPrecompile,
 		StateRentPrecompile,
 		ScheduleCallPrecompile,
+		DexPrecompile,
 	> Precompiles
 	for AllPrecompiles<
 		PrecompileCallerFilter,
 		MultiCurrencyPrecompile,
 		StateRentPrecompile,
 		ScheduleCallPrecompile,
+		DexPrecompile,
 	> where
 	MultiCurrencyPrecompile: Precompile,
 	StateRentPrecompile: Precompile,
 	ScheduleCallPrecompile: Precompile,
+	DexPrecompile: Precompile,
 	PrecompileCallerFilter: PrecompileCallerFilterT,
 {
 	#[allow(clippy::type_complexity)]
@@ -84,6 +92,8 @@ impl<
 				Some(StateRentPrecompile::execute(input, target_gas, context))
 			} else if address == H160::from_low_u64_be(PRECOMPILE_ADDRESS_START + 4) {
 				Some(ScheduleCallPrecompile::execute(input, target_gas, context))
+			} else if address == H160::from_low_u64_be(PRECOMPILE_ADDRESS_START + 5) {
+				Some(DexPrecompile::execute(input, target_gas, context))
 			} else {
 				None
 			}
@@ -6,6 +6,7 @@ use sp_std::{convert::TryFrom, fmt::Debug, marker::PhantomData, prelude::*, resu
 use orml_traits::MultiCurrency as MultiCurrencyT;
 
 use super::input::{Input, InputT};
+use module_support::{CurrencyMetadata, TransactionPaused as TransactionPausedT};
 use primitives::{Balance, CurrencyId};
 
 /// The `MultiCurrency` impl precompile.
@@ -17,14 +18,27 @@ use primitives::{Balance, CurrencyId};
 /// - Query total issuance.
 /// - Query balance. Rest `input` bytes: `account_id`.
 /// - Transfer. Rest `input` bytes: `from`, `to`, `amount`.
-pub struct MultiCurrencyPrecompile<AccountId, AddressMapping, MultiCurrency>(
-	PhantomData<(AccountId, AddressMapping, MultiCurrency)>,
+/// - Query name.
+/// - Query symbol.
+/// - Query decimals.
+///
+/// Transfers fail while the `transfer` call of the currencies pallet is
+/// paused.
+pub struct MultiCurrencyPrecompile<AccountId, AddressMapping, MultiCurrency, TransactionPaused>(
+	PhantomData<(AccountId, AddressMapping, MultiCurrency, TransactionPaused)>,
 );
 
+/// Name of the currencies pallet in the runtime, as paused by the
+/// transaction pause.
+const PALLET_NAME: &str = "Currencies";
+
 enum Action {
 	QueryTotalIssuance,
 	QueryBalance,
 	Transfer,
+	QueryName,
+	QuerySymbol,
+	QueryDecimals,
 }
 
 impl TryFrom<u8> for Action {
@@ -35,17 +49,21 @@ impl TryFrom<u8> for Action {
 			0 => Ok(Action::QueryTotalIssuance),
 			1 => Ok(Action::QueryBalance),
 			2 => Ok(Action::Transfer),
+			3 => Ok(Action::QueryName),
+			4 => Ok(Action::QuerySymbol),
+			5 => Ok(Action::QueryDecimals),
 			_ => Err(()),
 		}
 	}
 }
 
-impl<AccountId, AddressMapping, MultiCurrency> Precompile
-	for MultiCurrencyPrecompile<AccountId, AddressMapping, MultiCurrency>
+impl<AccountId, AddressMapping, MultiCurrency, TransactionPaused> Precompile
+	for MultiCurrencyPrecompile<AccountId, AddressMapping, MultiCurrency, TransactionPaused>
 where
 	AccountId: Debug + Clone,
 	AddressMapping: AddressMappingT<AccountId>,
-	MultiCurrency: MultiCurrencyT<AccountId, Balance = Balance, CurrencyId = CurrencyId>,
+	MultiCurrency: MultiCurrencyT<AccountId, Balance = Balance, CurrencyId = CurrencyId> + CurrencyMetadata,
+	TransactionPaused: TransactionPausedT,
 {
 	fn execute(
 		input: &[u8],
@@ -88,6 +106,9 @@ where
 				log::debug!(target: "evm", "to: {:?}", to);
 				log::debug!(target: "evm", "amount: {:?}", amount);
 
+				if TransactionPaused::is_paused(PALLET_NAME, "transfer") {
+					return Err(ExitError::Other("Transaction paused".into()));
+				}
 				MultiCurrency::transfer(currency_id, &from, &to, amount).map_err(|e| {
 					let err_msg: &str = e.into();
 					ExitError::Other(err_msg.into())
@@ -97,6 +118,27 @@ where
 
 				Ok((ExitSucceed::Returned, vec![], 0))
 			}
+			Action::QueryName => {
+				let name =
+					MultiCurrency::name(currency_id).ok_or_else(|| ExitError::Other("Get name failed".into()))?;
+				log::debug!(target: "evm", "name: {:?}", name);
+
+				Ok((ExitSucceed::Returned, vec_u8_from_str(&name), 0))
+			}
+			Action::QuerySymbol => {
+				let symbol =
+					MultiCurrency::symbol(currency_id).ok_or_else(|| ExitError::Other("Get symbol failed".into()))?;
+				log::debug!(target: "evm", "symbol: {:?}", symbol);
+
+				Ok((ExitSucceed::Returned, vec_u8_from_str(&symbol), 0))
+			}
+			Action::QueryDecimals => {
+				let decimals = MultiCurrency::decimals(currency_id)
+					.ok_or_else(|| ExitError::Other("Get decimals failed".into()))?;
+				log::debug!(target: "evm", "decimals: {:?}", decimals);
+
+				Ok((ExitSucceed::Returned, vec_u8_from_balance(decimals.into()), 0))
+			}
 		}
 	}
 }
@@ -106,3 +148,13 @@ fn vec_u8_from_balance(balance: Balance) -> Vec<u8> {
 	U256::from(balance).to_big_endian(&mut be_bytes[..]);
 	be_bytes.to_vec()
 }
+
+/// ABI encoding of a `string` return value: offset, length, then the bytes
+/// padded to a multiple of 32.
+fn vec_u8_from_str(b: &[u8]) -> Vec<u8> {
+	let mut res = vec_u8_from_balance(32);
+	res.extend_from_slice(&vec_u8_from_balance(b.len() as Balance));
+	res.extend_from_slice(b);
+	res.resize(res.len() + (32 - b.len() % 32) % 32, 0);
+	res
+}
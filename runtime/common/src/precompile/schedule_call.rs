@@ -147,29 +147,31 @@ impl<AccountId, AddressMapping, Scheduler, ChargeTransactionPayment, Call, Origi
 					input_data,
 				);
 
+				let value: PalletBalanceOf<Runtime> = value.into();
 				let mut _fee: PalletBalanceOf<Runtime> = Default::default();
+				let mut _sponsored = false;
 				#[cfg(not(feature = "with-ethereum-compatibility"))]
 				{
 					//// reserve the transaction fee for gas_limit
 					use sp_runtime::traits::Convert;
 					let from_account = AddressMapping::get_account_id(&from);
 					let weight = <Runtime as module_evm::Config>::GasToWeight::convert(gas_limit);
-					_fee = ChargeTransactionPayment::reserve_fee(&from_account, weight).map_err(|e| {
-						let err_msg: &str = e.into();
-						ExitError::Other(err_msg.into())
-					})?;
+					// the target pays if it sponsors the call
+					_fee = match module_evm::Pallet::<Runtime>::call_sponsor(&from_account, &target, &input_data) {
+						Some(sponsor) => {
+							let fee = ChargeTransactionPayment::reserve_fee(&sponsor, weight)
+								.map_err(|_| ExitError::Other("Sponsor cannot pay the fee".into()))?;
+							module_evm::Pallet::<Runtime>::note_sponsored_call(&from_account, &target, fee);
+							_sponsored = true;
+							fee
+						}
+						None => ChargeTransactionPayment::reserve_fee(&from_account, weight).map_err(|e| {
+							let err_msg: &str = e.into();
+							ExitError::Other(err_msg.into())
+						})?,
+					};
 				}
 
-				let call = module_evm::Call::<Runtime>::scheduled_call(
-					from,
-					target,
-					input_data,
-					value.into(),
-					gas_limit,
-					storage_limit,
-				)
-				.into();
-
 				let current_id = EvmSchedulerNextID::get();
 				let next_id = current_id
 					.checked_add(1)
@@ -190,6 +192,29 @@ impl<AccountId, AddressMapping, Scheduler, ChargeTransactionPayment, Call, Origi
 					task_id,
 				);
 
+				// a sponsored call takes its task id along to charge the sponsor
+				let call = if _sponsored {
+					module_evm::Call::<Runtime>::scheduled_sponsored_call(
+						task_id.clone(),
+						from,
+						target,
+						input_data,
+						value,
+						gas_limit,
+						storage_limit,
+					)
+				} else {
+					module_evm::Call::<Runtime>::scheduled_call(
+						from,
+						target,
+						input_data,
+						value,
+						gas_limit,
+						storage_limit,
+					)
+				}
+				.into();
+
 				Scheduler::schedule_named(
 					task_id.clone(),
 					DispatchTime::After(min_delay),
@@ -200,6 +225,10 @@ impl<AccountId, AddressMapping, Scheduler, ChargeTransactionPayment, Call, Origi
 				)
 				.map_err(|_| ExitError::Other("Schedule failed".into()))?;
 
+				if _sponsored {
+					module_evm::Pallet::<Runtime>::note_sponsored_task(task_id.clone(), target);
+				}
+
 				// add task_id len prefix
 				let mut task_id_with_len = [0u8; 96];
 				U256::from(task_id.len()).to_big_endian(&mut task_id_with_len[0..32]);
@@ -228,8 +257,11 @@ impl<AccountId, AddressMapping, Scheduler, ChargeTransactionPayment, Call, Origi
 				#[cfg(not(feature = "with-ethereum-compatibility"))]
 				{
 					// unreserve the transaction fee for gas_limit
-					let from_account = AddressMapping::get_account_id(&from);
-					ChargeTransactionPayment::unreserve_fee(&from_account, task_info.fee.into());
+					let payer = match module_evm::Pallet::<Runtime>::take_sponsored_task(&task_id) {
+						Some(sponsor) => module_evm::Pallet::<Runtime>::sponsorship_account(&sponsor),
+						None => AddressMapping::get_account_id(&from),
+					};
+					ChargeTransactionPayment::unreserve_fee(&payer, task_info.fee.into());
 				}
 
 				Ok((ExitSucceed::Returned, vec![], 0))
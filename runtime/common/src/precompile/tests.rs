@@ -2,16 +2,19 @@
 use super::*;
 use crate::precompile::{
 	mock::{
-		alice, bob, get_task_id, new_test_ext, run_to_block, Balances, Event as TestEvent,
-		ScheduleCallPrecompile, System, Test,
-		DUST_ERC20_ADDRESS,
+		alice, bob, get_task_id, new_test_ext, run_to_block, Balances, Currencies, Dex, DexPrecompile,
+		Event as TestEvent, MultiCurrencyPrecompile, Origin, ScheduleCallPrecompile, System, Test,
+		ALICE, DUST, DUST_ERC20_ADDRESS, PAUSED_CALLS, USDD,
 	},
 	schedule_call::TaskInfo,
 };
 use codec::Encode;
+use frame_support::{assert_ok, weights::constants::RocksDbWeight};
 use hex_literal::hex;
 use module_evm::ExitError;
-use primitives::{evm::AddressMapping, Balance, PREDEPLOY_ADDRESS_START};
+use module_support::DEXManager;
+use orml_traits::MultiCurrency;
+use primitives::{evm::AddressMapping, Balance, CurrencyId, PREDEPLOY_ADDRESS_START};
 use sp_core::{H160, U256};
 
 pub struct DummyPrecompile;
@@ -30,6 +33,7 @@ pub type WithSystemContractFilter = AllPrecompiles<
 	DummyPrecompile,
 	DummyPrecompile,
 	DummyPrecompile,
+	DummyPrecompile,
 >;
 
 #[test]
@@ -212,6 +216,106 @@ fn schedule_call_precompile_should_work() {
 	});
 }
 
+#[cfg(not(feature = "with-ethereum-compatibility"))]
+#[test]
+fn schedule_call_precompile_should_charge_sponsor() {
+	use frame_support::traits::Currency;
+	use std::str::FromStr;
+
+	new_test_ext().execute_with(|| {
+		let context = Context {
+			address: Default::default(),
+			caller: alice(),
+			apparent_value: Default::default(),
+		};
+
+		// the target sponsors the calls of alice
+		let target = H160::from_str(DUST_ERC20_ADDRESS).unwrap();
+		module_evm::Sponsorships::<Test>::insert(
+			target,
+			module_evm::SponsorshipInfo {
+				rate_limit: 10,
+				period: 10,
+			},
+		);
+		module_evm::SponsoredCallers::<Test>::insert(target, alice(), ());
+		let sponsor_account = module_evm::Pallet::<Test>::sponsorship_account(&target);
+		let from_account = <Test as module_evm::Config>::AddressMapping::get_account_id(&alice());
+
+		let mut input = [0u8; 9 * 32 + 1];
+		// array size
+		U256::default().to_big_endian(&mut input[0 * 32..1 * 32]);
+		// action
+		U256::default().to_big_endian(&mut input[1 * 32..2 * 32]);
+		// from
+		U256::from(alice().as_bytes()).to_big_endian(&mut input[2 * 32..3 * 32]);
+		// target
+		U256::from(DUST_ERC20_ADDRESS).to_big_endian(&mut input[3 * 32..4 * 32]);
+		// value
+		U256::from(0).to_big_endian(&mut input[4 * 32..5 * 32]);
+		// gas_limit
+		U256::from(300000).to_big_endian(&mut input[5 * 32..6 * 32]);
+		// storage_limit
+		U256::from(100).to_big_endian(&mut input[6 * 32..7 * 32]);
+		// min_delay
+		U256::from(1).to_big_endian(&mut input[7 * 32..8 * 32]);
+		// input_len
+		U256::from(1).to_big_endian(&mut input[8 * 32..9 * 32]);
+
+		// input_data = 0x12
+		input[9 * 32] = hex!("12")[0];
+
+		// a sponsor that cannot pay is not replaced by the caller
+		assert_eq!(
+			ScheduleCallPrecompile::execute(&input, None, &context),
+			Err(ExitError::Other("Sponsor cannot pay the fee".into()))
+		);
+
+		let _ = Balances::deposit_creating(&sponsor_account, 1000000);
+		let (reason, output, _) = ScheduleCallPrecompile::execute(&input, None, &context).unwrap();
+		assert_eq!(reason, ExitSucceed::Returned);
+		assert_eq!(Balances::reserved_balance(&sponsor_account), 300000);
+		assert_eq!(Balances::reserved_balance(&from_account), 0);
+
+		// the task id keeps its layout
+		assert_eq!(output.len(), 96);
+		let task_id = get_task_id(output);
+		assert_eq!(
+			module_evm::Pallet::<Test>::sponsored_tasks(task_id.clone()),
+			Some(target)
+		);
+		assert_eq!(module_evm::Pallet::<Test>::pending_sponsored_tasks(target), 1);
+
+		// cancelling returns the fee to the sponsor
+		let mut cancel_input = [0u8; 6 * 32];
+		// action
+		U256::from(1).to_big_endian(&mut cancel_input[1 * 32..2 * 32]);
+		// from
+		U256::from(alice().as_bytes()).to_big_endian(&mut cancel_input[2 * 32..3 * 32]);
+		// task_id_len
+		U256::from(task_id.len()).to_big_endian(&mut cancel_input[3 * 32..4 * 32]);
+		// task_id
+		cancel_input[4 * 32..4 * 32 + task_id.len()].copy_from_slice(&task_id[..]);
+
+		let (reason, _output, _) = ScheduleCallPrecompile::execute(&cancel_input, None, &context).unwrap();
+		assert_eq!(reason, ExitSucceed::Returned);
+		assert_eq!(Balances::reserved_balance(&sponsor_account), 0);
+		assert_eq!(module_evm::Pallet::<Test>::sponsored_tasks(task_id), None);
+		assert_eq!(module_evm::Pallet::<Test>::pending_sponsored_tasks(target), 0);
+
+		// the sponsor pays when the call runs
+		let (reason, _output, _) = ScheduleCallPrecompile::execute(&input, None, &context).unwrap();
+		assert_eq!(reason, ExitSucceed::Returned);
+		let from_balance = Balances::free_balance(&from_account);
+		run_to_block(4);
+		assert!(Balances::free_balance(&sponsor_account) < 1000000);
+		assert_eq!(Balances::reserved_balance(&sponsor_account), 0);
+		assert_eq!(Balances::free_balance(&from_account), from_balance);
+		assert_eq!(Balances::reserved_balance(&from_account), 0);
+		assert_eq!(module_evm::Pallet::<Test>::pending_sponsored_tasks(target), 0);
+	});
+}
+
 #[cfg(not(feature = "with-ethereum-compatibility"))]
 #[test]
 fn schedule_call_precompile_should_handle_invalid_input() {
@@ -290,7 +394,6 @@ fn schedule_call_precompile_should_handle_invalid_input() {
 	});
 }
 
-
 #[test]
 fn task_id_max_and_min() {
 	let task_id = TaskInfo {
@@ -313,3 +416,222 @@ fn task_id_max_and_min() {
 
 	assert_eq!(38, task_id.len());
 }
+
+#[test]
+fn dex_precompile_should_work() {
+	new_test_ext().execute_with(|| {
+		let context = Context {
+			address: Default::default(),
+			caller: alice(),
+			apparent_value: Default::default(),
+		};
+		let currency_id_bytes = |currency_id: CurrencyId| -> [u8; 32] { currency_id.into() };
+
+		assert_ok!(Dex::add_liquidity(
+			Origin::signed(ALICE),
+			DUST,
+			USDD,
+			1_000_000,
+			4_000_000,
+			0
+		));
+
+		// get liquidity pool
+		let mut input = [0u8; 3 * 32];
+		// action
+		U256::from(0).to_big_endian(&mut input[0 * 32..1 * 32]);
+		// currency_id_a
+		input[1 * 32..2 * 32].copy_from_slice(&currency_id_bytes(USDD));
+		// currency_id_b
+		input[2 * 32..3 * 32].copy_from_slice(&currency_id_bytes(DUST));
+
+		let mut expected_output = [0u8; 2 * 32];
+		U256::from(4_000_000).to_big_endian(&mut expected_output[0 * 32..1 * 32]);
+		U256::from(1_000_000).to_big_endian(&mut expected_output[1 * 32..2 * 32]);
+
+		let (reason, output, used_gas) = DexPrecompile::execute(&input, None, &context).unwrap();
+		assert_eq!(reason, ExitSucceed::Returned);
+		assert_eq!(output, expected_output.to_vec());
+		assert_eq!(used_gas, RocksDbWeight::get().reads(1));
+
+		// get swap target amount
+		let mut input = [0u8; 5 * 32];
+		// action
+		U256::from(2).to_big_endian(&mut input[0 * 32..1 * 32]);
+		// path_len
+		U256::from(2).to_big_endian(&mut input[1 * 32..2 * 32]);
+		// supply_amount
+		U256::from(10_000).to_big_endian(&mut input[2 * 32..3 * 32]);
+		// path
+		input[3 * 32..4 * 32].copy_from_slice(&currency_id_bytes(DUST));
+		input[4 * 32..5 * 32].copy_from_slice(&currency_id_bytes(USDD));
+
+		let mut expected_output = [0u8; 32];
+		U256::from(39_211).to_big_endian(&mut expected_output[..]);
+
+		let (reason, output, _) = DexPrecompile::execute(&input, None, &context).unwrap();
+		assert_eq!(reason, ExitSucceed::Returned);
+		assert_eq!(output, expected_output.to_vec());
+
+		// swap with exact supply
+		let who = <Test as module_evm::Config>::AddressMapping::get_account_id(&alice());
+		let usdd_balance = Currencies::free_balance(USDD, &who);
+
+		let mut input = [0u8; 7 * 32];
+		// action
+		U256::from(4).to_big_endian(&mut input[0 * 32..1 * 32]);
+		// who
+		U256::from(alice().as_bytes()).to_big_endian(&mut input[1 * 32..2 * 32]);
+		// path_len
+		U256::from(2).to_big_endian(&mut input[2 * 32..3 * 32]);
+		// supply_amount
+		U256::from(10_000).to_big_endian(&mut input[3 * 32..4 * 32]);
+		// min_target_amount
+		U256::from(39_211).to_big_endian(&mut input[4 * 32..5 * 32]);
+		// path
+		input[5 * 32..6 * 32].copy_from_slice(&currency_id_bytes(DUST));
+		input[6 * 32..7 * 32].copy_from_slice(&currency_id_bytes(USDD));
+
+		// not enough gas for the swap
+		assert_eq!(
+			DexPrecompile::execute(&input, Some(RocksDbWeight::get().reads(1)), &context),
+			Err(ExitError::OutOfGas)
+		);
+
+		let (reason, output, used_gas) = DexPrecompile::execute(&input, None, &context).unwrap();
+		assert_eq!(reason, ExitSucceed::Returned);
+		assert_eq!(output, expected_output.to_vec());
+		assert_eq!(used_gas, <() as module_dex::WeightInfo>::swap_with_exact_supply());
+		assert_eq!(Currencies::free_balance(USDD, &who), usdd_balance + 39_211);
+		assert_eq!(Dex::get_liquidity_pool(DUST, USDD), (1_010_000, 3_960_789));
+
+		// slippage is rejected
+		assert_eq!(
+			DexPrecompile::execute(&input, None, &context),
+			Err(ExitError::Other("InsufficientTargetAmount".into()))
+		);
+
+		// swap pair with exact supply, as the predeployed DEX contract calls it
+		let mut input = [0u8; 6 * 32];
+		// action
+		U256::from(1).to_big_endian(&mut input[0 * 32..1 * 32]);
+		// who
+		U256::from(alice().as_bytes()).to_big_endian(&mut input[1 * 32..2 * 32]);
+		// currency_id_a
+		input[2 * 32..3 * 32].copy_from_slice(&currency_id_bytes(DUST));
+		// currency_id_b
+		input[3 * 32..4 * 32].copy_from_slice(&currency_id_bytes(USDD));
+		// supply_amount
+		U256::from(10_000).to_big_endian(&mut input[4 * 32..5 * 32]);
+		// min_target_amount
+		U256::from(1).to_big_endian(&mut input[5 * 32..6 * 32]);
+
+		let (reason, _, used_gas) = DexPrecompile::execute(&input, None, &context).unwrap();
+		assert_eq!(reason, ExitSucceed::Returned);
+		assert_eq!(used_gas, <() as module_dex::WeightInfo>::swap_with_exact_supply());
+		assert!(Currencies::free_balance(USDD, &who) > usdd_balance + 39_211);
+
+		// swaps stop with the dex call they stand for
+		PAUSED_CALLS.with(|v| v.borrow_mut().push(("Dex", "swap_with_exact_supply")));
+		assert_eq!(
+			DexPrecompile::execute(&input, None, &context),
+			Err(ExitError::Other("Transaction paused".into()))
+		);
+	});
+}
+
+#[test]
+fn multicurrency_precompile_should_not_transfer_while_paused() {
+	new_test_ext().execute_with(|| {
+		let context = Context {
+			address: Default::default(),
+			caller: alice(),
+			apparent_value: Default::default(),
+		};
+		let currency_id_bytes = |currency_id: CurrencyId| -> [u8; 32] { currency_id.into() };
+		let to = <Test as module_evm::Config>::AddressMapping::get_account_id(&bob());
+
+		let mut input = [0u8; 5 * 32];
+		// action
+		U256::from(2).to_big_endian(&mut input[0 * 32..1 * 32]);
+		// currency_id
+		input[1 * 32..2 * 32].copy_from_slice(&currency_id_bytes(USDD));
+		// from
+		U256::from(alice().as_bytes()).to_big_endian(&mut input[2 * 32..3 * 32]);
+		// to
+		U256::from(bob().as_bytes()).to_big_endian(&mut input[3 * 32..4 * 32]);
+		// amount
+		U256::from(500).to_big_endian(&mut input[4 * 32..5 * 32]);
+
+		PAUSED_CALLS.with(|v| v.borrow_mut().push(("Currencies", "transfer")));
+		assert_eq!(
+			MultiCurrencyPrecompile::execute(&input, None, &context),
+			Err(ExitError::Other("Transaction paused".into()))
+		);
+		assert_eq!(Currencies::free_balance(USDD, &to), 0);
+
+		PAUSED_CALLS.with(|v| v.borrow_mut().clear());
+		let (reason, _, _) = MultiCurrencyPrecompile::execute(&input, None, &context).unwrap();
+		assert_eq!(reason, ExitSucceed::Returned);
+		assert_eq!(Currencies::free_balance(USDD, &to), 500);
+	});
+}
+
+#[test]
+fn multicurrency_precompile_should_query_metadata() {
+	new_test_ext().execute_with(|| {
+		let context = Context {
+			address: Default::default(),
+			caller: alice(),
+			apparent_value: Default::default(),
+		};
+		let currency_id_bytes = |currency_id: CurrencyId| -> [u8; 32] { currency_id.into() };
+
+		// name
+		let mut input = [0u8; 2 * 32];
+		// action
+		U256::from(3).to_big_endian(&mut input[0 * 32..1 * 32]);
+		// currency_id
+		input[1 * 32..2 * 32].copy_from_slice(&currency_id_bytes(USDD));
+
+		let mut expected_output = [0u8; 3 * 32];
+		// offset
+		U256::from(32).to_big_endian(&mut expected_output[0 * 32..1 * 32]);
+		// length
+		U256::from(8).to_big_endian(&mut expected_output[1 * 32..2 * 32]);
+		expected_output[2 * 32..2 * 32 + 8].copy_from_slice(b"USD Dust");
+
+		let (reason, output, _) = MultiCurrencyPrecompile::execute(&input, None, &context).unwrap();
+		assert_eq!(reason, ExitSucceed::Returned);
+		assert_eq!(output, expected_output.to_vec());
+
+		// symbol
+		U256::from(4).to_big_endian(&mut input[0 * 32..1 * 32]);
+
+		let mut expected_output = [0u8; 3 * 32];
+		U256::from(32).to_big_endian(&mut expected_output[0 * 32..1 * 32]);
+		U256::from(4).to_big_endian(&mut expected_output[1 * 32..2 * 32]);
+		expected_output[2 * 32..2 * 32 + 4].copy_from_slice(b"USDD");
+
+		let (reason, output, _) = MultiCurrencyPrecompile::execute(&input, None, &context).unwrap();
+		assert_eq!(reason, ExitSucceed::Returned);
+		assert_eq!(output, expected_output.to_vec());
+
+		// decimals
+		U256::from(5).to_big_endian(&mut input[0 * 32..1 * 32]);
+
+		let mut expected_output = [0u8; 32];
+		U256::from(12).to_big_endian(&mut expected_output[..]);
+
+		let (reason, output, _) = MultiCurrencyPrecompile::execute(&input, None, &context).unwrap();
+		assert_eq!(reason, ExitSucceed::Returned);
+		assert_eq!(output, expected_output.to_vec());
+
+		// unregistered currency
+		input[1 * 32..2 * 32].copy_from_slice(&currency_id_bytes(DUST));
+		assert_eq!(
+			MultiCurrencyPrecompile::execute(&input, None, &context),
+			Err(ExitError::Other("Get decimals failed".into()))
+		);
+	});
+}
@@ -106,6 +106,45 @@ runtime_benchmarks! {
 		set_dust_balance(&alice_account_id(), dollar(1000));
 		let contract = deploy_contract(alice_account_id())?;
 	}: _(RawOrigin::Signed(alice_account_id()), contract)
+
+	set_sponsorship {
+		set_dust_balance(&alice_account_id(), dollar(1000));
+		let contract = deploy_contract(alice_account_id())?;
+	}: _(RawOrigin::Signed(alice_account_id()), contract, 10, 100)
+
+	remove_sponsorship {
+		set_dust_balance(&alice_account_id(), dollar(1000));
+		let contract = deploy_contract(alice_account_id())?;
+		EVM::set_sponsorship(Origin::signed(alice_account_id()), contract, 10, 100)?;
+		EVM::set_sponsored_callers(Origin::signed(alice_account_id()), contract, vec![H160::default()], true)?;
+	}: _(RawOrigin::Signed(alice_account_id()), contract)
+
+	deposit_sponsorship {
+		set_dust_balance(&alice_account_id(), dollar(1000));
+		let contract = deploy_contract(alice_account_id())?;
+	}: _(RawOrigin::Signed(alice_account_id()), contract, dollar(100))
+
+	withdraw_sponsorship {
+		set_dust_balance(&alice_account_id(), dollar(1000));
+		let contract = deploy_contract(alice_account_id())?;
+		EVM::deposit_sponsorship(Origin::signed(alice_account_id()), contract, dollar(100))?;
+	}: _(RawOrigin::Signed(alice_account_id()), contract, dollar(50))
+
+	set_sponsored_callers {
+		let c in 1 .. 100;
+		set_dust_balance(&alice_account_id(), dollar(1000));
+		let contract = deploy_contract(alice_account_id())?;
+		EVM::set_sponsorship(Origin::signed(alice_account_id()), contract, 10, 100)?;
+		let callers = (0..c).map(|i| H160::from_low_u64_be(i as u64)).collect::<Vec<_>>();
+	}: _(RawOrigin::Signed(alice_account_id()), contract, callers, true)
+
+	set_sponsored_selectors {
+		let c in 1 .. 100;
+		set_dust_balance(&alice_account_id(), dollar(1000));
+		let contract = deploy_contract(alice_account_id())?;
+		EVM::set_sponsorship(Origin::signed(alice_account_id()), contract, 10, 100)?;
+		let selectors = (0..c).map(|i| i.to_be_bytes()).collect::<Vec<_>>();
+	}: _(RawOrigin::Signed(alice_account_id()), contract, selectors, true)
 }
 
 #[cfg(test)]
@@ -171,4 +210,46 @@ mod tests {
 			assert_ok!(test_benchmark_selfdestruct());
 		});
 	}
+
+	#[test]
+	fn test_set_sponsorship() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(test_benchmark_set_sponsorship());
+		});
+	}
+
+	#[test]
+	fn test_remove_sponsorship() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(test_benchmark_remove_sponsorship());
+		});
+	}
+
+	#[test]
+	fn test_deposit_sponsorship() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(test_benchmark_deposit_sponsorship());
+		});
+	}
+
+	#[test]
+	fn test_withdraw_sponsorship() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(test_benchmark_withdraw_sponsorship());
+		});
+	}
+
+	#[test]
+	fn test_set_sponsored_callers() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(test_benchmark_set_sponsored_callers());
+		});
+	}
+
+	#[test]
+	fn test_set_sponsored_selectors() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(test_benchmark_set_sponsored_selectors());
+		});
+	}
 }
@@ -3,6 +3,7 @@
 // module benchmarking
 pub mod evm;
 pub mod evm_accounts;
+pub mod vesting;
 
 // orml benchmarking
 pub mod authority;
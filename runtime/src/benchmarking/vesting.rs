@@ -0,0 +1,85 @@
+use crate::{
+	AccountId, Balance, BlockNumber, CurrencyId, MaxVestingSchedules, Runtime, System, TokenSymbol, Vesting, DUST,
+};
+
+use super::utils::set_balance;
+use frame_benchmarking::{account, whitelisted_caller};
+use frame_support::traits::Get;
+use frame_system::RawOrigin;
+use module_vesting::VestingSchedule;
+use orml_benchmarking::runtime_benchmarks;
+use sp_std::prelude::*;
+
+const SEED: u32 = 0;
+const USDD: CurrencyId = CurrencyId::Token(TokenSymbol::USDD);
+
+fn dollar(d: u32) -> Balance {
+	let d: Balance = d.into();
+	DUST.saturating_mul(d)
+}
+
+fn schedule(start: BlockNumber) -> VestingSchedule<BlockNumber, Balance> {
+	VestingSchedule {
+		start,
+		cliff: 0,
+		period: 10,
+		period_count: 10,
+		per_period: dollar(10),
+	}
+}
+
+runtime_benchmarks! {
+	{ Runtime, module_vesting }
+
+	vested_transfer {
+		let from: AccountId = account("from", 0, SEED);
+		set_balance(USDD, &from, dollar(1000));
+		let to: AccountId = account("to", 0, SEED);
+	}: _(RawOrigin::Signed(from), to.clone().into(), USDD, schedule(0))
+	verify {
+		assert_eq!(Vesting::vesting_balance(&to, USDD).0, dollar(100));
+	}
+
+	claim {
+		// every schedule but the last one is fully vested
+		let s in 1 .. MaxVestingSchedules::get();
+		let from: AccountId = account("from", 0, SEED);
+		set_balance(USDD, &from, dollar(100).saturating_mul(s.into()));
+		let to: AccountId = whitelisted_caller();
+		for i in 0..s {
+			let start = if i + 1 < s { 0 } else { 100 };
+			Vesting::vested_transfer(RawOrigin::Signed(from.clone()).into(), to.clone().into(), USDD, schedule(start))?;
+		}
+		System::set_block_number(100);
+	}: _(RawOrigin::Signed(to.clone()), USDD)
+	verify {
+		assert_eq!(Vesting::vesting_schedules(&to, USDD).len(), 1);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use frame_support::assert_ok;
+
+	fn new_test_ext() -> sp_io::TestExternalities {
+		frame_system::GenesisConfig::default()
+			.build_storage::<Runtime>()
+			.unwrap()
+			.into()
+	}
+
+	#[test]
+	fn test_vested_transfer() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(test_benchmark_vested_transfer());
+		});
+	}
+
+	#[test]
+	fn test_claim() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(test_benchmark_claim());
+		});
+	}
+}
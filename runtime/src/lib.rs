@@ -6,19 +6,19 @@
 #[cfg(feature = "std")]
 include!(concat!(env!("OUT_DIR"), "/wasm_binary.rs"));
 
-use codec::Encode;
+use codec::{Encode, MaxEncodedLen};
 
 use sp_std::prelude::*;
 use sp_core::{
 	crypto::KeyTypeId,
-	u32_trait::{_2, _3, _4},
+	u32_trait::{_1, _2, _3, _4},
 	H160, OpaqueMetadata, Decode,
 };
 use sp_runtime::{
 	ApplyExtrinsicResult, generic, create_runtime_str, impl_opaque_keys,
-	transaction_validity::{TransactionValidity, TransactionSource, TransactionPriority},
+	transaction_validity::{TransactionValidity, TransactionSource, TransactionPriority, InvalidTransaction},
 	curve::PiecewiseLinear,
-	FixedPointNumber,
+	FixedPointNumber, RuntimeDebug,
 };
 use sp_runtime::traits::{
 	BlakeTwo256,
@@ -29,6 +29,7 @@ use sp_runtime::traits::{
 	StaticLookup,
 	BadOrigin,
 	OpaqueKeys,
+	AccountIdConversion,
 };
 pub use sp_runtime::{
 	Perbill, Percent, Permill, Perquintill,
@@ -52,26 +53,27 @@ pub use pallet_timestamp::Call as TimestampCall;
 pub use pallet_balances::Call as BalancesCall;
 pub use frame_support::{
 	construct_runtime, parameter_types,
-	StorageValue,
+	PalletId, StorageValue,
 	traits::{
-		WithdrawReasons,
-		KeyOwnerProofSystem, Randomness, EnsureOrigin, OriginTrait, U128CurrencyToVote,
-		schedule::Priority,
+		WithdrawReasons, Currency, Get, Imbalance, OnUnbalanced, FindAuthor,
+		KeyOwnerProofSystem, Randomness, EnsureOrigin, OriginTrait, U128CurrencyToVote, InstanceFilter,
+		schedule::{Priority, HARD_DEADLINE},
 	},
 	weights::{
 		Weight, IdentityFee,
 		constants::{BlockExecutionWeight, ExtrinsicBaseWeight, RocksDbWeight, WEIGHT_PER_SECOND},
 	},
 };
-pub use frame_system::{ensure_root, EnsureOneOf, EnsureRoot, RawOrigin};
+pub use frame_system::{ensure_root, ensure_signed, EnsureOneOf, EnsureRoot, EnsureSigned, RawOrigin};
 
-use orml_traits::{parameter_type_with_key};
-use orml_authority::EnsureDelayed;
+use orml_traits::{parameter_type_with_key, GetByKey};
+use orml_authority::{DelayedOrigin, EnsureDelayed};
 
 use module_evm::{CallInfo, CreateInfo};
 use module_evm_accounts::EvmAddressMapping;
 use module_currencies::{BasicCurrencyAdapter};
 use module_transaction_payment::{Multiplier, TargetedFeeAdjustment};
+use module_support::DEXManager;
 
 // re-exports
 
@@ -80,11 +82,11 @@ pub use primitives::{
 	evm::EstimateResourcesRequest,
 	AccountId, AccountIndex, Amount, Balance, BlockNumber,
 	CurrencyId, EraIndex, Hash, Moment, Nonce, Signature, TokenSymbol,
-	AuthoritysOriginId,
+	TradingPair, AuthoritysOriginId,
 };
 
 pub use runtime_common::{
-	BlockLength, BlockWeights, GasToWeight, OffchainSolutionWeightLimit,
+	BlockLength, BlockWeights, GasToWeight, OffchainSolutionLengthLimit, OffchainSolutionWeightLimit,
 	Price, Rate, Ratio, SystemContractsFilter,
 };
 
@@ -100,43 +102,88 @@ mod benchmarking;
 //
 parameter_types! {
 	pub BurnAccount: AccountId = AccountId::from([0u8; 32]);
+	pub const OneDay: BlockNumber = DAYS;
+	pub const ThreeDays: BlockNumber = 3 * DAYS;
 	pub const SevenDays: BlockNumber = 7 * DAYS;
-	// pub TreasuryModuleAccount: AccountId = DustTreasuryModuleId::get().into_account();
+	pub TreasuryModuleAccount: AccountId = TreasuryPalletId::get().into_account();
 }
 
+/// Highest priority of the dispatches scheduled by a simple Tech Council majority.
+pub const HALF_TECH_COUNCIL_PRIORITY: Priority = 128;
+
 pub fn get_all_module_accounts() -> Vec<AccountId> {
 	vec![
 		BurnAccount::get(),
+		TreasuryModuleAccount::get(),
 	]
 }
 
+type EnsureHalfTechCouncil = pallet_collective::EnsureProportionMoreThan<_1, _2, AccountId, TechCouncilInstance>;
+type EnsureTwoThirdsTechCouncil = pallet_collective::EnsureProportionAtLeast<_2, _3, AccountId, TechCouncilInstance>;
+type EnsureThreeFourthsTechCouncil = pallet_collective::EnsureProportionAtLeast<_3, _4, AccountId, TechCouncilInstance>;
+type EnsureDelayedBy<Delay, Inner> = EnsureDelayed<Delay, Inner, BlockNumber, OriginCaller>;
+
+/// The Tech Council can schedule dispatches, but the smaller its majority the
+/// lower their priority and the longer they wait before dispatching as root.
+/// Until then any council member can veto them.
 pub struct AuthorityConfigImpl;
 impl orml_authority::AuthorityConfig<Origin, OriginCaller, BlockNumber> for AuthorityConfigImpl {
-	fn check_schedule_dispatch(origin: Origin, _priority: Priority) -> DispatchResult {
-		EnsureRoot::<AccountId>::try_origin(origin)
-			.map_or_else(|_| Err(BadOrigin.into()), |_| Ok(()))
+	fn check_schedule_dispatch(origin: Origin, priority: Priority) -> DispatchResult {
+		let highest_priority = EnsureRoot::<AccountId>::try_origin(origin)
+			.or_else(EnsureThreeFourthsTechCouncil::try_origin)
+			.map(|_| 0)
+			.or_else(|o| EnsureTwoThirdsTechCouncil::try_origin(o).map(|_| HARD_DEADLINE + 1))
+			.or_else(|o| EnsureHalfTechCouncil::try_origin(o).map(|_| HALF_TECH_COUNCIL_PRIORITY))
+			.map_err(|_| BadOrigin)?;
+
+		// lower values are higher priorities
+		if priority < highest_priority {
+			return Err(BadOrigin.into());
+		}
+		Ok(())
 	}
 
 	fn check_fast_track_schedule(
 		origin: Origin,
 		_initial_origin: &OriginCaller,
-		_new_delay: BlockNumber,
+		new_delay: BlockNumber,
 	) -> DispatchResult {
-		ensure_root(origin).map_err(|_| BadOrigin.into())
+		if ensure_root(origin.clone()).is_ok() {
+			return Ok(());
+		}
+
+		// fast-tracking takes a larger majority and still leaves a day to veto
+		EnsureThreeFourthsTechCouncil::try_origin(origin).map_err(|_| BadOrigin)?;
+		if new_delay < OneDay::get() {
+			return Err(BadOrigin.into());
+		}
+		Ok(())
 	}
 
-	fn check_delay_schedule(origin: Origin, _initial_origin: &OriginCaller) -> DispatchResult {
-		ensure_root(origin).map_err(|_| BadOrigin.into())
+	fn check_delay_schedule(origin: Origin, initial_origin: &OriginCaller) -> DispatchResult {
+		if origin.caller() == initial_origin {
+			return Ok(());
+		}
+		EnsureRoot::<AccountId>::try_origin(origin)
+			.or_else(EnsureHalfTechCouncil::try_origin)
+			.map_or_else(|_| Err(BadOrigin.into()), |_| Ok(()))
 	}
 
 	fn check_cancel_schedule(origin: Origin, initial_origin: &OriginCaller) -> DispatchResult {
-		ensure_root(origin.clone()).or_else(|_| {
-			if origin.caller() == initial_origin {
+		if origin.caller() == initial_origin || ensure_root(origin.clone()).is_ok() {
+			return Ok(());
+		}
+
+		// the delay of a council dispatch is the veto window of its members
+		let who = ensure_signed(origin)?;
+		match initial_origin {
+			OriginCaller::Authority(DelayedOrigin { origin, .. })
+				if matches!(**origin, OriginCaller::TechCouncil(_)) && TechCouncil::is_member(&who) =>
+			{
 				Ok(())
-			} else {
-				Err(BadOrigin.into())
 			}
-		})
+			_ => Err(BadOrigin.into()),
+		}
 	}
 }
 
@@ -150,13 +197,11 @@ impl orml_authority::AsOriginId<Origin, OriginCaller> for AuthoritysOriginId {
 	fn check_dispatch_from(&self, origin: Origin) -> DispatchResult {
 		ensure_root(origin.clone()).or_else(|_| {
 			match self {
-			AuthoritysOriginId::Root => <EnsureDelayed<
-				SevenDays,
-				EnsureRoot<AccountId>,
-				BlockNumber,
-				OriginCaller,
-			> as EnsureOrigin<Origin>>::ensure_origin(origin)
-			.map_or_else(|_| Err(BadOrigin.into()), |_| Ok(())),
+			AuthoritysOriginId::Root => EnsureDelayedBy::<SevenDays, EnsureRoot<AccountId>>::try_origin(origin)
+				.or_else(EnsureDelayedBy::<OneDay, EnsureThreeFourthsTechCouncil>::try_origin)
+				.or_else(EnsureDelayedBy::<ThreeDays, EnsureTwoThirdsTechCouncil>::try_origin)
+				.or_else(EnsureDelayedBy::<SevenDays, EnsureHalfTechCouncil>::try_origin)
+				.map_or_else(|_| Err(BadOrigin.into()), |_| Ok(())),
 		}
 		})
 	}
@@ -231,10 +276,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	spec_name: create_runtime_str!("dust"),
 	impl_name: create_runtime_str!("dust"),
 	authoring_version: 1,
-	spec_version: 10,
+	spec_version: 11,
 	impl_version: 10,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 2,
+	transaction_version: 3,
 };
 
 /// The version information used to identify this runtime when compiled natively.
@@ -262,7 +307,7 @@ parameter_types! {
 
 impl frame_system::Config for Runtime {
 	/// The basic call filter to use in dispatchable.
-	type BaseCallFilter = frame_support::traits::Everything;
+	type BaseCallFilter = module_transaction_pause::NonPausedTransactionFilter<Runtime>;
 	/// Block & extrinsics weights: base values and limits.
 	type BlockWeights = BlockWeights;
 	/// The maximum length of a block (in bytes).
@@ -363,13 +408,13 @@ parameter_types! {
 }
 
 impl pallet_staking::Config for Runtime {
-	const MAX_NOMINATIONS: u32 = 16;
+	const MAX_NOMINATIONS: u32 = MAX_NOMINATIONS;
 	type Currency = Balances;
 	type UnixTime = Timestamp;
 	type CurrencyToVote = U128CurrencyToVote;
-	type RewardRemainder = (); // burn
+	type RewardRemainder = Treasury;
 	type Event = Event;
-	type Slash = (); // burn slashed rewards
+	type Slash = SlashSplit;
 	type Reward = (); // rewards are minted from the void
 	type SessionsPerEra = SessionsPerEra;
 	type BondingDuration = BondingDuration;
@@ -379,7 +424,7 @@ impl pallet_staking::Config for Runtime {
 	type NextNewSession = Session;
 	type MaxNominatorRewardedPerValidator = MaxNominatorRewardedPerValidator;
 	type WeightInfo = ();
-	type ElectionProvider = onchain::OnChainSequentialPhragmen<Self>;
+	type ElectionProvider = ElectionProviderMultiPhase;
 	type EraPayout = pallet_staking::ConvertCurve<RewardCurve>;
 	type GenesisElectionProvider = onchain::OnChainSequentialPhragmen<Self>;
 }
@@ -392,6 +437,84 @@ impl onchain::Config for Runtime {
 	type DataProvider = Staking;
 }
 
+parameter_types! {
+	// phase durations, 1/4 of the last session of the era for each
+	pub const SignedPhase: BlockNumber = EPOCH_DURATION_IN_BLOCKS / 4;
+	pub const UnsignedPhase: BlockNumber = EPOCH_DURATION_IN_BLOCKS / 4;
+
+	// signed config
+	pub const SignedMaxSubmissions: u32 = 10;
+	pub const SignedRewardBase: Balance = 1 * DUST;
+	pub const SignedDepositBase: Balance = 1 * DUST;
+	pub const SignedDepositByte: Balance = 1 * CENTS;
+
+	// fallback to an on-chain election when no solution was submitted
+	pub const Fallback: pallet_election_provider_multi_phase::FallbackStrategy =
+		pallet_election_provider_multi_phase::FallbackStrategy::OnChain;
+
+	pub SolutionImprovementThreshold: Perbill = Perbill::from_rational(1u32, 10_000);
+
+	// miner configs
+	pub const MultiPhaseUnsignedPriority: TransactionPriority = ImOnlineUnsignedPriority::get() - 1u64;
+	pub const MinerMaxIterations: u32 = 10;
+	pub const OffchainRepeat: BlockNumber = 5;
+}
+
+sp_npos_elections::generate_solution_type!(
+	#[compact]
+	pub struct NposSolution16::<
+		VoterIndex = u32,
+		TargetIndex = u16,
+		Accuracy = sp_runtime::PerU16,
+	>(16)
+);
+
+pub const MAX_NOMINATIONS: u32 = <NposSolution16 as sp_npos_elections::NposSolution>::LIMIT as u32;
+
+/// Snapshot sizes the election benchmarks run with, well above the number of
+/// validators and nominators staking has on this chain, so the generated
+/// weights cover the largest snapshots.
+pub struct BenchmarkConfig;
+impl pallet_election_provider_multi_phase::BenchmarkingConfig for BenchmarkConfig {
+	const VOTERS: [u32; 2] = [5_000, 10_000];
+	const TARGETS: [u32; 2] = [1_000, 2_000];
+	const ACTIVE_VOTERS: [u32; 2] = [1000, 4_000];
+	const DESIRED_TARGETS: [u32; 2] = [400, 800];
+	const SNAPSHOT_MAXIMUM_VOTERS: u32 = 25_000;
+	const MINER_MAXIMUM_VOTERS: u32 = 15_000;
+	const MAXIMUM_TARGETS: u32 = 2000;
+}
+
+impl pallet_election_provider_multi_phase::Config for Runtime {
+	type Event = Event;
+	type Currency = Balances;
+	type EstimateCallFee = TransactionPayment;
+	type SignedPhase = SignedPhase;
+	type UnsignedPhase = UnsignedPhase;
+	type SolutionImprovementThreshold = SolutionImprovementThreshold;
+	type OffchainRepeat = OffchainRepeat;
+	type MinerMaxIterations = MinerMaxIterations;
+	type MinerMaxWeight = OffchainSolutionWeightLimit;
+	type MinerMaxLength = OffchainSolutionLengthLimit;
+	type MinerTxPriority = MultiPhaseUnsignedPriority;
+	type SignedMaxSubmissions = SignedMaxSubmissions;
+	type SignedRewardBase = SignedRewardBase;
+	type SignedDepositBase = SignedDepositBase;
+	type SignedDepositByte = SignedDepositByte;
+	type SignedDepositWeight = ();
+	type SignedMaxWeight = OffchainSolutionWeightLimit;
+	// the deposits of rejected signed solutions go where staking slashes go
+	type SlashHandler = SlashSplit;
+	type RewardHandler = (); // nothing to do upon rewards
+	type DataProvider = Staking;
+	type OnChainAccuracy = Perbill;
+	type Solution = NposSolution16;
+	type Fallback = Fallback;
+	type WeightInfo = pallet_election_provider_multi_phase::weights::SubstrateWeight<Runtime>;
+	type ForceOrigin = EnsureRootOrThreeFourthsTechCouncil;
+	type BenchmarkingConfig = BenchmarkConfig;
+}
+
 
 impl pallet_babe::Config for Runtime {
 	type EpochDuration = EpochDuration;
@@ -503,6 +626,12 @@ impl pallet_indices::Config for Runtime {
 	type WeightInfo = ();
 }
 
+parameter_types! {
+	// no pallet reserved ERC20 before the reserves were tracked, so the reserve
+	// address balances were all sent to them directly
+	pub Erc20ReservesBeforeTracking: Vec<(H160, AccountId)> = vec![];
+}
+
 impl module_currencies::Config for Runtime {
 	type Event = Event;
 	type MultiCurrency = Tokens;
@@ -510,11 +639,42 @@ impl module_currencies::Config for Runtime {
 	type WeightInfo = ();
 	type AddressMapping = EvmAddressMapping<Runtime>;
 	type EVMBridge = EVMBridge;
+	type AssetRegistry = AssetRegistry;
+	type Erc20ReservesBeforeTracking = Erc20ReservesBeforeTracking;
+}
+
+impl module_asset_registry::Config for Runtime {
+	type Event = Event;
+	type Currency = Balances;
+	type EVMBridge = EVMBridge;
+	type RegisterOrigin = EnsureRootOrTwoThridsTechCouncil;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub EnabledTradingPairs: Vec<TradingPair> = vec![
+		TradingPair::from_currency_ids(GetNativeCurrencyId::get(), GetStableCurrencyId::get()).unwrap(),
+	];
+	pub const GetExchangeFee: (u32, u32) = (3, 1000);	// 0.3%
+	pub const TradingPathLimit: u32 = 3;
+	pub const DEXPalletId: PalletId = PalletId(*b"dust/dex");
+}
+
+impl module_dex::Config for Runtime {
+	type Event = Event;
+	type Currency = Currencies;
+	type EnabledTradingPairs = EnabledTradingPairs;
+	type GetExchangeFee = GetExchangeFee;
+	type TradingPathLimit = TradingPathLimit;
+	type PalletId = DEXPalletId;
+	type WeightInfo = ();
 }
 
 parameter_type_with_key! {
-	pub ExistentialDeposits: |_currency_id: CurrencyId| -> Balance {
-		Zero::zero()
+	pub ExistentialDeposits: |currency_id: CurrencyId| -> Balance {
+		AssetRegistry::asset_metadatas(currency_id)
+			.map(|metadata| metadata.minimal_balance)
+			.unwrap_or_else(Zero::zero)
 	};
 }
 
@@ -534,15 +694,153 @@ parameter_types! {
 	pub const GetNativeCurrencyId: CurrencyId = CurrencyId::Token(TokenSymbol::DUST);
 	pub const GetStableCurrencyId: CurrencyId = CurrencyId::Token(TokenSymbol::USDD);
 	// All currency types except for native currency, Sort by fee charge order
-	pub AllNonNativeCurrencyIds: Vec<CurrencyId> = vec![];
+	pub AllNonNativeCurrencyIds: Vec<CurrencyId> = vec![CurrencyId::Token(TokenSymbol::USDD)];
 
 }
 
+parameter_type_with_key! {
+	// high enough that filling the schedules of an account costs more than it is worth
+	pub MinVestedTransfer: |currency_id: CurrencyId| -> Balance {
+		match currency_id {
+			CurrencyId::Token(TokenSymbol::DUST) => 100 * DUST,
+			CurrencyId::Token(TokenSymbol::USDD) => 100 * DOLLARS,
+			// other currencies vest once registered, from 100 times their existential deposit
+			_ if AssetRegistry::asset_metadatas(currency_id).is_none() => Balance::max_value(),
+			_ => ExistentialDeposits::get(currency_id).saturating_mul(100).max(1),
+		}
+	};
+}
+
+parameter_types! {
+	pub const MaxVestingSchedules: u32 = 10;
+}
+
+impl module_vesting::Config for Runtime {
+	type Event = Event;
+	type Currency = Currencies;
+	type MinVestedTransfer = MinVestedTransfer;
+	type VestedTransferOrigin = EnsureSigned<AccountId>;
+	type MaxVestingSchedules = MaxVestingSchedules;
+	type WeightInfo = module_vesting::weights::WeightInfo<Runtime>;
+}
+
 parameter_types! {
 	pub const TransactionByteFee: Balance = 1 * MILLI_DUST; // kedia: reduce transaction byte fee from 10 to 1
 	pub const TargetBlockFullness: Perquintill = Perquintill::from_percent(25);
 	pub AdjustmentVariable: Multiplier = Multiplier::saturating_from_rational(1, 100_000);
 	pub MinimumMultiplier:  Multiplier = Multiplier::saturating_from_rational(1, 1_000_000_000 as u128);
+	pub MaxSlippageSwapWithDEX: Ratio = Ratio::saturating_from_rational(5, 100);
+	// PoC voting weight an account must exceed to get a fee discount, by ascending tier
+	pub PocFeeDiscountTiers: Vec<(Balance, Perbill)> = vec![
+		(1_000_000 * DUST, Perbill::from_percent(10)),
+		(10_000_000 * DUST, Perbill::from_percent(25)),
+		(100_000_000 * DUST, Perbill::from_percent(50)),
+	];
+	pub const MaxFeeDiscountPerBlock: Balance = 100 * DUST;
+}
+
+/// Discounts the transaction fees of PoC committers by the highest tier their voting weight exceeds.
+pub struct PocFeeDiscount;
+impl module_transaction_payment::FeeDiscount<AccountId> for PocFeeDiscount {
+	fn fee_discount(who: &AccountId) -> Perbill {
+		let weight = Poc::account_voting_weight(who);
+		PocFeeDiscountTiers::get()
+			.into_iter()
+			.filter(|(threshold, _)| weight > *threshold)
+			.map(|(_, discount)| discount)
+			.max()
+			.unwrap_or_else(Zero::zero)
+	}
+}
+
+parameter_types! {
+	pub const TreasuryPalletId: PalletId = PalletId(*b"dust/trs");
+	pub const ProposalBond: Permill = Permill::from_percent(5);
+	pub const ProposalBondMinimum: Balance = 1_000 * DUST;
+	pub const SpendPeriod: BlockNumber = 7 * DAYS;
+	pub const TreasuryBurn: Permill = Permill::from_percent(0);
+	pub const MaxApprovals: u32 = 100;
+}
+
+impl pallet_treasury::Config for Runtime {
+	type PalletId = TreasuryPalletId;
+	type Currency = Balances;
+	type ApproveOrigin = EnsureRootOrTwoThridsTechCouncil;
+	type RejectOrigin = EnsureRootOrTwoThridsTechCouncil;
+	type Event = Event;
+	type OnSlash = Treasury; // slashed proposal bonds
+	type ProposalBond = ProposalBond;
+	type ProposalBondMinimum = ProposalBondMinimum;
+	type SpendPeriod = SpendPeriod;
+	type Burn = TreasuryBurn;
+	type BurnDestination = ();
+	type SpendFunds = ();
+	type MaxApprovals = MaxApprovals;
+	type WeightInfo = pallet_treasury::weights::SubstrateWeight<Runtime>;
+}
+
+type NegativeImbalance = <Balances as Currency<AccountId>>::NegativeImbalance;
+
+/// The author of the current block, if its pre-runtime digest names one.
+fn block_author() -> Option<AccountId> {
+	let digest = System::digest();
+	let pre_runtime_digests = digest.logs.iter().filter_map(|log| log.as_pre_runtime());
+	<Runtime as pallet_authorship::Config>::FindAuthor::find_author(pre_runtime_digests)
+}
+
+/// Splits an imbalance between the treasury and the block author, burning
+/// the rest. Both shares are of the whole imbalance. Without a block author
+/// its share is burnt too.
+pub struct SplitImbalance<TreasuryShare, AuthorShare>(sp_std::marker::PhantomData<(TreasuryShare, AuthorShare)>);
+impl<TreasuryShare, AuthorShare> OnUnbalanced<NegativeImbalance> for SplitImbalance<TreasuryShare, AuthorShare>
+where
+	TreasuryShare: Get<Percent>,
+	AuthorShare: Get<Percent>,
+{
+	fn on_nonzero_unbalanced(amount: NegativeImbalance) {
+		let total = amount.peek();
+		let (to_treasury, rest) = amount.split(TreasuryShare::get() * total);
+		let (to_author, _burnt) = rest.split(AuthorShare::get() * total);
+		Treasury::on_unbalanced(to_treasury);
+		if to_author.peek() > 0 {
+			if let Some(author) = block_author() {
+				Balances::resolve_creating(&author, to_author);
+			}
+		}
+	}
+}
+
+parameter_types! {
+	// transaction fees
+	pub const FeeTreasuryShare: Percent = Percent::from_percent(60);
+	pub const FeeAuthorShare: Percent = Percent::from_percent(20);
+	// transaction tips
+	pub const TipTreasuryShare: Percent = Percent::from_percent(0);
+	pub const TipAuthorShare: Percent = Percent::from_percent(100);
+	// evm contract deployment fees
+	pub const DeploymentFeeTreasuryShare: Percent = Percent::from_percent(100);
+	pub const DeploymentFeeAuthorShare: Percent = Percent::from_percent(0);
+	// staking, election and PoC slashes
+	pub const SlashTreasuryShare: Percent = Percent::from_percent(100);
+	pub const SlashAuthorShare: Percent = Percent::from_percent(0);
+}
+
+pub type FeeSplit = SplitImbalance<FeeTreasuryShare, FeeAuthorShare>;
+pub type TipSplit = SplitImbalance<TipTreasuryShare, TipAuthorShare>;
+pub type DeploymentFeeSplit = SplitImbalance<DeploymentFeeTreasuryShare, DeploymentFeeAuthorShare>;
+pub type SlashSplit = SplitImbalance<SlashTreasuryShare, SlashAuthorShare>;
+
+/// Routes the transaction fees and tips through their splits.
+pub struct DealWithFees;
+impl OnUnbalanced<NegativeImbalance> for DealWithFees {
+	fn on_unbalanceds<B>(mut fees_then_tips: impl Iterator<Item = NegativeImbalance>) {
+		if let Some(fees) = fees_then_tips.next() {
+			FeeSplit::on_unbalanced(fees);
+			if let Some(tips) = fees_then_tips.next() {
+				TipSplit::on_unbalanced(tips);
+			}
+		}
+	}
 }
 
 impl module_transaction_payment::Config for Runtime {
@@ -551,10 +849,15 @@ impl module_transaction_payment::Config for Runtime {
 	type StableCurrencyId = GetStableCurrencyId;
 	type Currency = Balances;
 	type MultiCurrency = Currencies;
-	type OnTransactionPayment = (); // fees get burned
+	type OnTransactionPayment = DealWithFees;
 	type TransactionByteFee = TransactionByteFee;
 	type WeightToFee = fee::WeightToFee;
 	type FeeMultiplierUpdate = TargetedFeeAdjustment<Self, TargetBlockFullness, AdjustmentVariable, MinimumMultiplier>;
+	type DEX = Dex;
+	type MaxSlippageSwapWithDEX = MaxSlippageSwapWithDEX;
+	type FeeSponsor = EVM;
+	type FeeDiscount = PocFeeDiscount;
+	type MaxFeeDiscountPerBlock = MaxFeeDiscountPerBlock;
 	type WeightInfo = weights::transaction_payment::WeightInfo<Runtime>;
 }
 
@@ -600,7 +903,7 @@ parameter_types! {
 }
 
 pub type MultiCurrencyPrecompile =
-	runtime_common::MultiCurrencyPrecompile<AccountId, EvmAddressMapping<Runtime>, Currencies>;
+	runtime_common::MultiCurrencyPrecompile<AccountId, EvmAddressMapping<Runtime>, Currencies, TransactionPause>;
 pub type StateRentPrecompile = runtime_common::StateRentPrecompile<AccountId, EvmAddressMapping<Runtime>, EVM>;
 pub type ScheduleCallPrecompile = runtime_common::ScheduleCallPrecompile<
 	AccountId,
@@ -612,6 +915,13 @@ pub type ScheduleCallPrecompile = runtime_common::ScheduleCallPrecompile<
 	OriginCaller,
 	Runtime,
 >;
+pub type DexPrecompile = runtime_common::DexPrecompile<
+	AccountId,
+	EvmAddressMapping<Runtime>,
+	Dex,
+	<Runtime as module_dex::Config>::WeightInfo,
+	TransactionPause,
+>;
 
 impl module_evm::Config for Runtime {
 	type AddressMapping = EvmAddressMapping<Runtime>;
@@ -626,15 +936,17 @@ impl module_evm::Config for Runtime {
 		MultiCurrencyPrecompile,
 		StateRentPrecompile,
 		ScheduleCallPrecompile,
+		DexPrecompile,
 	>;
 	type ChainId = ChainId;
 	type GasToWeight = GasToWeight;
 	type ChargeTransactionPayment = module_transaction_payment::ChargeTransactionPayment<Runtime>;
-	type NetworkContractOrigin = EnsureRoot<AccountId>; // todo: EnsureRootOrTwoThridsTechCouncil
+	type NetworkContractOrigin = EnsureRootOrTwoThridsTechCouncil;
 	type NetworkContractSource = NetworkContractSource;
 	type DeveloperDeposit = DeveloperDeposit;
 	type DeploymentFee = DeploymentFee;
-	type FreeDeploymentOrigin = EnsureRoot<AccountId>; // todo: EnsureRootOrTwoThridsTechCouncil
+	type OnDeploymentFee = DeploymentFeeSplit;
+	type FreeDeploymentOrigin = EnsureRootOrTwoThridsTechCouncil;
 	type WeightInfo = weights::evm::WeightInfo<Runtime>;
 
 	#[cfg(feature = "with-ethereum-compatibility")]
@@ -698,12 +1010,6 @@ impl orml_authority::Config for Runtime {
 }
 
 
-impl pallet_sudo::Config for Runtime {
-	type Event = Event;
-	type Call = Call;
-}
-
-
 type TechCouncilInstance = pallet_collective::Instance1;
 
 type EnsureRootOrTwoThridsTechCouncil = EnsureOneOf<
@@ -731,6 +1037,12 @@ parameter_types! {
 	pub const CandidacyDeposit: Balance =   1_000_000 * primitives::currency::DUST;
 	pub const MinLockAmount: Balance =        100_000 * primitives::currency::DUST;
 	pub const TotalLockedCap: Balance = 2_000_000_000 * primitives::currency::DUST;
+	pub const PocHistoryDepth: u32 = 12; // eras
+	pub const PocMaxVotes: u32 = 16;
+	pub const PocMaxCommitments: u32 = 10_000;
+	pub const PocMaxRunnersUp: u32 = 5;
+	pub const PocResignationPenalty: Perbill = Perbill::from_percent(20);
+	pub const PocEarlyUnbondPenalty: Perbill = Perbill::from_percent(30);
 }
 
 impl pallet_collective::Config<TechCouncilInstance> for Runtime {
@@ -754,11 +1066,100 @@ impl module_poc::Config for Runtime {
 	type MinLockAmount = MinLockAmount;
 	type TotalLockedCap = TotalLockedCap;
 	type MaxCandidates = TechCouncilMaxCandidates;
+	type MaxCommitments = PocMaxCommitments;
 	type MaxMembers = TechCouncilMaxMembers;
+	type MaxRunnersUp = PocMaxRunnersUp;
+	type ResignationPenalty = PocResignationPenalty;
+	type ResignationPenaltyHandler = Treasury;
+	type EarlyUnbondPenalty = PocEarlyUnbondPenalty;
+	type EarlyUnbondPenaltyHandler = Treasury;
+	type SlashOrigin = EnsureRootOrTwoThridsTechCouncil;
+	type SlashHandler = SlashSplit;
+	type CouncilRewardSource = TreasuryModuleAccount;
+	type HistoryDepth = PocHistoryDepth;
+	type MaxVotes = PocMaxVotes;
+	type CurrencyToVote = U128CurrencyToVote;
 	type MembershipChanged = TechCouncil;
+	type ParametersOrigin = EnsureRootOrTwoThridsTechCouncil;
+	type OnVotesRemoved = PocConviction;
 	type WeightInfo = ();
 }
 
+parameter_types! {
+	pub const LaunchPeriod: BlockNumber = 7 * DAYS;
+	pub const VotingPeriod: BlockNumber = 7 * DAYS;
+	pub const FastTrackVotingPeriod: BlockNumber = 3 * HOURS;
+	pub const EnactmentPeriod: BlockNumber = 2 * DAYS;
+	pub const MinimumTurnout: Perbill = Perbill::from_percent(10);
+	pub const MinimumDeposit: Balance = 1_000 * DUST;
+	pub const DemocracyMaxProposals: u32 = 100;
+	pub const DemocracyMaxSeconds: u32 = 100;
+	pub const DemocracyMaxVotes: u32 = 100;
+}
+
+/// The DUST locked in referendum votes weighs as much as the PoC commitments
+/// of its owner, up to the committed amount. The votes are re-weighed when
+/// the commitments stop voting or shrink.
+pub struct PocConviction;
+impl module_democracy::VotingConviction<AccountId, Balance> for PocConviction {
+	fn voting_weight(who: &AccountId, locked: Balance) -> Balance {
+		Poc::convicted_weight(who, locked)
+	}
+}
+
+impl module_poc::OnVotesRemoved<AccountId> for PocConviction {
+	fn on_votes_removed(who: &AccountId) {
+		Democracy::reweigh_votes(who);
+	}
+}
+
+impl module_democracy::Config for Runtime {
+	type Event = Event;
+	type Currency = Balances;
+	type Proposal = Call;
+	type Conviction = PocConviction;
+	type LaunchPeriod = LaunchPeriod;
+	type VotingPeriod = VotingPeriod;
+	type FastTrackVotingPeriod = FastTrackVotingPeriod;
+	type EnactmentPeriod = EnactmentPeriod;
+	type MinimumTurnout = MinimumTurnout;
+	type MinimumDeposit = MinimumDeposit;
+	type MaxProposals = DemocracyMaxProposals;
+	type MaxSeconds = DemocracyMaxSeconds;
+	type MaxVotes = DemocracyMaxVotes;
+	type FastTrackOrigin = EnsureRootOrTwoThridsTechCouncil;
+	type CancellationOrigin = EnsureRootOrThreeFourthsTechCouncil;
+	type Slash = Treasury;
+	type PalletsOrigin = OriginCaller;
+	type Scheduler = Scheduler;
+	type WeightInfo = module_democracy::weights::WeightInfo<Runtime>;
+}
+
+parameter_types! {
+	/// Pallets kept in maintenance mode and never paused, to produce blocks and govern.
+	pub const MaintenancePallets: &'static [&'static str] = &[
+		"System",
+		"Timestamp",
+		"Scheduler",
+		"Authority",
+		"Authorship",
+		"Babe",
+		"Grandpa",
+		"Session",
+		"ImOnline",
+		"ElectionProviderMultiPhase",
+		"TechCouncil",
+		"Democracy",
+	];
+}
+
+impl module_transaction_pause::Config for Runtime {
+	type Event = Event;
+	type UpdateOrigin = EnsureRootOrTwoThridsTechCouncil;
+	type MaintenancePallets = MaintenancePallets;
+	type WeightInfo = module_transaction_pause::weights::WeightInfo<Runtime>;
+}
+
 impl pallet_randomness_collective_flip::Config for Runtime {}
 
 impl pallet_utility::Config for Runtime {
@@ -783,6 +1184,115 @@ impl pallet_multisig::Config for Runtime {
 	type WeightInfo = pallet_multisig::weights::SubstrateWeight<Runtime>;
 }
 
+parameter_types! {
+	// One storage item; key size 32, value size 8
+	pub const ProxyDepositBase: Balance = deposit(1, 8);
+	// Additional storage item size of 33 bytes
+	pub const ProxyDepositFactor: Balance = deposit(0, 33);
+	pub const MaxProxies: u16 = 32;
+	pub const AnnouncementDepositBase: Balance = deposit(1, 8);
+	pub const AnnouncementDepositFactor: Balance = deposit(0, 66);
+	pub const MaxPending: u32 = 32;
+}
+
+/// The calls an account can make on behalf of another.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Encode, Decode, RuntimeDebug, MaxEncodedLen)]
+pub enum ProxyType {
+	Any,
+	/// Anything but moving funds
+	NonTransfer,
+	/// Committing, voting and unbonding in PoC
+	Poc,
+	/// EVM calls and contract maintenance
+	Evm,
+	Staking,
+	Governance,
+}
+
+impl Default for ProxyType {
+	fn default() -> Self {
+		Self::Any
+	}
+}
+
+impl InstanceFilter<Call> for ProxyType {
+	fn filter(&self, c: &Call) -> bool {
+		match self {
+			ProxyType::Any => true,
+			ProxyType::NonTransfer => matches!(
+				c,
+				Call::System(..)
+					| Call::Timestamp(..)
+					| Call::Scheduler(..)
+					| Call::Indices(pallet_indices::Call::claim(..))
+					| Call::Indices(pallet_indices::Call::free(..))
+					| Call::Indices(pallet_indices::Call::freeze(..))
+					| Call::Vesting(module_vesting::Call::claim(..))
+					| Call::Treasury(..)
+					| Call::Authority(..)
+					| Call::Utility(..)
+					| Call::Multisig(..)
+					| Call::Proxy(..)
+					| Call::EvmAccounts(..)
+					| Call::Authorship(..)
+					| Call::Babe(..)
+					| Call::Grandpa(..)
+					| Call::Staking(..)
+					| Call::Session(..)
+					| Call::ImOnline(..)
+					| Call::Identity(..)
+					| Call::TechCouncil(..)
+					| Call::Poc(..)
+					| Call::Democracy(..)
+					| Call::TransactionPause(..)
+			),
+			ProxyType::Poc => matches!(
+				c,
+				Call::Poc(module_poc::Call::commit(..))
+					| Call::Poc(module_poc::Call::add_funds(..))
+					| Call::Poc(module_poc::Call::vote_candidates(..))
+					| Call::Poc(module_poc::Call::unbond(..))
+					| Call::Poc(module_poc::Call::withdraw(..))
+					| Call::Poc(module_poc::Call::claim_rewards(..))
+					| Call::Utility(..)
+			),
+			ProxyType::Evm => matches!(c, Call::EVM(..) | Call::Utility(..)),
+			ProxyType::Staking => matches!(c, Call::Staking(..) | Call::Session(..) | Call::Utility(..)),
+			ProxyType::Governance => matches!(
+				c,
+				Call::TechCouncil(..) | Call::Democracy(..) | Call::Treasury(..) | Call::Utility(..)
+			),
+		}
+	}
+
+	fn is_superset(&self, o: &Self) -> bool {
+		match (self, o) {
+			(x, y) if x == y => true,
+			(ProxyType::Any, _) => true,
+			(_, ProxyType::Any) => false,
+			// EVM calls can move funds
+			(ProxyType::NonTransfer, ProxyType::Evm) => false,
+			(ProxyType::NonTransfer, _) => true,
+			_ => false,
+		}
+	}
+}
+
+impl pallet_proxy::Config for Runtime {
+	type Event = Event;
+	type Call = Call;
+	type Currency = Balances;
+	type ProxyType = ProxyType;
+	type ProxyDepositBase = ProxyDepositBase;
+	type ProxyDepositFactor = ProxyDepositFactor;
+	type MaxProxies = MaxProxies;
+	type WeightInfo = pallet_proxy::weights::SubstrateWeight<Runtime>;
+	type MaxPending = MaxPending;
+	type CallHasher = BlakeTwo256;
+	type AnnouncementDepositBase = AnnouncementDepositBase;
+	type AnnouncementDepositFactor = AnnouncementDepositFactor;
+}
+
 
 // Create the runtime by composing the FRAME pallets that were previously configured.
 
@@ -801,7 +1311,6 @@ construct_runtime!(
 		System: frame_system::{Pallet, Call, Config, Storage, Event<T>} = 0,
 		RandomnessCollectiveFlip: pallet_randomness_collective_flip::{Pallet, Storage} = 1,
 		Timestamp: pallet_timestamp::{Pallet, Call, Storage, Inherent} = 2,
-		Sudo: pallet_sudo::{Pallet, Call, Config<T>, Storage, Event<T>} = 3,
 		Scheduler: pallet_scheduler::{Pallet, Call, Storage, Event<T>} = 4,
 
 		// Account lookup
@@ -812,11 +1321,16 @@ construct_runtime!(
 		Currencies: module_currencies::{Pallet, Call, Event<T>} = 7,
 		Tokens: orml_tokens::{Pallet, Storage, Event<T>, Config<T>} = 8,
 		TransactionPayment: module_transaction_payment::{Pallet, Call, Storage} = 9,
+		AssetRegistry: module_asset_registry::{Pallet, Call, Storage, Event<T>} = 13,
+		Dex: module_dex::{Pallet, Call, Storage, Event<T>} = 14,
+		Treasury: pallet_treasury::{Pallet, Call, Storage, Config, Event<T>} = 15,
+		Vesting: module_vesting::{Pallet, Call, Storage, Config<T>, Event<T>} = 17,
 
 		// Authorization + Utility
 		Authority: orml_authority::{Pallet, Call, Event<T>, Origin<T>} = 10,
 		Utility: pallet_utility::{Pallet, Call, Event} = 11,
 		Multisig: pallet_multisig::{Pallet, Call, Storage, Event<T>} = 12,
+		Proxy: pallet_proxy::{Pallet, Call, Storage, Event<T>} = 16,
 
 		// Smart contracts
 		EvmAccounts: module_evm_accounts::{Pallet, Call, Storage, Event<T>} = 20,
@@ -833,6 +1347,7 @@ construct_runtime!(
 		Offences: pallet_offences::{Pallet, Storage, Event} = 36,
 		ImOnline: pallet_im_online::{Pallet, Call, Storage, Event<T>, ValidateUnsigned, Config<T>} = 37,
 		AuthorityDiscovery: pallet_authority_discovery::{Pallet, Config} = 38,
+		ElectionProviderMultiPhase: pallet_election_provider_multi_phase::{Pallet, Call, Storage, Event<T>, ValidateUnsigned} = 39,
 
 		// Identity
 		Identity: pallet_identity::{Pallet, Call, Storage, Event<T>} = 40,
@@ -840,6 +1355,10 @@ construct_runtime!(
 		// Proof of Commitment
 		TechCouncil: pallet_collective::<Instance1>::{Pallet, Call, Storage, Origin<T>, Event<T>, Config<T>} = 50,
 		Poc: module_poc::{Pallet, Call, Storage, Event<T>} = 51,
+
+		// Governance
+		Democracy: module_democracy::{Pallet, Call, Storage, Event<T>} = 52,
+		TransactionPause: module_transaction_pause::{Pallet, Call, Storage, Event<T>} = 53,
 	}
 );
 
@@ -877,8 +1396,22 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPallets,
+	RemoveSudo,
 >;
 
+/// Drops the sudo key, root is only reachable through referenda. Upgrades
+/// after the key is gone only check for it.
+pub struct RemoveSudo;
+impl frame_support::traits::OnRuntimeUpgrade for RemoveSudo {
+	fn on_runtime_upgrade() -> Weight {
+		if !frame_support::storage::migration::have_storage_value(b"Sudo", b"Key", &[]) {
+			return RocksDbWeight::get().reads(1);
+		}
+		frame_support::storage::migration::remove_storage_prefix(b"Sudo", b"Key", &[]);
+		RocksDbWeight::get().reads_writes(1, 1)
+	}
+}
+
 impl<LocalCall> frame_system::offchain::CreateSignedTransaction<LocalCall> for Runtime
 where
 	Call: From<LocalCall>,
@@ -986,6 +1519,10 @@ impl_runtime_apis! {
 			tx: <Block as BlockT>::Extrinsic,
 			block_hash: <Block as BlockT>::Hash,
 		) -> TransactionValidity {
+			// paused calls would fail and still be charged, keep them out of the pool
+			if !<Runtime as frame_system::Config>::BaseCallFilter::contains(&tx.function) {
+				return InvalidTransaction::Call.into();
+			}
 			Executive::validate_transaction(source, tx, block_hash)
 		}
 	}
@@ -1103,7 +1640,11 @@ impl_runtime_apis! {
 			uxt: <Block as BlockT>::Extrinsic,
 			len: u32,
 		) -> pallet_transaction_payment_rpc_runtime_api::RuntimeDispatchInfo<Balance> {
-			TransactionPayment::query_info(uxt, len)
+			let who = uxt
+				.signature
+				.as_ref()
+				.and_then(|(address, _, _)| <Runtime as frame_system::Config>::Lookup::lookup(address.clone()).ok());
+			TransactionPayment::query_info(uxt, len, who.as_ref())
 		}
 		fn query_fee_details(
 			uxt: <Block as BlockT>::Extrinsic,
@@ -1113,6 +1654,42 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl module_transaction_payment_rpc_runtime_api::MultiCurrencyFeeApi<Block, AccountId, Balance> for Runtime {
+		fn query_fee_in_currencies(
+			uxt: <Block as BlockT>::Extrinsic,
+			len: u32,
+			who: AccountId,
+		) -> (Vec<(CurrencyId, Balance)>, Option<CurrencyId>) {
+			TransactionPayment::query_fee_in_currencies(uxt, len, &who)
+		}
+	}
+
+	impl module_transaction_payment_rpc_runtime_api::FeeDiscountApi<Block, AccountId> for Runtime {
+		fn fee_discount(who: AccountId) -> Perbill {
+			TransactionPayment::query_fee_discount(&who)
+		}
+	}
+
+	impl module_transaction_pause_rpc_runtime_api::TransactionPauseApi<Block> for Runtime {
+		fn maintenance_mode() -> bool {
+			TransactionPause::maintenance_mode()
+		}
+
+		fn paused_pallets() -> Vec<Vec<u8>> {
+			TransactionPause::paused_pallets()
+		}
+
+		fn paused_transactions() -> Vec<(Vec<u8>, Vec<u8>)> {
+			TransactionPause::paused_transactions()
+		}
+	}
+
+	impl module_vesting_rpc_runtime_api::VestingApi<Block, AccountId, CurrencyId, Balance> for Runtime {
+		fn vesting_balance(who: AccountId, currency_id: CurrencyId) -> (Balance, Balance) {
+			Vesting::vesting_balance(&who, currency_id)
+		}
+	}
+
 	impl module_evm_rpc_runtime_api::EVMRuntimeRPCApi<Block, Balance> for Runtime {
 		fn call(
 			from: H160,
@@ -1197,6 +1774,53 @@ impl_runtime_apis! {
 
 	}
 
+	impl module_dex_rpc_runtime_api::DexApi<Block, Balance> for Runtime {
+		fn get_liquidity_pool(currency_id_a: CurrencyId, currency_id_b: CurrencyId) -> (Balance, Balance) {
+			Dex::get_liquidity_pool(currency_id_a, currency_id_b)
+		}
+
+		fn get_swap_target_amount(path: Vec<CurrencyId>, supply_amount: Balance) -> Option<Balance> {
+			Dex::get_swap_target_amount(&path, supply_amount, None)
+		}
+
+		fn get_swap_supply_amount(path: Vec<CurrencyId>, target_amount: Balance) -> Option<Balance> {
+			Dex::get_swap_supply_amount(&path, target_amount, None)
+		}
+	}
+
+	impl module_poc_rpc_runtime_api::PocApi<Block, AccountId, Balance, BlockNumber> for Runtime {
+		fn candidate_rankings() -> Vec<(AccountId, Balance)> {
+			Poc::candidate_rankings()
+		}
+
+		fn projected_members() -> Vec<(AccountId, Balance)> {
+			Poc::projected_members()
+		}
+
+		fn voting_weight(who: AccountId) -> Balance {
+			Poc::account_voting_weight(&who)
+		}
+
+		fn pending_rewards(who: AccountId) -> Balance {
+			Poc::pending_rewards(&who)
+		}
+
+		fn unbonding_ends(who: AccountId) -> Vec<(u32, BlockNumber)> {
+			Poc::unbonding_ends(&who)
+		}
+
+		fn era_council_rewards() -> Balance {
+			Poc::era_council_rewards()
+		}
+
+		fn slashing_history(who: AccountId) -> Vec<(u32, Perbill, Balance, Balance)> {
+			Poc::slashing_history(&who)
+				.into_iter()
+				.map(|record| (record.era, record.fraction, record.deposit_slashed, record.backers_slashed))
+				.collect()
+		}
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	impl frame_benchmarking::Benchmark<Block> for Runtime {
 		fn benchmark_metadata(extra: bool) -> (
@@ -1214,9 +1838,15 @@ impl_runtime_apis! {
 			list_benchmark!(list, extra, pallet_balances, Balances);
 			list_benchmark!(list, extra, pallet_timestamp, Timestamp);
 			list_benchmark!(list, extra, module_poc, Poc);
+			list_benchmark!(list, extra, module_democracy, Democracy);
+			list_benchmark!(list, extra, module_transaction_pause, TransactionPause);
+			list_benchmark!(list, extra, pallet_treasury, Treasury);
+			list_benchmark!(list, extra, pallet_proxy, Proxy);
+			list_benchmark!(list, extra, pallet_election_provider_multi_phase, ElectionProviderMultiPhase);
 
 			orml_list_benchmark!(list, extra, evm, benchmarking::evm);
 			orml_list_benchmark!(list, extra, evm_accounts, benchmarking::evm_accounts);
+			orml_list_benchmark!(list, extra, vesting, benchmarking::vesting);
 
 			let storage_info = AllPalletsWithSystem::storage_info();
 
@@ -1252,9 +1882,15 @@ impl_runtime_apis! {
 			add_benchmark!(params, batches, pallet_balances, Balances);
 			add_benchmark!(params, batches, pallet_timestamp, Timestamp);
 			add_benchmark!(params, batches, module_poc, Poc);
+			add_benchmark!(params, batches, module_democracy, Democracy);
+			add_benchmark!(params, batches, module_transaction_pause, TransactionPause);
+			add_benchmark!(params, batches, pallet_treasury, Treasury);
+			add_benchmark!(params, batches, pallet_proxy, Proxy);
+			add_benchmark!(params, batches, pallet_election_provider_multi_phase, ElectionProviderMultiPhase);
 
 			orml_add_benchmark!(params, batches, evm, benchmarking::evm);
 			orml_add_benchmark!(params, batches, evm_accounts, benchmarking::evm_accounts);
+			orml_add_benchmark!(params, batches, vesting, benchmarking::vesting);
 
 			if batches.is_empty() { return Err("Benchmark not found for this pallet.".into()) }
 			Ok(batches)
@@ -12,7 +12,7 @@ impl<T: frame_system::Config> module_transaction_payment::WeightInfo for WeightI
 	fn on_finalize() -> Weight {
 		(39_708_000 as Weight)
 			.saturating_add(DbWeight::get().reads(2 as Weight))
-			.saturating_add(DbWeight::get().writes(1 as Weight))
+			.saturating_add(DbWeight::get().writes(2 as Weight))
 	}
 	fn set_default_fee_token() -> Weight {
 		(1_000_000 as Weight).saturating_add(DbWeight::get().writes(1 as Weight))
@@ -3,22 +3,32 @@
 use codec::Encode;
 use frame_support::{
 	assert_noop, assert_ok,
-	traits::{GenesisBuild, OnFinalize, OnInitialize},
+	traits::{
+		schedule::{DispatchTime, HARD_DEADLINE, LOWEST_PRIORITY},
+		Contains, Currency, GenesisBuild, InstanceFilter, OnFinalize, OnInitialize, OnUnbalanced,
+	},
 };
+use frame_election_provider_support::ElectionProvider;
 use dust_runtime::{
 	get_all_module_accounts,
 	AccountId, AuthoritysOriginId,
-	Balance, Balances, Call,
-	CurrencyId,
+	Balance, Balances, BlockNumber, BurnAccount, Call,
+	CurrencyId, DealWithFees, DeploymentFeeSplit,
 	Event, EvmAccounts, GetNativeCurrencyId,
-	NativeTokenExistentialDeposit, Origin,
-	Perbill, Runtime, System,
-	TokenSymbol, EVM,
+	NativeTokenExistentialDeposit, Origin, OriginCaller,
+	Perbill, Proxy, ProxyDepositBase, ProxyDepositFactor, ProxyType,
+	AnnouncementDepositBase, AnnouncementDepositFactor, Runtime, System, TechCouncil,
+	TokenSymbol, TransactionPause, TreasuryModuleAccount, Vesting, DAYS, EVM,
+	HALF_TECH_COUNCIL_PRIORITY, ElectionProviderMultiPhase, SignedPhase, UnsignedPhase,
+	BlockLength, BlockWeights, OffchainSolutionLengthLimit, OffchainSolutionWeightLimit, EPOCH_DURATION_IN_BLOCKS,
+	StakerStatus,
 };
+use pallet_election_provider_multi_phase::{ElectionCompute, Phase};
 use module_support::{Price};
+use orml_traits::MultiCurrency;
 use sp_io::hashing::keccak_256;
 use sp_runtime::{
-	traits::{BadOrigin},
+	traits::{BadOrigin, BlakeTwo256, Hash},
 	DispatchError, FixedPointNumber, MultiAddress,
 };
 
@@ -44,14 +54,24 @@ fn last_event() -> Event {
 	SystemModule::events().pop().expect("Event expected").event
 }
 
+/// Run block `n` only, skipping the blocks before it.
+fn jump_to_block(n: BlockNumber) {
+	SystemModule::set_block_number(n - 1);
+	run_to_block(n);
+}
+
 pub struct ExtBuilder {
 	endowed_accounts: Vec<(AccountId, CurrencyId, Balance)>,
+	validator_count: u32,
+	stakers: Vec<(AccountId, AccountId, Balance, StakerStatus<AccountId>)>,
 }
 
 impl Default for ExtBuilder {
 	fn default() -> Self {
 		Self {
 			endowed_accounts: vec![],
+			validator_count: 0,
+			stakers: vec![],
 		}
 	}
 }
@@ -62,6 +82,16 @@ impl ExtBuilder {
 		self
 	}
 
+	pub fn stakers(
+		mut self,
+		validator_count: u32,
+		stakers: Vec<(AccountId, AccountId, Balance, StakerStatus<AccountId>)>,
+	) -> Self {
+		self.validator_count = validator_count;
+		self.stakers = stakers;
+		self
+	}
+
 	pub fn build(self) -> sp_io::TestExternalities {
 		let mut t = frame_system::GenesisConfig::default()
 			.build_storage::<Runtime>()
@@ -97,6 +127,15 @@ impl ExtBuilder {
 		.assimilate_storage(&mut t)
 		.unwrap();
 
+		pallet_staking::GenesisConfig::<Runtime> {
+			validator_count: self.validator_count,
+			minimum_validator_count: 1,
+			stakers: self.stakers,
+			..Default::default()
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
 		let mut ext = sp_io::TestExternalities::new(t);
 		ext.execute_with(|| SystemModule::set_block_number(1));
 		ext
@@ -391,6 +430,561 @@ fn test_authority_module() {
 		});
 }
 
+fn council_origin(yes: u32, total: u32) -> Origin {
+	pallet_collective::RawOrigin::<AccountId, pallet_collective::Instance1>::Members(yes, total).into()
+}
+
+fn set_balance_as_root(who: [u8; 32], free: Balance) -> Box<Call> {
+	let set_balance_call = Call::Balances(pallet_balances::Call::set_balance(
+		MultiAddress::Id(AccountId::from(who)),
+		free,
+		0,
+	));
+	Box::new(Call::Authority(orml_authority::Call::dispatch_as(
+		AuthoritysOriginId::Root,
+		Box::new(set_balance_call),
+	)))
+}
+
+fn last_scheduled() -> (Box<OriginCaller>, orml_authority::ScheduleTaskIndex) {
+	match last_event() {
+		Event::Authority(orml_authority::Event::Scheduled(origin, task_id)) => (Box::new(origin), task_id),
+		event => panic!("unexpected event {:?}", event),
+	}
+}
+
+#[test]
+fn test_authority_council_schedule_dispatch() {
+	ExtBuilder::default().build().execute_with(|| {
+		let in_three_days = 1 + 3 * DAYS;
+		let in_seven_days = 1 + 7 * DAYS;
+
+		// a council minority cannot schedule, smaller majorities get lower priorities
+		assert_noop!(
+			AuthorityModule::schedule_dispatch(
+				council_origin(1, 2),
+				DispatchTime::At(in_seven_days),
+				LOWEST_PRIORITY,
+				true,
+				set_balance_as_root(ALICE, 1_000 * DUST)
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			AuthorityModule::schedule_dispatch(
+				council_origin(3, 5),
+				DispatchTime::At(in_seven_days),
+				HALF_TECH_COUNCIL_PRIORITY - 1,
+				true,
+				set_balance_as_root(ALICE, 1_000 * DUST)
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			AuthorityModule::schedule_dispatch(
+				council_origin(2, 3),
+				DispatchTime::At(in_seven_days),
+				HARD_DEADLINE,
+				true,
+				set_balance_as_root(ALICE, 1_000 * DUST)
+			),
+			BadOrigin
+		);
+
+		// the council dispatches as root once the delay of its majority has passed
+		assert_ok!(AuthorityModule::schedule_dispatch(
+			council_origin(3, 5),
+			DispatchTime::At(in_three_days),
+			HALF_TECH_COUNCIL_PRIORITY,
+			true,
+			set_balance_as_root(ALICE, 1_000 * DUST)
+		));
+		assert_ok!(AuthorityModule::schedule_dispatch(
+			council_origin(2, 3),
+			DispatchTime::At(in_three_days),
+			HARD_DEADLINE + 1,
+			true,
+			set_balance_as_root(BOB, 1_000 * DUST)
+		));
+		assert_ok!(AuthorityModule::schedule_dispatch(
+			council_origin(3, 5),
+			DispatchTime::At(in_seven_days),
+			HALF_TECH_COUNCIL_PRIORITY,
+			true,
+			set_balance_as_root(ALICE, 2_000 * DUST)
+		));
+
+		// and never without the delayed origin
+		assert_ok!(AuthorityModule::schedule_dispatch(
+			council_origin(3, 4),
+			DispatchTime::At(in_three_days),
+			0,
+			false,
+			set_balance_as_root(BOB, 3_000 * DUST)
+		));
+
+		jump_to_block(in_three_days);
+		assert_eq!(Balances::free_balance(AccountId::from(ALICE)), 0);
+		assert_eq!(Balances::free_balance(AccountId::from(BOB)), 1_000 * DUST);
+
+		jump_to_block(in_seven_days);
+		assert_eq!(Balances::free_balance(AccountId::from(ALICE)), 2_000 * DUST);
+	});
+}
+
+#[test]
+fn test_authority_council_fast_track_and_delay() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(AuthorityModule::schedule_dispatch(
+			council_origin(3, 5),
+			DispatchTime::At(1 + 7 * DAYS),
+			HALF_TECH_COUNCIL_PRIORITY,
+			true,
+			set_balance_as_root(ALICE, 1_000 * DUST)
+		));
+		let (initial_origin, task_id) = last_scheduled();
+
+		// fast-tracking takes three fourths of the council and leaves a day at least
+		assert_noop!(
+			AuthorityModule::fast_track_scheduled_dispatch(
+				council_origin(2, 3),
+				initial_origin.clone(),
+				task_id,
+				DispatchTime::At(1 + DAYS)
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			AuthorityModule::fast_track_scheduled_dispatch(
+				council_origin(3, 4),
+				initial_origin.clone(),
+				task_id,
+				DispatchTime::At(DAYS)
+			),
+			BadOrigin
+		);
+		assert_ok!(AuthorityModule::fast_track_scheduled_dispatch(
+			council_origin(3, 4),
+			initial_origin,
+			task_id,
+			DispatchTime::At(1 + DAYS)
+		));
+
+		jump_to_block(1 + DAYS);
+		assert_eq!(Balances::free_balance(AccountId::from(ALICE)), 1_000 * DUST);
+
+		// any council majority can delay
+		assert_ok!(AuthorityModule::schedule_dispatch(
+			council_origin(3, 5),
+			DispatchTime::At(1 + 8 * DAYS),
+			HALF_TECH_COUNCIL_PRIORITY,
+			true,
+			set_balance_as_root(BOB, 1_000 * DUST)
+		));
+		let (initial_origin, task_id) = last_scheduled();
+
+		assert_noop!(
+			AuthorityModule::delay_scheduled_dispatch(council_origin(1, 2), initial_origin.clone(), task_id, DAYS),
+			BadOrigin
+		);
+		assert_ok!(AuthorityModule::delay_scheduled_dispatch(
+			council_origin(4, 7),
+			initial_origin,
+			task_id,
+			DAYS
+		));
+
+		jump_to_block(1 + 8 * DAYS);
+		assert_eq!(Balances::free_balance(AccountId::from(BOB)), 0);
+
+		jump_to_block(1 + 9 * DAYS);
+		assert_eq!(Balances::free_balance(AccountId::from(BOB)), 1_000 * DUST);
+	});
+}
+
+#[test]
+fn test_authority_council_veto() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(TechCouncil::set_members(
+			Origin::root(),
+			vec![AccountId::from(ALICE)],
+			None,
+			0
+		));
+
+		assert_ok!(AuthorityModule::schedule_dispatch(
+			council_origin(3, 5),
+			DispatchTime::At(1 + 7 * DAYS),
+			HALF_TECH_COUNCIL_PRIORITY,
+			true,
+			set_balance_as_root(BOB, 1_000 * DUST)
+		));
+		let (initial_origin, task_id) = last_scheduled();
+
+		// any council member can veto the council while the dispatch is delayed
+		assert_noop!(
+			AuthorityModule::cancel_scheduled_dispatch(
+				Origin::signed(AccountId::from(BOB)),
+				initial_origin.clone(),
+				task_id
+			),
+			BadOrigin
+		);
+		assert_ok!(AuthorityModule::cancel_scheduled_dispatch(
+			Origin::signed(AccountId::from(ALICE)),
+			initial_origin,
+			task_id
+		));
+
+		jump_to_block(1 + 7 * DAYS);
+		assert_eq!(Balances::free_balance(AccountId::from(BOB)), 0);
+
+		// but not root
+		assert_ok!(AuthorityModule::schedule_dispatch(
+			Origin::root(),
+			DispatchTime::At(1 + 14 * DAYS),
+			0,
+			true,
+			set_balance_as_root(BOB, 1_000 * DUST)
+		));
+		let (initial_origin, task_id) = last_scheduled();
+
+		assert_noop!(
+			AuthorityModule::cancel_scheduled_dispatch(Origin::signed(AccountId::from(ALICE)), initial_origin, task_id),
+			BadOrigin
+		);
+	});
+}
+
+
+#[test]
+fn test_transaction_pause_module() {
+	ExtBuilder::default().build().execute_with(|| {
+		let transfer_call = Call::Balances(pallet_balances::Call::transfer(
+			MultiAddress::Id(AccountId::from(BOB)),
+			100,
+		));
+		let timestamp_call = Call::Timestamp(pallet_timestamp::Call::set(0));
+		let exit_call = Call::TransactionPause(module_transaction_pause::Call::exit_maintenance_mode());
+		let is_allowed = |call: &Call| <Runtime as frame_system::Config>::BaseCallFilter::contains(call);
+
+		assert_noop!(
+			TransactionPause::pause_transaction(council_origin(1, 2), b"Balances".to_vec(), b"transfer".to_vec()),
+			BadOrigin
+		);
+		assert_ok!(TransactionPause::pause_transaction(
+			council_origin(2, 3),
+			b"Balances".to_vec(),
+			b"transfer".to_vec()
+		));
+		assert!(!is_allowed(&transfer_call));
+		assert!(is_allowed(&timestamp_call));
+
+		// the inherents cannot be paused
+		assert_noop!(
+			TransactionPause::pause_pallet(council_origin(2, 3), b"Timestamp".to_vec()),
+			module_transaction_pause::Error::<Runtime>::CannotPause
+		);
+		assert_noop!(
+			TransactionPause::pause_transaction(council_origin(2, 3), b"Timestamp".to_vec(), b"set".to_vec()),
+			module_transaction_pause::Error::<Runtime>::CannotPause
+		);
+
+		// blocks are still produced and the council can resume in maintenance mode
+		assert_ok!(TransactionPause::unpause_transaction(
+			council_origin(2, 3),
+			b"Balances".to_vec(),
+			b"transfer".to_vec()
+		));
+		assert_ok!(TransactionPause::enter_maintenance_mode(council_origin(2, 3)));
+		assert!(!is_allowed(&transfer_call));
+		assert!(is_allowed(&timestamp_call));
+		assert!(is_allowed(&exit_call));
+
+		assert_ok!(TransactionPause::exit_maintenance_mode(council_origin(2, 3)));
+		assert!(is_allowed(&transfer_call));
+	});
+}
+
+#[test]
+fn test_proxy_module() {
+	ExtBuilder::default()
+		.balances(vec![
+			(AccountId::from(ALICE), CurrencyId::Token(TokenSymbol::DUST), 1_000 * DUST),
+			(AccountId::from(BOB), CurrencyId::Token(TokenSymbol::DUST), 1_000 * DUST),
+		])
+		.build()
+		.execute_with(|| {
+			let transfer_call = Box::new(Call::Balances(pallet_balances::Call::transfer(
+				MultiAddress::Id(AccountId::from(BOB)),
+				10 * DUST,
+			)));
+			let unbond_call = Call::Poc(module_poc::Call::unbond(0));
+
+			assert!(ProxyType::Poc.filter(&unbond_call));
+			assert!(!ProxyType::Poc.filter(&transfer_call));
+			assert!(ProxyType::NonTransfer.filter(&unbond_call));
+			assert!(!ProxyType::NonTransfer.filter(&transfer_call));
+			assert!(ProxyType::NonTransfer.is_superset(&ProxyType::Poc));
+			assert!(!ProxyType::NonTransfer.is_superset(&ProxyType::Evm));
+
+			// the proxy deposit is reserved in DUST
+			assert_ok!(Proxy::add_proxy(
+				Origin::signed(AccountId::from(ALICE)),
+				AccountId::from(BOB),
+				ProxyType::Poc,
+				0
+			));
+			assert_eq!(
+				Balances::reserved_balance(AccountId::from(ALICE)),
+				ProxyDepositBase::get() + ProxyDepositFactor::get()
+			);
+
+			// a PoC proxy cannot transfer
+			assert_ok!(Proxy::proxy(
+				Origin::signed(AccountId::from(BOB)),
+				AccountId::from(ALICE),
+				None,
+				transfer_call.clone()
+			));
+			assert_eq!(Balances::free_balance(AccountId::from(BOB)), 1_000 * DUST);
+
+			// a delayed proxy announces its calls first
+			assert_ok!(Proxy::add_proxy(
+				Origin::signed(AccountId::from(ALICE)),
+				AccountId::from(BOB),
+				ProxyType::Any,
+				10
+			));
+			assert_noop!(
+				Proxy::proxy(
+					Origin::signed(AccountId::from(BOB)),
+					AccountId::from(ALICE),
+					Some(ProxyType::Any),
+					transfer_call.clone()
+				),
+				pallet_proxy::Error::<Runtime>::Unannounced
+			);
+
+			assert_ok!(Proxy::announce(
+				Origin::signed(AccountId::from(BOB)),
+				AccountId::from(ALICE),
+				BlakeTwo256::hash_of(&transfer_call)
+			));
+			assert_eq!(
+				Balances::reserved_balance(AccountId::from(BOB)),
+				AnnouncementDepositBase::get() + AnnouncementDepositFactor::get()
+			);
+			assert_noop!(
+				Proxy::proxy_announced(
+					Origin::signed(AccountId::from(BOB)),
+					AccountId::from(BOB),
+					AccountId::from(ALICE),
+					Some(ProxyType::Any),
+					transfer_call.clone()
+				),
+				pallet_proxy::Error::<Runtime>::Unannounced
+			);
+
+			SystemModule::set_block_number(11);
+			assert_ok!(Proxy::proxy_announced(
+				Origin::signed(AccountId::from(BOB)),
+				AccountId::from(BOB),
+				AccountId::from(ALICE),
+				Some(ProxyType::Any),
+				transfer_call
+			));
+			assert_eq!(Balances::free_balance(AccountId::from(BOB)), 1_010 * DUST);
+		});
+}
+
+#[test]
+fn test_vesting_module() {
+	ExtBuilder::default()
+		.balances(vec![
+			(AccountId::from(ALICE), CurrencyId::Token(TokenSymbol::DUST), 1_000 * DUST),
+			(AccountId::from(ALICE), CurrencyId::Token(TokenSymbol::USDD), 1_000 * DUST),
+		])
+		.build()
+		.execute_with(|| {
+			let schedule = module_vesting::VestingSchedule {
+				start: 1,
+				cliff: DAYS,
+				period: DAYS,
+				period_count: 10,
+				per_period: 10 * DUST,
+			};
+			let claim_call = Call::Vesting(module_vesting::Call::claim(CurrencyId::Token(TokenSymbol::DUST)));
+			let vested_transfer_call = Call::Vesting(module_vesting::Call::vested_transfer(
+				MultiAddress::Id(AccountId::from(BOB)),
+				CurrencyId::Token(TokenSymbol::DUST),
+				schedule.clone(),
+			));
+			assert!(ProxyType::NonTransfer.filter(&claim_call));
+			assert!(!ProxyType::NonTransfer.filter(&vested_transfer_call));
+
+			// tiny schedules which would fill the slots of an account are refused
+			assert_noop!(
+				Vesting::vested_transfer(
+					origin_of(AccountId::from(ALICE)),
+					MultiAddress::Id(AccountId::from(BOB)),
+					CurrencyId::Token(TokenSymbol::DUST),
+					module_vesting::VestingSchedule {
+						per_period: 1,
+						..schedule.clone()
+					}
+				),
+				module_vesting::Error::<Runtime>::AmountLow
+			);
+
+			// the native currency and tokens vest alike
+			for &currency_id in &[
+				CurrencyId::Token(TokenSymbol::DUST),
+				CurrencyId::Token(TokenSymbol::USDD),
+			] {
+				assert_ok!(Vesting::vested_transfer(
+					origin_of(AccountId::from(ALICE)),
+					MultiAddress::Id(AccountId::from(BOB)),
+					currency_id,
+					schedule.clone()
+				));
+				assert_eq!(Currencies::free_balance(currency_id, &AccountId::from(BOB)), 100 * DUST);
+				assert_eq!(
+					Vesting::vesting_balance(&AccountId::from(BOB), currency_id),
+					(100 * DUST, 0)
+				);
+			}
+			assert_noop!(
+				Balances::transfer(
+					origin_of(AccountId::from(BOB)),
+					MultiAddress::Id(AccountId::from(ALICE)),
+					1 * DUST
+				),
+				pallet_balances::Error::<Runtime>::LiquidityRestrictions
+			);
+
+			// nothing vests before the cliff
+			SystemModule::set_block_number(DAYS);
+			assert_eq!(
+				Vesting::vesting_balance(&AccountId::from(BOB), CurrencyId::Token(TokenSymbol::DUST)),
+				(100 * DUST, 0)
+			);
+
+			SystemModule::set_block_number(1 + 2 * DAYS);
+			assert_eq!(
+				Vesting::vesting_balance(&AccountId::from(BOB), CurrencyId::Token(TokenSymbol::DUST)),
+				(80 * DUST, 20 * DUST)
+			);
+			assert_ok!(Vesting::claim(
+				origin_of(AccountId::from(BOB)),
+				CurrencyId::Token(TokenSymbol::DUST)
+			));
+			assert_ok!(Balances::transfer(
+				origin_of(AccountId::from(BOB)),
+				MultiAddress::Id(AccountId::from(ALICE)),
+				20 * DUST
+			));
+			assert_eq!(
+				Vesting::vesting_balance(&AccountId::from(BOB), CurrencyId::Token(TokenSymbol::USDD)),
+				(80 * DUST, 20 * DUST)
+			);
+		});
+}
+
+#[test]
+fn test_election_provider_multi_phase() {
+	let validator = |i: u8| AccountId::from([10 + i; 32]);
+	let nominator = AccountId::from([20u8; 32]);
+	ExtBuilder::default()
+		.balances(
+			vec![
+				validator(1),
+				validator(2),
+				validator(3),
+				nominator.clone(),
+				AccountId::from(ALICE),
+			]
+			.into_iter()
+			.map(|who| (who, CurrencyId::Token(TokenSymbol::DUST), 10_000 * DUST))
+			.collect(),
+		)
+		.stakers(
+			2,
+			vec![
+				(validator(1), validator(1), 1_000 * DUST, StakerStatus::Validator),
+				(validator(2), validator(2), 2_000 * DUST, StakerStatus::Validator),
+				(validator(3), validator(3), 3_000 * DUST, StakerStatus::Validator),
+				(
+					nominator.clone(),
+					nominator.clone(),
+					5_000 * DUST,
+					StakerStatus::Nominator(vec![validator(1)]),
+				),
+			],
+		)
+		.build()
+		.execute_with(|| {
+			// both phases run within the last session of the era
+			assert!(SignedPhase::get() + UnsignedPhase::get() < EPOCH_DURATION_IN_BLOCKS);
+
+			// mined solutions fit in a block
+			assert!(OffchainSolutionWeightLimit::get() < BlockWeights::get().max_block);
+			let normal_length = *BlockLength::get()
+				.max
+				.get(frame_support::weights::DispatchClass::Normal);
+			assert!(OffchainSolutionLengthLimit::get() < normal_length);
+
+			// the nominated validator and the one with the largest bond have the most backing
+			let elected = || {
+				let mut elected = <ElectionProviderMultiPhase as ElectionProvider<AccountId, BlockNumber>>::elect()
+					.unwrap()
+					.into_iter()
+					.map(|(who, _)| who)
+					.collect::<Vec<_>>();
+				elected.sort();
+				elected
+			};
+			let finalized = |compute| {
+				Event::ElectionProviderMultiPhase(pallet_election_provider_multi_phase::Event::ElectionFinalized(Some(
+					compute,
+				)))
+			};
+
+			// nothing was submitted, the validators are elected on chain
+			assert_eq!(ElectionProviderMultiPhase::current_phase(), Phase::Off);
+			assert_eq!(elected(), vec![validator(1), validator(3)]);
+			assert_eq!(last_event(), finalized(ElectionCompute::OnChain));
+			assert_eq!(ElectionProviderMultiPhase::current_phase(), Phase::Off);
+
+			// a solution submitted in the signed phase
+			assert_ok!(ElectionProviderMultiPhase::create_snapshot());
+			ElectionProviderMultiPhase::on_initialize_open_signed();
+			let (solution, _) = ElectionProviderMultiPhase::mine_solution(2).unwrap();
+			assert_ok!(ElectionProviderMultiPhase::submit(
+				origin_of(AccountId::from(ALICE)),
+				Box::new(solution),
+				0
+			));
+
+			// the best signed solution is queued as the signed phase ends
+			let _ = ElectionProviderMultiPhase::finalize_signed_phase();
+			assert!(ElectionProviderMultiPhase::queued_solution().is_some());
+			assert_eq!(elected(), vec![validator(1), validator(3)]);
+			assert_eq!(last_event(), finalized(ElectionCompute::Signed));
+
+			// a solution mined by the offchain worker in the unsigned phase
+			assert_ok!(ElectionProviderMultiPhase::create_snapshot());
+			ElectionProviderMultiPhase::on_initialize_open_unsigned(true, SystemModule::block_number());
+			let (solution, witness) = ElectionProviderMultiPhase::mine_solution(2).unwrap();
+			assert_ok!(ElectionProviderMultiPhase::submit_unsigned(
+				Origin::none(),
+				Box::new(solution),
+				witness
+			));
+			assert_eq!(elected(), vec![validator(1), validator(3)]);
+			assert_eq!(last_event(), finalized(ElectionCompute::Unsigned));
+		});
+}
 
 #[test]
 fn test_evm_accounts_module() {
@@ -477,3 +1071,27 @@ fn test_evm_module() {
 			assert_eq!(Balances::free_balance(bob_account_id()), amount(1 * MILLI_DUST) - amount(10 * MICRO_DUST));
 		});
 }
+
+#[test]
+fn test_fee_distribution() {
+	ExtBuilder::default().build().execute_with(|| {
+		let treasury = TreasuryModuleAccount::get();
+		let burn_account = BurnAccount::get();
+		let existential_deposit = NativeTokenExistentialDeposit::get();
+		let issuance = Balances::total_issuance();
+
+		// fees are split 60/20 between the treasury and the author, tips go to the author,
+		// without a pre-runtime digest there is no author and its share is burnt
+		DealWithFees::on_unbalanceds(
+			vec![Balances::issue(1_000 * DUST), Balances::issue(10 * DUST)].into_iter(),
+		);
+		assert_eq!(Balances::free_balance(&treasury), existential_deposit + 600 * DUST);
+		assert_eq!(Balances::free_balance(&burn_account), existential_deposit);
+		assert_eq!(Balances::total_issuance(), issuance + 600 * DUST);
+
+		// deployment fees go to the treasury
+		DeploymentFeeSplit::on_unbalanced(Balances::issue(100 * DUST));
+		assert_eq!(Balances::free_balance(&treasury), existential_deposit + 700 * DUST);
+		assert_eq!(Balances::total_issuance(), issuance + 700 * DUST);
+	});
+}